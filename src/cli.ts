@@ -0,0 +1,283 @@
+#!/usr/bin/env node
+import 'dotenv/config';
+import * as fs from 'fs';
+import * as path from 'path';
+import { execFile } from 'child_process';
+import { promisify } from 'util';
+import { DocumentSchema, Storage } from './utils/storage';
+import { HISTORY_SCHEMA, HistoryDocument } from './utils/queueManager';
+import { PLAYLIST_SCHEMA, PlaylistDocument } from './utils/playlistManager';
+import { QueueSpill } from './utils/queueSpill';
+import { GUILD_SETTINGS_SCHEMA, GuildSettingsDocument } from './utils/guildSettings';
+import { PLAYBACK_MODES, PlaybackMode } from './utils/audioInput';
+import { DOWNLOAD_DIR } from './utils/tempFiles';
+import { parseGuildProxies, validateProxyUrl } from './utils/proxy';
+import { YTDLP_MANAGED, getYtDlpCommand, updateYtDlp } from './utils/ytDlpBinary';
+import { USER_PREFERENCES_SCHEMA } from './utils/userPreferences';
+import { PLAYBACK_STATE_SCHEMA } from './utils/playbackState';
+import { LISTENING_STATS_SCHEMA, ListeningStatsDocument } from './utils/listeningStats';
+import { TRACK_METADATA_SCHEMA } from './utils/trackMetadata';
+
+const execFileAsync = promisify(execFile);
+
+// Every document the bot persists; `migrate-db` upgrades each of them
+const PERSISTED_SCHEMAS: DocumentSchema<unknown>[] = [
+  HISTORY_SCHEMA,
+  PLAYLIST_SCHEMA,
+  GUILD_SETTINGS_SCHEMA,
+  USER_PREFERENCES_SCHEMA,
+  PLAYBACK_STATE_SCHEMA,
+  LISTENING_STATS_SCHEMA,
+  TRACK_METADATA_SCHEMA,
+];
+
+const NUMERIC_SETTINGS = [
+  'QUEUE_MEMORY_LIMIT',
+  'EXTRACTION_CONCURRENCY',
+  'BULK_EXTRACTION_CONCURRENCY',
+  'PREFETCH_CONCURRENCY',
+  'DOWNLOAD_QUOTA_MB',
+  'TRACK_METADATA_LIMIT',
+  'WEB_PORT',
+];
+
+// Migration backups older than this are removed by `cache-gc`
+const BACKUP_MAX_AGE_MS = 30 * 24 * 60 * 60 * 1000;
+
+const USAGE = `Usage: goonbot <command>
+
+Commands:
+  validate-config     Check environment settings and required binaries
+  migrate-db          Upgrade every data file to the current format
+  export-guild <id>   Print all stored data for a guild as JSON
+  cache-gc            Remove leftover temp files, spilled queues and old backups
+  update-ytdlp        Install the latest yt-dlp release (needs YTDLP_MANAGED=true)`;
+
+async function validateConfig(): Promise<number> {
+  const problems: string[] = [];
+
+  for (const name of ['DISCORD_TOKEN', 'CLIENT_ID']) {
+    if (!process.env[name]) problems.push(`${name} is not set`);
+  }
+
+  for (const name of ['CLIENT_ID', 'GUILD_ID']) {
+    const value = process.env[name];
+    if (value && !/^\d+$/.test(value)) problems.push(`${name} should be a numeric Discord ID`);
+  }
+
+  for (const name of NUMERIC_SETTINGS) {
+    const value = process.env[name];
+    if (value && !(Number(value) > 0)) problems.push(`${name} should be a positive number, got "${value}"`);
+  }
+
+  const suggestionProvider = process.env.SUGGESTION_PROVIDER;
+  if (suggestionProvider && !['youtube', 'history', 'none'].includes(suggestionProvider.toLowerCase())) {
+    problems.push(`SUGGESTION_PROVIDER must be youtube, history or none, got "${suggestionProvider}"`);
+  }
+
+  const playbackMode = process.env.PLAYBACK_MODE;
+  if (playbackMode && !PLAYBACK_MODES.includes(playbackMode as PlaybackMode)) {
+    problems.push(`PLAYBACK_MODE must be ${PLAYBACK_MODES.join(', ')}, got "${playbackMode}"`);
+  }
+
+  const playbackBackend = process.env.PLAYBACK_BACKEND;
+  if (playbackBackend && !['local', 'lavalink'].includes(playbackBackend)) {
+    problems.push(`PLAYBACK_BACKEND must be local or lavalink, got "${playbackBackend}"`);
+  }
+  if (playbackBackend === 'lavalink') {
+    const lavalinkUrl = process.env.LAVALINK_URL;
+    if (!lavalinkUrl) {
+      problems.push('LAVALINK_URL is not set; PLAYBACK_BACKEND=lavalink needs the node to connect to');
+    } else if (!/^https?:\/\//.test(lavalinkUrl)) {
+      problems.push(`LAVALINK_URL should start with http:// or https://, got "${lavalinkUrl}"`);
+    }
+  }
+
+  const webPublicUrl = process.env.WEB_PUBLIC_URL;
+  if (webPublicUrl && !/^https?:\/\//.test(webPublicUrl)) {
+    problems.push(`WEB_PUBLIC_URL should start with http:// or https://, got "${webPublicUrl}"`);
+  }
+
+  const piperModel = process.env.PIPER_MODEL;
+  if (piperModel && !fs.existsSync(piperModel)) {
+    problems.push(`PIPER_MODEL points to ${piperModel}, which doesn't exist`);
+  }
+  const ttsUrl = process.env.TTS_URL;
+  if (ttsUrl && !/^https?:\/\//.test(ttsUrl)) {
+    problems.push(`TTS_URL should start with http:// or https://, got "${ttsUrl}"`);
+  }
+  const storageBackend = process.env.STORAGE_BACKEND;
+  if (storageBackend && storageBackend !== 'file' && storageBackend !== 'sqlite') {
+    problems.push(`STORAGE_BACKEND must be file or sqlite, got "${storageBackend}"`);
+  }
+  const clusterProcesses = process.env.CLUSTER_PROCESSES;
+  if (clusterProcesses && !(Number.isInteger(Number(clusterProcesses)) && Number(clusterProcesses) > 0)) {
+    problems.push(`CLUSTER_PROCESSES must be a positive whole number, got "${clusterProcesses}"`);
+  }
+  const lrclibUrl = process.env.LRCLIB_URL;
+  if (lrclibUrl && !/^https?:\/\//.test(lrclibUrl)) {
+    problems.push(`LRCLIB_URL should start with http:// or https://, got "${lrclibUrl}"`);
+  }
+
+  const cookiesFile = process.env.YTDLP_COOKIES_FILE;
+  if (cookiesFile && !fs.existsSync(cookiesFile)) {
+    problems.push(`YTDLP_COOKIES_FILE points to ${cookiesFile}, which doesn't exist`);
+  }
+
+  const proxies = parseGuildProxies(process.env.GUILD_PROXY_URLS);
+  if (process.env.PROXY_URL) {
+    proxies.set('PROXY_URL', process.env.PROXY_URL);
+  }
+  for (const [owner, url] of proxies) {
+    const problem = validateProxyUrl(url);
+    if (problem) {
+      problems.push(`Proxy for ${owner} is ${problem}`);
+    }
+  }
+
+  const dataDir = new Storage().getDataDir();
+  try {
+    fs.mkdirSync(dataDir, { recursive: true });
+    fs.accessSync(dataDir, fs.constants.W_OK);
+  } catch {
+    problems.push(`Data directory ${dataDir} is not writable`);
+  }
+
+  for (const binary of [getYtDlpCommand(), 'ffmpeg']) {
+    try {
+      await execFileAsync(binary, [binary === 'ffmpeg' ? '-version' : '--version']);
+    } catch {
+      problems.push(
+        YTDLP_MANAGED && binary === 'yt-dlp'
+          ? 'The managed yt-dlp is not installed yet; run update-ytdlp or start the bot once'
+          : `${binary} was not found`
+      );
+    }
+  }
+
+  if (problems.length > 0) {
+    console.error('❌ Configuration problems:');
+    for (const problem of problems) console.error(`  - ${problem}`);
+    return 1;
+  }
+
+  console.log('✅ Configuration looks good');
+  return 0;
+}
+
+function migrateDb(): number {
+  const storage = new Storage();
+  let failed = false;
+
+  for (const schema of PERSISTED_SCHEMAS) {
+    try {
+      storage.load(schema);
+      console.log(`✅ ${schema.name} is at version ${schema.version}`);
+    } catch (error) {
+      failed = true;
+      console.error(`❌ ${schema.name}:`, error instanceof Error ? error.message : error);
+    }
+  }
+
+  return failed ? 1 : 0;
+}
+
+function exportGuild(guildId: string | undefined): number {
+  if (!guildId) {
+    console.error('Usage: goonbot export-guild <guild id>');
+    return 1;
+  }
+
+  const storage = new Storage();
+  const history: HistoryDocument = storage.load(HISTORY_SCHEMA);
+  const playlists: PlaylistDocument = storage.load(PLAYLIST_SCHEMA);
+  const settings: GuildSettingsDocument = storage.load(GUILD_SETTINGS_SCHEMA);
+  const listeningStats: ListeningStatsDocument = storage.load(LISTENING_STATS_SCHEMA);
+
+  const guildExport = {
+    guildId,
+    exportedAt: new Date().toISOString(),
+    settings: settings[guildId] ?? null,
+    history: history[guildId] ?? [],
+    playlists: playlists[guildId] ?? {},
+    listeningStats: listeningStats[guildId] ?? null,
+  };
+
+  console.log(JSON.stringify(guildExport, null, 2));
+  return 0;
+}
+
+function cacheGc(): number {
+  const storage = new Storage();
+  const dataDir = storage.getDataDir();
+  let removed = 0;
+
+  // The bot must be stopped: spilled pages belong to live queues while it runs
+  QueueSpill.clearAll(storage);
+  fs.rmSync(DOWNLOAD_DIR, { recursive: true, force: true });
+
+  if (fs.existsSync(dataDir)) {
+    for (const file of fs.readdirSync(dataDir)) {
+      const filePath = path.join(dataDir, file);
+      const isTempFile = file.endsWith('.tmp');
+      const isOldBackup = file.endsWith('.bak') && Date.now() - fs.statSync(filePath).mtimeMs > BACKUP_MAX_AGE_MS;
+
+      if (isTempFile || isOldBackup) {
+        fs.rmSync(filePath, { force: true });
+        removed++;
+      }
+    }
+  }
+
+  console.log(`🧹 Removed ${removed} stale file${removed !== 1 ? 's' : ''} and cleared spilled queues and downloads`);
+  return 0;
+}
+
+async function updateYtDlpCommand(): Promise<number> {
+  if (!YTDLP_MANAGED) {
+    console.error('yt-dlp is taken from PATH; set YTDLP_MANAGED=true to let the bot manage it');
+    return 1;
+  }
+
+  try {
+    const result = await updateYtDlp();
+    console.log(
+      result.updated
+        ? `✅ Installed yt-dlp ${result.version}${result.previousVersion ? ` (was ${result.previousVersion})` : ''}`
+        : `✅ yt-dlp ${result.version} is already the latest release`
+    );
+    return 0;
+  } catch (error) {
+    console.error('❌ yt-dlp update failed:', error instanceof Error ? error.message : error);
+    return 1;
+  }
+}
+
+export async function runCli(args: string[]): Promise<number> {
+  const [command, ...rest] = args;
+
+  switch (command) {
+    case 'validate-config':
+      return validateConfig();
+    case 'migrate-db':
+      return migrateDb();
+    case 'export-guild':
+      return exportGuild(rest[0]);
+    case 'cache-gc':
+      return cacheGc();
+    case 'update-ytdlp':
+      return updateYtDlpCommand();
+    default:
+      console.log(USAGE);
+      return command ? 1 : 0;
+  }
+}
+
+if (require.main === module) {
+  runCli(process.argv.slice(2))
+    .then((code) => process.exit(code))
+    .catch((error) => {
+      console.error(error);
+      process.exit(1);
+    });
+}
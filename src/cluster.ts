@@ -0,0 +1,54 @@
+// Load .env before any module reads process.env at import time
+import 'dotenv/config';
+import * as path from 'path';
+import { ShardingManager } from 'discord.js';
+
+/**
+ * Runs the bot as several processes, one per group of Discord shards.
+ * Discord gives every guild to exactly one shard, so each process owns its
+ * guilds' queues and voice connections outright; settings, playlists and
+ * preferences go through the shared SQLite database so every process sees
+ * the same ones.
+ */
+
+const TOKEN = process.env.DISCORD_TOKEN;
+if (!TOKEN) {
+  console.error('Missing DISCORD_TOKEN in .env');
+  process.exit(1);
+}
+if (process.env.STORAGE_BACKEND !== 'sqlite') {
+  console.error('Clustering needs STORAGE_BACKEND=sqlite, so every process sees the same settings and playlists');
+  process.exit(1);
+}
+
+const processCount = Number(process.env.CLUSTER_PROCESSES);
+// Run through ts-node (npm run cluster:dev) the shards are TypeScript too
+const runningTypeScript = path.extname(__filename) === '.ts';
+
+const manager = new ShardingManager(path.join(__dirname, `index${path.extname(__filename)}`), {
+  token: TOKEN,
+  totalShards: Number.isInteger(processCount) && processCount > 0 ? processCount : 'auto',
+  execArgv: runningTypeScript ? ['-r', 'ts-node/register'] : [],
+});
+
+manager.on('shardCreate', (shard) => {
+  console.log(`[CLUSTER] Started process for shard ${shard.id}`);
+  shard.on('death', () => console.error(`[CLUSTER] Process for shard ${shard.id} exited`));
+});
+
+function shutdown(signal: NodeJS.Signals): void {
+  console.log(`\n👋 ${signal} received, stopping every shard...`);
+  // Each shard saves its playback state on the way out, the same as a single process
+  manager.respawn = false;
+  for (const shard of manager.shards.values()) {
+    shard.process?.kill(signal);
+  }
+}
+
+process.on('SIGINT', () => shutdown('SIGINT'));
+process.on('SIGTERM', () => shutdown('SIGTERM'));
+
+manager.spawn().catch((error) => {
+  console.error('[CLUSTER] Failed to start shards:', error);
+  process.exit(1);
+});
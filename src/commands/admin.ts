@@ -0,0 +1,56 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { isBotOwner } from '../utils/permissions';
+import { YTDLP_MANAGED, getInstalledVersion, updateYtDlp } from '../utils/ytDlpBinary';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('admin')
+  .setDescription('Bot maintenance (bot owner only)')
+  .addSubcommand((subcommand) =>
+    subcommand.setName('update-ytdlp').setDescription('Install the latest yt-dlp release')
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  if (!isBotOwner(interaction.user.id)) {
+    await interaction.reply({ content: t('admin.ownerOnly'), ephemeral: true });
+    return;
+  }
+
+  if (!YTDLP_MANAGED) {
+    await interaction.reply({
+      content: t('admin.unmanaged'),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  await interaction.deferReply({ ephemeral: true });
+
+  try {
+    const result = await updateYtDlp();
+    await interaction.editReply(
+      result.updated
+        ? result.previousVersion
+          ? t('admin.updatedFrom', { version: result.version, previous: result.previousVersion })
+          : t('admin.installed', { version: result.version })
+        : t('admin.upToDate', { version: result.version })
+    );
+  } catch (error) {
+    console.error('yt-dlp update error:', error);
+    const installed = getInstalledVersion();
+    await interaction.editReply(
+      t('admin.updateFailed', { reason: error instanceof Error ? error.message : 'unknown error' }) +
+        (installed ? t('admin.stillUsing', { version: installed }) : '')
+    );
+  }
+}
@@ -0,0 +1,107 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { YouTubeUtil } from '../utils/youtubeUtil';
+import { findExtractor } from '../utils/sourceExtractor';
+import { normalizeAliasName } from '../utils/guildSettings';
+import { isAdmin } from '../utils/permissions';
+import { Translator } from '../utils/i18n';
+import { BotServices } from '../types/index';
+
+const MAX_ALIASES = 50;
+// Discord's limit on an embed description
+const MAX_LIST_LENGTH = 4096;
+
+export const data = new SlashCommandBuilder()
+  .setName('alias')
+  .setDescription('Manage this server\'s song shortcuts for /play')
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('add')
+      .setDescription('Save a shortcut name for a YouTube URL (admins only)')
+      .addStringOption((option) => option.setName('name').setDescription('Shortcut name').setRequired(true).setMaxLength(32))
+      .addStringOption((option) => option.setName('url').setDescription('YouTube URL').setRequired(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('remove')
+      .setDescription('Delete a shortcut (admins only)')
+      .addStringOption((option) => option.setName('name').setDescription('Shortcut name').setRequired(true))
+  )
+  .addSubcommand((subcommand) => subcommand.setName('list').setDescription('List this server\'s shortcuts'));
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const subcommand = interaction.options.getSubcommand();
+  const { aliases } = services.guildSettings.get(guildId);
+
+  if (subcommand === 'list') {
+    const names = Object.keys(aliases).sort();
+    const embed = new EmbedBuilder()
+      .setColor('#1DB954')
+      .setTitle(t('alias.title'))
+      .setDescription(names.length > 0 ? listAliases(names, aliases, t) : t('alias.empty'))
+      .setFooter({ text: t('alias.footer') });
+
+    await interaction.reply({ embeds: [embed], ephemeral: true });
+    return;
+  }
+
+  if (!isAdmin(interaction)) {
+    await interaction.reply({ content: t('alias.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  const name = normalizeAliasName(interaction.options.getString('name', true));
+
+  if (subcommand === 'add') {
+    const url = interaction.options.getString('url', true).trim();
+    if (!findExtractor(url)) {
+      await interaction.reply({ content: t('play.invalidUrl'), ephemeral: true });
+      return;
+    }
+    if (!name || YouTubeUtil.isUrl(name)) {
+      await interaction.reply({ content: t('alias.badName'), ephemeral: true });
+      return;
+    }
+    if (!services.guildSettings.resolveAlias(guildId, name) && Object.keys(aliases).length >= MAX_ALIASES) {
+      await interaction.reply({ content: t('alias.full', { max: MAX_ALIASES }), ephemeral: true });
+      return;
+    }
+
+    services.guildSettings.update(guildId, { aliases: { ...aliases, [name]: url } });
+    await interaction.reply(t('alias.added', { name, url }));
+    return;
+  }
+
+  if (subcommand === 'remove') {
+    if (!services.guildSettings.resolveAlias(guildId, name)) {
+      await interaction.reply({ content: t('alias.notFound', { name }), ephemeral: true });
+      return;
+    }
+
+    const { [name]: _removed, ...remaining } = aliases;
+    services.guildSettings.update(guildId, { aliases: remaining });
+    await interaction.reply(t('alias.removed', { name }));
+  }
+}
+
+/** One line per shortcut, ending with how many didn't fit when long URLs push the list past Discord's limit. */
+function listAliases(names: string[], aliases: Record<string, string>, t: Translator): string {
+  const lines = names.map((name) => `• **${name}** → ${aliases[name]}`);
+  let shown = lines.length;
+  let text = lines.join('\n');
+  while (text.length > MAX_LIST_LENGTH) {
+    shown--;
+    text = [...lines.slice(0, shown), t('alias.more', { count: lines.length - shown })].join('\n');
+  }
+  return text;
+}
@@ -0,0 +1,64 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { formatTrack } from '../utils/titleParser';
+import { playbackActors } from '../utils/guildActor';
+import { playbackEvents } from '../utils/playbackEvents';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('back')
+  .setDescription('Replay the previously played song');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const queue = queueManager.getQueue(guildId);
+
+  if (!queue.voiceConnection) {
+    await interaction.reply({
+      content: t('common.notInVoice'),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  // Runs in turn with other playback changes, so the current song can't change underneath it
+  await playbackActors.run(guildId, async () => {
+    const previousSong = queueManager.popPreviousSong(guildId);
+    if (!previousSong) {
+      await interaction.reply(t('back.noPrevious'));
+      return;
+    }
+
+    // Put the interrupted song back at the front so it plays again after the previous one
+    const currentSong = queueManager.getCurrentSong(guildId);
+    if (currentSong) {
+      queueManager.addSongToFront(guildId, currentSong);
+    }
+
+    queueManager.setCurrentSong(guildId, previousSong, false);
+    queueManager.setPlaying(guildId, true);
+    queueManager.setSongStartTime(guildId, Date.now());
+
+    const embed = new EmbedBuilder()
+      .setColor('#1DB954')
+      .setTitle(t('back.title'))
+      .setDescription(formatTrack(previousSong))
+      .setFooter({ text: t('common.requestedBy', { user: interaction.user.username }) })
+      .setTimestamp();
+
+    await interaction.reply({ embeds: [embed] });
+
+    await musicPlayer.playSong(queue.voiceConnection, previousSong);
+    startPlayerUpdate(guildId);
+    playbackEvents.emit('trackStarted', { guildId, song: previousSong, automatic: false });
+  });
+}
@@ -0,0 +1,123 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { describeBlacklistEntry, normalizeBlacklistValue } from '../utils/blacklist';
+import { isAdmin } from '../utils/permissions';
+import { BlacklistKind, BotServices } from '../types/index';
+
+const MAX_BLACKLIST_ENTRIES = 200;
+
+export const data = new SlashCommandBuilder()
+  .setName('blacklist')
+  .setDescription('Manage the songs that can\'t be queued on this server')
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('add')
+      .setDescription('Block a video, channel or title keyword (admins only)')
+      .addStringOption((option) =>
+        option
+          .setName('type')
+          .setDescription('What to match')
+          .setRequired(true)
+          .addChoices(
+            { name: 'Video', value: 'video' },
+            { name: 'Channel', value: 'channel' },
+            { name: 'Title keyword', value: 'keyword' }
+          )
+      )
+      .addStringOption((option) =>
+        option
+          .setName('value')
+          .setDescription('Video URL or ID, channel URL, ID or name, or a word or phrase')
+          .setRequired(true)
+          .setMaxLength(200)
+      )
+      .addStringOption((option) =>
+        option.setName('reason').setDescription('Shown to people whose songs are refused').setMaxLength(200)
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('remove')
+      .setDescription('Unblock something (admins only)')
+      .addStringOption((option) =>
+        option
+          .setName('type')
+          .setDescription('What the entry matches')
+          .setRequired(true)
+          .addChoices(
+            { name: 'Video', value: 'video' },
+            { name: 'Channel', value: 'channel' },
+            { name: 'Title keyword', value: 'keyword' }
+          )
+      )
+      .addStringOption((option) => option.setName('value').setDescription('As it was added').setRequired(true))
+  )
+  .addSubcommand((subcommand) => subcommand.setName('list').setDescription('List what\'s blocked on this server'));
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const subcommand = interaction.options.getSubcommand();
+  const { blacklist } = services.guildSettings.get(guildId);
+
+  if (subcommand === 'list') {
+    const embed = new EmbedBuilder()
+      .setColor('#1DB954')
+      .setTitle(t('blacklist.title'))
+      .setDescription(
+        blacklist.length > 0
+          ? blacklist.map((entry) => `• ${describeBlacklistEntry(entry)}`).join('\n').slice(0, 4000)
+          : t('blacklist.empty')
+      );
+
+    await interaction.reply({ embeds: [embed], ephemeral: true });
+    return;
+  }
+
+  if (!isAdmin(interaction)) {
+    await interaction.reply({ content: t('blacklist.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  const kind = interaction.options.getString('type', true) as BlacklistKind;
+  const value = normalizeBlacklistValue(kind, interaction.options.getString('value', true));
+  if (!value) {
+    await interaction.reply({ content: kind === 'keyword' ? t('blacklist.invalidKeyword') : t('blacklist.invalidId', { kind }), ephemeral: true });
+    return;
+  }
+  const existing = blacklist.find((entry) => entry.kind === kind && entry.value === value);
+
+  if (subcommand === 'add') {
+    if (existing) {
+      await interaction.reply({ content: t('blacklist.alreadyBlocked', { entry: describeBlacklistEntry(existing) }), ephemeral: true });
+      return;
+    }
+    if (blacklist.length >= MAX_BLACKLIST_ENTRIES) {
+      await interaction.reply({ content: t('blacklist.full', { max: MAX_BLACKLIST_ENTRIES }), ephemeral: true });
+      return;
+    }
+
+    const entry = { kind, value, reason: interaction.options.getString('reason') ?? undefined, addedBy: interaction.user.id };
+    services.guildSettings.update(guildId, { blacklist: [...blacklist, entry] });
+    await interaction.reply(t('blacklist.added', { entry: describeBlacklistEntry(entry) }));
+    return;
+  }
+
+  if (subcommand === 'remove') {
+    if (!existing) {
+      await interaction.reply({ content: t('blacklist.notFound', { kind, value }), ephemeral: true });
+      return;
+    }
+
+    services.guildSettings.update(guildId, { blacklist: blacklist.filter((entry) => entry !== existing) });
+    await interaction.reply(t('blacklist.removed', { entry: describeBlacklistEntry(existing) }));
+  }
+}
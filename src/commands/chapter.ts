@@ -0,0 +1,78 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { YouTubeUtil } from '../utils/youtubeUtil';
+import { formatChapter, getChapterAt } from '../utils/chapters';
+import { seekTo } from '../utils/playback';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('chapter')
+  .setDescription('Jump between chapters of the current video')
+  .addSubcommand((subcommand) => subcommand.setName('next').setDescription('Skip to the next chapter'))
+  .addSubcommand((subcommand) => subcommand.setName('list').setDescription('List the chapters'))
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('goto')
+      .setDescription('Jump to a chapter by number')
+      .addIntegerOption((option) =>
+        option.setName('number').setDescription('Chapter number, as shown by /chapter list').setRequired(true).setMinValue(1)
+      )
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const currentSong = queueManager.getCurrentSong(guildId);
+
+  if (!currentSong) {
+    await interaction.reply(t('common.nothingPlaying'));
+    return;
+  }
+
+  const chapters = currentSong.chapters ?? [];
+  if (chapters.length === 0) {
+    await interaction.reply({ content: t('chapter.none', { track: currentSong.title }), ephemeral: true });
+    return;
+  }
+
+  const elapsed = queueManager.getSongElapsedTime(guildId);
+  const currentIndex = getChapterAt(currentSong, elapsed)?.index ?? -1;
+  const subcommand = interaction.options.getSubcommand();
+
+  if (subcommand === 'list') {
+    const lines = chapters.map(
+      (chapter, index) =>
+        `${index === currentIndex ? '▶️' : `\`${index + 1}.\``} \`${YouTubeUtil.formatDuration(chapter.start)}\` ${chapter.title}`
+    );
+
+    const embed = new EmbedBuilder()
+      .setColor('#1DB954')
+      .setTitle(t('play.chapters'))
+      .setDescription(lines.join('\n').slice(0, 4096))
+      .setFooter({ text: currentSong.title });
+
+    await interaction.reply({ embeds: [embed], ephemeral: true });
+    return;
+  }
+
+  const targetIndex = subcommand === 'next' ? currentIndex + 1 : interaction.options.getInteger('number', true) - 1;
+  if (targetIndex >= chapters.length) {
+    await interaction.reply({
+      content: subcommand === 'next' ? t('chapter.last') : t('chapter.onlyCount', { count: chapters.length }),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  await interaction.deferReply();
+  await seekTo(guildId, queueManager, musicPlayer, chapters[targetIndex].start);
+  await interaction.editReply(t('chapter.jumped', { chapter: formatChapter(currentSong, targetIndex) }));
+}
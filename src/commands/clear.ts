@@ -0,0 +1,45 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { hasDjRole, isInBotVoiceChannel } from '../utils/permissions';
+import { describeVote, getVoiceListenerIds } from '../utils/votes';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('clear')
+  .setDescription('Empty the queue but keep the current song playing');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (queueManager.getQueueSize(guildId) === 0) {
+    await interaction.reply({ content: t('common.queueEmpty'), ephemeral: true });
+    return;
+  }
+
+  if (!hasDjRole(interaction, services.guildSettings.get(guildId))) {
+    if (!isInBotVoiceChannel(interaction)) {
+      await interaction.reply({ content: t('clear.joinToVote'), ephemeral: true });
+      return;
+    }
+    const vote = services.votes.castVote(guildId, 'clear', interaction.user.id, getVoiceListenerIds(interaction.guild));
+    if (!vote.passed) {
+      await interaction.reply(describeVote(t('common.voteClear'), vote, t));
+      return;
+    }
+  }
+  services.votes.reset(guildId);
+
+  const removed = queueManager.clearUpcoming(guildId);
+  await interaction.reply(
+    queueManager.getCurrentSong(guildId) ? t('clear.done', { count: removed }) : t('clear.doneIdle', { count: removed })
+  );
+}
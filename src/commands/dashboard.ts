@@ -0,0 +1,42 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { isAdmin } from '../utils/permissions';
+import { dashboardSessions } from '../utils/dashboard';
+import { webServer } from '../utils/webServer';
+import { BotServices, FeatureFlag } from '../types/index';
+
+export const feature: FeatureFlag = 'dashboard';
+
+export const data = new SlashCommandBuilder()
+  .setName('dashboard')
+  .setDescription('Get a link to manage the queue and settings in your browser (admins only)');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  if (!isAdmin(interaction)) {
+    await interaction.reply({ content: t('dashboard.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  if (!webServer) {
+    await interaction.reply({
+      content: t('dashboard.notRunning'),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  const token = dashboardSessions.create(interaction.guildId!, interaction.user.id);
+  await interaction.reply({
+    content: t('dashboard.link', { url: `${webServer.publicUrl}/dashboard#${token}` }),
+    ephemeral: true,
+  });
+}
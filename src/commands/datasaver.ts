@@ -0,0 +1,41 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { describeBandwidth } from '../utils/bandwidth';
+import { isAdmin } from '../utils/permissions';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('datasaver')
+  .setDescription('Toggle low-bitrate streaming for hosts on metered connections (admins only)');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (!isAdmin(interaction)) {
+    await interaction.reply({ content: t('dataSaver.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  const enabled = !services.guildSettings.get(guildId).dataSaver;
+  services.guildSettings.update(guildId, { dataSaver: enabled });
+  musicPlayer.setDataSaver(enabled);
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(enabled ? t('dataSaver.on') : t('dataSaver.off'))
+    .setDescription(enabled ? t('dataSaver.onDescription') : t('dataSaver.offDescription'))
+    .addFields({ name: t('dataSaver.sessionSoFar'), value: describeBandwidth(musicPlayer.getBandwidthUsage(), t), inline: false })
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
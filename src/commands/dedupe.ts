@@ -0,0 +1,28 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('dedupe')
+  .setDescription('Remove songs that are in the queue more than once, keeping the first of each');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (queueManager.getQueueSize(guildId) === 0) {
+    await interaction.reply({ content: t('common.queueEmpty'), ephemeral: true });
+    return;
+  }
+
+  const removed = queueManager.removeDuplicates(guildId);
+  await interaction.reply(removed === 0 ? t('dedupe.none') : t('dedupe.done', { count: removed }));
+}
@@ -0,0 +1,82 @@
+import { AttachmentBuilder, SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { ExportFormat, exportSongs, toExportFileName } from '../utils/playlistExport';
+import { BotServices } from '../types/index';
+
+// Discord allows at most 10 attachments per message
+const MAX_ATTACHMENTS = 10;
+
+export const data = new SlashCommandBuilder()
+  .setName('export')
+  .setDescription('Export the queue or your playlists as a file')
+  .addStringOption((option) =>
+    option
+      .setName('source')
+      .setDescription('What to export')
+      .addChoices({ name: 'Current queue', value: 'queue' }, { name: 'My playlists', value: 'playlists' })
+  )
+  .addStringOption((option) =>
+    option
+      .setName('format')
+      .setDescription('File format')
+      .addChoices({ name: 'JSON', value: 'json' }, { name: 'M3U', value: 'm3u' })
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const source = interaction.options.getString('source') ?? 'queue';
+  const format = (interaction.options.getString('format') ?? 'json') as ExportFormat;
+
+  if (source === 'playlists') {
+    const playlists = services.playlistManager.getPlaylists(guildId, interaction.user.id);
+    if (playlists.length === 0) {
+      await interaction.reply({ content: t('export.noPlaylists'), ephemeral: true });
+      return;
+    }
+
+    const files = playlists
+      .slice(0, MAX_ATTACHMENTS)
+      .map((playlist) =>
+        new AttachmentBuilder(Buffer.from(exportSongs(playlist.name, playlist.songs, format)), {
+          name: toExportFileName(playlist.name, format),
+        })
+      );
+
+    const skipped = playlists.length - files.length;
+    await interaction.reply({
+      content:
+        (files.length === 1 ? t('export.playlistsOne') : t('export.playlists', { count: files.length })) +
+        (skipped > 0 ? ` ${t('export.playlistsSkipped', { count: skipped })}` : ''),
+      files,
+      ephemeral: true,
+    });
+    return;
+  }
+
+  const currentSong = queueManager.getCurrentSong(guildId);
+  const songs = [...(currentSong ? [currentSong] : []), ...queueManager.getFullQueue(guildId)];
+
+  if (songs.length === 0) {
+    await interaction.reply({ content: t('common.queueEmpty'), ephemeral: true });
+    return;
+  }
+
+  const name = `${interaction.guild?.name ?? 'queue'}-queue`;
+  const file = new AttachmentBuilder(Buffer.from(exportSongs(name, songs, format)), {
+    name: toExportFileName(name, format),
+  });
+
+  await interaction.reply({
+    content: songs.length === 1 ? t('export.queueOne') : t('export.queue', { count: songs.length }),
+    files: [file],
+  });
+}
@@ -0,0 +1,72 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { FEATURE_FLAGS } from '../utils/guildSettings';
+import { isBotOwner } from '../utils/permissions';
+import { BotServices, FeatureFlag } from '../types/index';
+
+const featureChoices = (Object.keys(FEATURE_FLAGS) as FeatureFlag[]).map((feature) => ({ name: feature, value: feature }));
+
+export const data = new SlashCommandBuilder()
+  .setName('feature')
+  .setDescription('Manage experimental features for this server (bot owner only)')
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('enable')
+      .setDescription('Enable an experimental feature')
+      .addStringOption((option) =>
+        option.setName('feature').setDescription('Feature to enable').setRequired(true).addChoices(...featureChoices)
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('disable')
+      .setDescription('Disable an experimental feature')
+      .addStringOption((option) =>
+        option.setName('feature').setDescription('Feature to disable').setRequired(true).addChoices(...featureChoices)
+      )
+  )
+  .addSubcommand((subcommand) => subcommand.setName('list').setDescription('Show which features are enabled'));
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (!isBotOwner(interaction.user.id)) {
+    await interaction.reply({ content: t('feature.ownerOnly'), ephemeral: true });
+    return;
+  }
+
+  const subcommand = interaction.options.getSubcommand();
+
+  if (subcommand === 'list') {
+    const lines = (Object.keys(FEATURE_FLAGS) as FeatureFlag[]).map((feature) => {
+      const enabled = services.guildSettings.isFeatureEnabled(guildId, feature);
+      return `${enabled ? '🟢' : '⚪'} **${feature}** — ${FEATURE_FLAGS[feature]}`;
+    });
+
+    const embed = new EmbedBuilder()
+      .setColor('#1DB954')
+      .setTitle(t('feature.title'))
+      .setDescription(lines.join('\n'));
+
+    await interaction.reply({ embeds: [embed], ephemeral: true });
+    return;
+  }
+
+  const feature = interaction.options.getString('feature', true) as FeatureFlag;
+  const enabled = subcommand === 'enable';
+  services.guildSettings.setFeatureEnabled(guildId, feature, enabled);
+
+  await interaction.reply({
+    content: enabled ? t('feature.enabled', { feature }) : t('feature.disabled', { feature }),
+    ephemeral: true,
+  });
+}
@@ -0,0 +1,64 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { YouTubeUtil } from '../utils/youtubeUtil';
+import { seekBy } from '../utils/playback';
+import { formatTrack } from '../utils/titleParser';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('ff')
+  .setDescription('Fast-forward the current song')
+  .addIntegerOption((option) =>
+    option.setName('seconds').setDescription('How far to jump ahead (default 10)').setMinValue(1).setMaxValue(3600)
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  await seekRelative(interaction, queueManager, musicPlayer, services, interaction.options.getInteger('seconds') ?? 10);
+}
+
+/** /ff and /rw: jumps `seconds` from the current position (back when negative) and says where it landed. */
+export async function seekRelative(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  services: BotServices,
+  seconds: number
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const currentSong = queueManager.getCurrentSong(guildId);
+
+  if (!currentSong) {
+    await interaction.reply(t('common.nothingPlaying'));
+    return;
+  }
+  if (currentSong.duration <= 0) {
+    await interaction.reply({ content: t('seek.notSeekable'), ephemeral: true });
+    return;
+  }
+
+  await interaction.deferReply();
+  const position = await seekBy(guildId, queueManager, musicPlayer, seconds);
+  if (position === undefined) {
+    // The song ended while the reply was deferred
+    await interaction.editReply(t('common.nothingPlaying'));
+    return;
+  }
+
+  await interaction.editReply(
+    t(seconds > 0 ? 'seek.forward' : 'seek.back', {
+      seconds: Math.abs(seconds),
+      track: formatTrack(currentSong),
+      position: YouTubeUtil.formatDuration(position),
+      duration: YouTubeUtil.formatDuration(currentSong.duration),
+    })
+  );
+}
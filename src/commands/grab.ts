@@ -0,0 +1,66 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { formatTrack } from '../utils/titleParser';
+import { YouTubeUtil } from '../utils/youtubeUtil';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('grab')
+  .setDescription('Send the current song to your DMs');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const currentSong = queueManager.getCurrentSong(guildId);
+
+  if (!currentSong) {
+    await interaction.reply({ content: t('common.nothingPlaying'), ephemeral: true });
+    return;
+  }
+
+  const elapsed = queueManager.getSongElapsedTime(guildId);
+  const position = currentSong.duration > 0
+    ? `${YouTubeUtil.formatDuration(elapsed)} / ${YouTubeUtil.formatDuration(currentSong.duration)}`
+    : YouTubeUtil.formatDuration(elapsed);
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('grab.title'))
+    .setDescription(`**[${formatTrack(currentSong)}](${currentSong.url})**`)
+    .addFields(
+      {
+        name: t('grab.position'),
+        value: position,
+        inline: true,
+      },
+      {
+        name: t('grab.server'),
+        value: interaction.guild?.name || t('grab.unknownServer'),
+        inline: true,
+      }
+    )
+    .setTimestamp();
+
+  if (currentSong.thumbnail) {
+    embed.setThumbnail(currentSong.thumbnail);
+  }
+
+  try {
+    await interaction.user.send({ embeds: [embed] });
+    await interaction.reply({ content: t('grab.sent'), ephemeral: true });
+  } catch (error) {
+    console.error('Error sending grab DM:', error);
+    await interaction.reply({
+      content: t('grab.dmFailed'),
+      ephemeral: true,
+    });
+  }
+}
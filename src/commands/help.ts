@@ -0,0 +1,215 @@
+import {
+  SlashCommandBuilder,
+  ChatInputCommandInteraction,
+  MessageComponentInteraction,
+  ActionRowBuilder,
+  ApplicationCommandOptionType,
+  ApplicationCommandType,
+  APIApplicationCommandOption,
+  ButtonBuilder,
+  ButtonStyle,
+  StringSelectMenuBuilder,
+} from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { RegisteredCommand, getCommands } from '../utils/commandRegistry';
+import { createThemedEmbed } from '../utils/embedTheme';
+import { MessageKey, Translator } from '../utils/i18n';
+import { BotServices, GuildSettings } from '../types/index';
+
+const ENTRIES_PER_PAGE = 8;
+
+type HelpCategory = 'playback' | 'queue' | 'playlists' | 'stats' | 'server' | 'other';
+
+const CATEGORIES: Record<HelpCategory, { label: MessageKey; emoji: string; description: MessageKey }> = {
+  playback: { label: 'help.playback', emoji: '🎵', description: 'help.playbackDescription' },
+  queue: { label: 'help.queue', emoji: '📋', description: 'help.queueDescription' },
+  playlists: { label: 'help.playlists', emoji: '💾', description: 'help.playlistsDescription' },
+  stats: { label: 'help.stats', emoji: '📊', description: 'help.statsDescription' },
+  server: { label: 'help.server', emoji: '⚙️', description: 'help.serverDescription' },
+  other: { label: 'help.other', emoji: '🧩', description: 'help.otherDescription' },
+};
+
+// Commands not listed here still show up, under Other
+const COMMAND_CATEGORIES: Record<string, HelpCategory> = {
+  play: 'playback',
+  pause: 'playback',
+  resume: 'playback',
+  skip: 'playback',
+  back: 'playback',
+  stop: 'playback',
+  join: 'playback',
+  summon: 'playback',
+  leave: 'playback',
+  loop: 'playback',
+  chapter: 'playback',
+  ff: 'playback',
+  rw: 'playback',
+  replay: 'playback',
+  speed: 'playback',
+  nightcore: 'playback',
+  myvolume: 'playback',
+  notifyme: 'playback',
+  karaoke: 'playback',
+  grab: 'playback',
+  intro: 'playback',
+  queue: 'queue',
+  shuffle: 'queue',
+  dedupe: 'queue',
+  clear: 'queue',
+  mix: 'queue',
+  remove: 'queue',
+  move: 'queue',
+  insights: 'queue',
+  suggest: 'queue',
+  playlist: 'playlists',
+  import: 'playlists',
+  export: 'playlists',
+  history: 'stats',
+  stats: 'stats',
+  top: 'stats',
+  settings: 'server',
+  alias: 'server',
+  blacklist: 'server',
+  lock: 'server',
+  unlock: 'server',
+  volume: 'server',
+  style: 'server',
+  normalize: 'server',
+  datasaver: 'server',
+  idletimeout: 'server',
+  voicepolicy: 'server',
+  dashboard: 'server',
+  feature: 'server',
+  admin: 'server',
+};
+
+interface HelpEntry {
+  usage: string;
+  description: string;
+}
+
+export const data = new SlashCommandBuilder()
+  .setName('help')
+  .setDescription('Browse the commands, by category');
+
+/** `<required> [optional]` for a command's or subcommand's options. */
+function formatOptions(options: APIApplicationCommandOption[] | undefined): string {
+  return (options ?? []).map((option) => (option.required ? ` <${option.name}>` : ` [${option.name}]`)).join('');
+}
+
+/** One entry per way of calling the command: each subcommand, or the command itself. */
+function describeCommand(command: RegisteredCommand): HelpEntry[] {
+  const entries: HelpEntry[] = [];
+  for (const option of command.options) {
+    if (option.type === ApplicationCommandOptionType.Subcommand) {
+      entries.push({ usage: `/${command.name} ${option.name}${formatOptions(option.options)}`, description: option.description });
+    } else if (option.type === ApplicationCommandOptionType.SubcommandGroup) {
+      for (const subcommand of option.options ?? []) {
+        entries.push({
+          usage: `/${command.name} ${option.name} ${subcommand.name}${formatOptions(subcommand.options)}`,
+          description: subcommand.description,
+        });
+      }
+    }
+  }
+  if (entries.length === 0) {
+    entries.push({ usage: `/${command.name}${formatOptions(command.options)}`, description: command.description });
+  }
+  return entries;
+}
+
+/** The guild's usable commands by category; turned-off and experimental ones it hasn't enabled are left out. */
+function collectEntries(settings: GuildSettings): Map<HelpCategory, HelpEntry[]> {
+  const byCategory = new Map<HelpCategory, HelpEntry[]>();
+  for (const command of getCommands()) {
+    // Right-click commands have no usage to show; the README covers them
+    if (command.type !== ApplicationCommandType.ChatInput) continue;
+    if (settings.disabledCommands.includes(command.name)) continue;
+    if (command.feature && settings.features[command.feature] !== true) continue;
+
+    const category = COMMAND_CATEGORIES[command.name] ?? 'other';
+    byCategory.set(category, [...(byCategory.get(category) ?? []), ...describeCommand(command)]);
+  }
+  return byCategory;
+}
+
+function buildHelpMessage(settings: GuildSettings, t: Translator, requested: HelpCategory, requestedPage: number) {
+  const byCategory = collectEntries(settings);
+  const categories = (Object.keys(CATEGORIES) as HelpCategory[]).filter((category) => byCategory.has(category));
+  const category = byCategory.has(requested) ? requested : categories[0];
+  const entries = byCategory.get(category) ?? [];
+  const pageCount = Math.max(1, Math.ceil(entries.length / ENTRIES_PER_PAGE));
+  // The command list can change between clicks, e.g. when an admin turns a command off
+  const page = Math.min(Math.max(0, requestedPage), pageCount - 1);
+  const { label, emoji, description } = CATEGORIES[category];
+
+  const embed = createThemedEmbed(settings.theme, t('help.footer', { page: page + 1, pages: pageCount }))
+    .setTitle(`${emoji} ${t(label)}`)
+    .setDescription(
+      [
+        `*${t(description)}*`,
+        ...entries
+          .slice(page * ENTRIES_PER_PAGE, (page + 1) * ENTRIES_PER_PAGE)
+          .map((entry) => `**\`${entry.usage}\`**\n${entry.description}`),
+      ].join('\n\n')
+    );
+
+  const menuRow = new ActionRowBuilder<StringSelectMenuBuilder>().addComponents(
+    new StringSelectMenuBuilder()
+      .setCustomId('help:category')
+      .setPlaceholder(t('help.chooseCategory'))
+      .addOptions(
+        categories.map((value) => ({
+          label: t(CATEGORIES[value].label),
+          emoji: CATEGORIES[value].emoji,
+          description: t(CATEGORIES[value].description),
+          value,
+          default: value === category,
+        }))
+      )
+  );
+  const pageRow = new ActionRowBuilder<ButtonBuilder>().addComponents(
+    new ButtonBuilder()
+      .setCustomId(`help:page:${category}:${page - 1}`)
+      .setEmoji('◀️')
+      .setStyle(ButtonStyle.Secondary)
+      .setDisabled(page === 0),
+    new ButtonBuilder()
+      .setCustomId(`help:page:${category}:${page + 1}`)
+      .setEmoji('▶️')
+      .setStyle(ButtonStyle.Secondary)
+      .setDisabled(page >= pageCount - 1)
+  );
+
+  return { embeds: [embed], components: [menuRow, pageRow] };
+}
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const settings = services.guildSettings.get(interaction.guildId!);
+  const t = services.guildSettings.translator(interaction.guildId!);
+  await interaction.reply({ ...buildHelpMessage(settings, t, 'playback', 0), ephemeral: true });
+}
+
+/** The category menu (`help:category`) and page buttons (`help:page:<category>:<page>`). */
+export async function handleComponent(interaction: MessageComponentInteraction, services: BotServices): Promise<void> {
+  const settings = services.guildSettings.get(interaction.guildId!);
+  const t = services.guildSettings.translator(interaction.guildId!);
+
+  if (interaction.isStringSelectMenu() && interaction.customId === 'help:category') {
+    await interaction.update(buildHelpMessage(settings, t, interaction.values[0] as HelpCategory, 0));
+    return;
+  }
+
+  const [, action, category, page] = interaction.customId.split(':');
+  if (interaction.isButton() && action === 'page') {
+    await interaction.update(buildHelpMessage(settings, t, category as HelpCategory, Number(page)));
+  }
+}
@@ -0,0 +1,145 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueLimitError, QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { resolveSong } from '../utils/sourceExtractor';
+import { startPlayback } from '../utils/playback';
+import { getQueueLimits } from '../utils/permissions';
+import { describeBlockedSong, findBlacklistMatch } from '../utils/blacklist';
+import { SongStats } from '../utils/listeningStats';
+import { formatTrack } from '../utils/titleParser';
+import { describeError } from '../utils/errors';
+import { BotServices } from '../types/index';
+
+const RESULTS_SHOWN = 10;
+// Numbers from a search stay usable with /history play for this long
+const RESULTS_TTL_MS = 15 * 60 * 1000;
+
+// Each member's latest search, by guild and user
+const lastResults = new Map<string, { songs: SongStats[]; at: number }>();
+
+export const data = new SlashCommandBuilder()
+  .setName('history')
+  .setDescription('Search everything this server has played')
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('search')
+      .setDescription('Find past plays by title or channel; typos are fine')
+      .addStringOption((option) => option.setName('text').setDescription('What to look for').setRequired(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('play')
+      .setDescription('Queue a result from your last /history search')
+      .addIntegerOption((option) =>
+        option.setName('number').setDescription('Its number in the results').setRequired(true).setMinValue(1)
+      )
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  if (interaction.options.getSubcommand() === 'search') {
+    await searchHistory(interaction, services);
+    return;
+  }
+  await playFromResults(interaction, queueManager, musicPlayer, services);
+}
+
+async function searchHistory(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const text = interaction.options.getString('text', true);
+  const songs = services.listeningStats.searchPlayed(interaction.guildId!, text, RESULTS_SHOWN);
+
+  if (songs.length === 0) {
+    await interaction.reply({ content: t('history.noMatches', { text }), ephemeral: true });
+    return;
+  }
+  lastResults.set(`${interaction.guildId}:${interaction.user.id}`, { songs, at: Date.now() });
+
+  const lines = songs.map((song, index) => {
+    const details = [song.channel, t('history.plays', { count: song.plays })];
+    if (song.lastPlayedAt) details.push(t('history.lastPlayed', { time: `<t:${Math.floor(song.lastPlayedAt / 1000)}:R>` }));
+    return `${index + 1}. [${song.title.slice(0, 80)}](${song.url})\n   ${details.filter(Boolean).join(' · ')}`;
+  });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('history.title', { text: text.slice(0, 50) }))
+    .setDescription(lines.join('\n'))
+    .setFooter({ text: t('history.footer') });
+
+  await interaction.reply({ embeds: [embed], ephemeral: true });
+}
+
+async function playFromResults(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const number = interaction.options.getInteger('number', true);
+  const results = lastResults.get(`${guildId}:${interaction.user.id}`);
+
+  if (!results || Date.now() - results.at > RESULTS_TTL_MS) {
+    await interaction.reply({ content: t('history.searchFirst'), ephemeral: true });
+    return;
+  }
+  const result = results.songs[number - 1];
+  if (!result) {
+    await interaction.reply({ content: t('history.noSuchResult', { count: results.songs.length }), ephemeral: true });
+    return;
+  }
+
+  const member = interaction.member as any;
+  if (!member?.voice?.channel) {
+    await interaction.reply(t('common.joinVoiceFirst'));
+    return;
+  }
+
+  await interaction.deferReply();
+  const song = await resolveSong(result.url, interaction.user.id, guildId);
+  if (!song) {
+    await interaction.editReply(t('history.gone', { track: result.title }));
+    return;
+  }
+
+  const settings = services.guildSettings.get(guildId);
+  const blockedBy = findBlacklistMatch(song, settings.blacklist);
+  if (blockedBy) {
+    await interaction.editReply(describeBlockedSong(song, blockedBy, t));
+    return;
+  }
+
+  try {
+    queueManager.addSong(guildId, song, getQueueLimits(interaction, settings));
+  } catch (error) {
+    if (!(error instanceof QueueLimitError)) throw error;
+    await interaction.editReply(describeError(error, t));
+    return;
+  }
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('play.addedToQueue'))
+    .setDescription(`**${formatTrack(song)}**`)
+    .addFields({
+      name: t('play.position'),
+      value: t('play.positionInQueue', { position: queueManager.getQueueSize(guildId) }),
+      inline: true,
+    })
+    .setFooter({ text: t('common.addedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+
+  if (!queueManager.isPlaying(guildId)) {
+    await startPlayback(interaction, queueManager, musicPlayer);
+  }
+}
@@ -0,0 +1,45 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { MAX_IDLE_TIMEOUT_MINUTES } from '../utils/idleTracker';
+import { isAdmin } from '../utils/permissions';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('idletimeout')
+  .setDescription('Leave voice after a while with nothing playing (admins only)')
+  .addIntegerOption((option) =>
+    option
+      .setName('minutes')
+      .setDescription('Minutes of an empty queue or pause before leaving; 0 turns it off')
+      .setRequired(true)
+      .setMinValue(0)
+      .setMaxValue(MAX_IDLE_TIMEOUT_MINUTES)
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  if (!isAdmin(interaction)) {
+    await interaction.reply({ content: t('idleTimeout.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  const minutes = interaction.options.getInteger('minutes', true);
+  services.guildSettings.update(interaction.guildId!, { idleTimeoutMinutes: minutes });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(minutes > 0 ? t('idleTimeout.set', { minutes }) : t('idleTimeout.off'))
+    .setDescription(minutes > 0 ? t('idleTimeout.setDescription', { minutes }) : t('idleTimeout.offDescription'))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
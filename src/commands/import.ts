@@ -0,0 +1,189 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueLimitError, QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { PlaylistError } from '../utils/playlistManager';
+import { startPlayback } from '../utils/playback';
+import { getQueueLimits } from '../utils/permissions';
+import { checkExtractionLimit } from '../utils/rateLimiter';
+import { filterBlacklisted } from '../utils/blacklist';
+import {
+  ImportEntry,
+  MAX_IMPORT_ENTRIES,
+  detectImportFormat,
+  formatImportFailures,
+  formatImportProgress,
+  parseImportFile,
+  parsePastedList,
+  resolveImportEntries,
+} from '../utils/playlistImport';
+import { ProgressMessage } from '../utils/progressMessage';
+import { describeError } from '../utils/errors';
+import { BotServices } from '../types/index';
+
+const MAX_IMPORT_FILE_BYTES = 512 * 1024;
+const MAX_REPORTED_FAILURES = 10;
+
+export const data = new SlashCommandBuilder()
+  .setName('import')
+  .setDescription('Bulk import songs from a playlist file or a list of URLs')
+  .addAttachmentOption((option) =>
+    option.setName('file').setDescription('An .m3u, .json, .csv or .txt file with one song per line')
+  )
+  .addStringOption((option) =>
+    option.setName('urls').setDescription('YouTube URLs separated by spaces or commas')
+  )
+  .addStringOption((option) =>
+    option.setName('playlist').setDescription('Save into this playlist instead of the queue').setAutocomplete(true)
+  );
+
+export { autocomplete } from './playlist';
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const userId = interaction.user.id;
+  const file = interaction.options.getAttachment('file');
+  const urls = interaction.options.getString('urls');
+  const playlistName = interaction.options.getString('playlist');
+
+  if (!file && !urls) {
+    await interaction.reply({ content: t('import.nothingGiven'), ephemeral: true });
+    return;
+  }
+
+  if (playlistName && !services.playlistManager.getPlaylist(guildId, userId, playlistName)) {
+    await interaction.reply({ content: `❌ ${t('playlist.notFound', { name: playlistName })}`, ephemeral: true });
+    return;
+  }
+
+  const member = interaction.member as any;
+  if (!playlistName && !member?.voice?.channel) {
+    await interaction.reply(t('import.joinVoiceFirst'));
+    return;
+  }
+
+  if (file && file.size > MAX_IMPORT_FILE_BYTES) {
+    await interaction.reply({ content: t('import.fileTooBig', { kb: MAX_IMPORT_FILE_BYTES / 1024 }), ephemeral: true });
+    return;
+  }
+
+  if (!(await checkExtractionLimit(interaction, services))) return;
+  await interaction.deferReply();
+
+  let entries: ImportEntry[] = [];
+  try {
+    if (file) {
+      const response = await fetch(file.url);
+      if (!response.ok) {
+        throw new Error(`Download failed with status ${response.status}`);
+      }
+      entries = parseImportFile(await response.text(), detectImportFormat(file.name));
+    }
+    if (urls) {
+      // Number pasted entries after the file's so each line number stays unique
+      const offset = entries.length;
+      entries.push(...parsePastedList(urls).map((entry) => ({ ...entry, line: entry.line + offset })));
+    }
+  } catch (error) {
+    await interaction.editReply(
+      t('import.unreadable', { reason: error instanceof Error ? error.message : String(error) })
+    );
+    return;
+  }
+
+  if (entries.length === 0) {
+    await interaction.editReply(t('import.noSongs'));
+    return;
+  }
+  if (entries.length > MAX_IMPORT_ENTRIES) {
+    await interaction.editReply(t('import.tooMany', { max: MAX_IMPORT_ENTRIES, count: entries.length }));
+    return;
+  }
+
+  const limits = playlistName ? undefined : getQueueLimits(interaction, services.guildSettings.get(guildId));
+  try {
+    // Checked up front too, so a long import isn't resolved only to be turned away
+    queueManager.checkLimits(guildId, userId, entries.length, limits);
+  } catch (error) {
+    if (!(error instanceof QueueLimitError)) throw error;
+    await interaction.editReply(describeError(error, t));
+    return;
+  }
+
+  await interaction.editReply(t('import.resolving', { count: entries.length }));
+  // Resolving can take minutes; if the queue is cleared meanwhile, this import is cancelled
+  const generation = queueManager.getGeneration(guildId);
+  const progress = new ProgressMessage((content) => interaction.editReply(content));
+  const resolved = await resolveImportEntries(entries, userId, guildId, (update) =>
+    progress.update(formatImportProgress(update, t))
+  );
+  await progress.stop();
+  const { failures } = resolved;
+  // Saving to a playlist is fine; the blacklist is checked again when it's played
+  const { allowed, blocked } = playlistName
+    ? { allowed: resolved.songs, blocked: [] }
+    : filterBlacklisted(resolved.songs, services.guildSettings.get(guildId).blacklist);
+  // Playlists can hold a song twice on purpose; only the queue is deduplicated
+  const songs = playlistName ? allowed : queueManager.withoutQueued(guildId, allowed);
+
+  let destination = t('import.intoQueue');
+  let imported = songs.length;
+  if (songs.length > 0) {
+    if (playlistName) {
+      try {
+        const playlist = services.playlistManager.addSongs(guildId, userId, playlistName, songs);
+        destination = t('import.intoPlaylist', { name: playlist.name });
+      } catch (error) {
+        if (!(error instanceof PlaylistError)) throw error;
+        await interaction.editReply(describeError(error, t));
+        return;
+      }
+    } else {
+      try {
+        imported = queueManager.addSongs(guildId, songs, generation, limits);
+        if (imported === 0) {
+          await interaction.editReply(t('import.cleared'));
+          return;
+        }
+      } catch (error) {
+        if (!(error instanceof QueueLimitError)) throw error;
+        await interaction.editReply(describeError(error, t));
+        return;
+      }
+    }
+  }
+
+  let description = t('import.summary', { imported, total: entries.length, destination });
+  const skipped = [
+    blocked.length > 0 ? t('play.skippedBlocked', { count: blocked.length }) : '',
+    allowed.length > songs.length ? t('play.skippedQueued', { count: allowed.length - songs.length }) : '',
+    imported < songs.length ? t('play.skippedTooLong', { count: songs.length - imported }) : '',
+  ].filter(Boolean);
+  if (skipped.length > 0) {
+    description += ` ${t('import.skipped', { skipped: skipped.join(', ') })}`;
+  }
+  if (failures.length > 0) {
+    // Failures go in the description; a field's 1024 characters is too little for yt-dlp errors
+    description += `\n\n**${t('import.failed', { count: failures.length })}**\n${formatImportFailures(failures, MAX_REPORTED_FAILURES, t)}`;
+  }
+
+  const embed = new EmbedBuilder()
+    .setColor(failures.length === 0 ? '#1DB954' : '#FFA500')
+    .setTitle(t('import.finished'))
+    .setDescription(description)
+    .setFooter({ text: t('import.importedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ content: '', embeds: [embed] });
+
+  if (!playlistName && songs.length > 0 && !queueManager.isPlaying(guildId)) {
+    await startPlayback(interaction, queueManager, musicPlayer);
+  }
+}
@@ -0,0 +1,76 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { YouTubeUtil } from '../utils/youtubeUtil';
+import { formatTrack } from '../utils/titleParser';
+import { computeQueueInsights } from '../utils/queueInsights';
+import { BotServices, Song } from '../types/index';
+
+const TOP_REQUESTERS = 5;
+
+export const data = new SlashCommandBuilder()
+  .setName('insights')
+  .setDescription('Summarize the queue: runtime, who added what, duplicates and sources');
+
+function describeSong(song: Song): string {
+  return `${formatTrack(song)} (${YouTubeUtil.formatDuration(song.duration)})`;
+}
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const currentSong = queueManager.getCurrentSong(guildId);
+  const songs = [...(currentSong ? [currentSong] : []), ...queueManager.getFullQueue(guildId)];
+
+  if (songs.length === 0) {
+    await interaction.reply({ content: t('common.queueEmpty'), ephemeral: true });
+    return;
+  }
+
+  const insights = computeQueueInsights(songs);
+
+  let runtime = YouTubeUtil.formatDuration(insights.totalDuration);
+  if (insights.pendingMetadata > 0) {
+    runtime += ` + ${t('insights.stillLoading', { count: insights.pendingMetadata })}`;
+  }
+
+  const requesterLines = insights.requesters.slice(0, TOP_REQUESTERS).map((share) => {
+    const percent = insights.totalDuration > 0 ? Math.round((share.duration / insights.totalDuration) * 100) : 0;
+    return `<@${share.userId}> — ${t('top.songs', { count: share.songs })}, ${YouTubeUtil.formatDuration(share.duration)} (${percent}%)`;
+  });
+  if (insights.requesters.length > TOP_REQUESTERS) {
+    requesterLines.push(t('insights.moreRequesters', { count: insights.requesters.length - TOP_REQUESTERS }));
+  }
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('insights.title'))
+    .addFields(
+      { name: t('insights.songs'), value: String(insights.songCount), inline: true },
+      { name: t('insights.runtime'), value: runtime, inline: true },
+      { name: t('insights.duplicates'), value: String(insights.duplicates), inline: true },
+      { name: t('insights.requesters'), value: requesterLines.join('\n') },
+      {
+        name: t('insights.sources'),
+        value: insights.sources.map(({ source, songs: count }) => `${source}: ${count}`).join('\n'),
+      }
+    )
+    .setFooter({ text: currentSong ? t('insights.includesCurrent') : t('insights.upcoming') })
+    .setTimestamp();
+
+  if (insights.longest && insights.shortest) {
+    embed.addFields(
+      { name: t('insights.longest'), value: describeSong(insights.longest).slice(0, 1024) },
+      { name: t('insights.shortest'), value: describeSong(insights.shortest).slice(0, 1024) }
+    );
+  }
+
+  await interaction.reply({ embeds: [embed] });
+}
@@ -0,0 +1,65 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { INTRO_COOLDOWN_MS, INTRO_MAX_SECONDS, INTRO_MAX_UPLOAD_BYTES } from '../utils/intros';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('intro')
+  .setDescription('A short clip that plays over the music when you join my voice channel')
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('set')
+      .setDescription(`Upload your intro; only the first ${INTRO_MAX_SECONDS} seconds are kept`)
+      .addAttachmentOption((option) => option.setName('clip').setDescription('An audio or video file').setRequired(true))
+  )
+  .addSubcommand((subcommand) => subcommand.setName('clear').setDescription('Remove your intro'));
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const userId = interaction.user.id;
+
+  if (interaction.options.getSubcommand() === 'clear') {
+    await interaction.reply({
+      content: services.intros.remove(userId) ? t('intro.cleared') : t('intro.none'),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  const clip = interaction.options.getAttachment('clip', true);
+  const contentType = clip.contentType ?? '';
+  if (!contentType.startsWith('audio/') && !contentType.startsWith('video/')) {
+    await interaction.reply({ content: t('intro.notAudio'), ephemeral: true });
+    return;
+  }
+  if (clip.size > INTRO_MAX_UPLOAD_BYTES) {
+    await interaction.reply({
+      content: t('intro.tooBig', { mb: INTRO_MAX_UPLOAD_BYTES / 1024 / 1024 }),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  await interaction.deferReply({ ephemeral: true });
+  try {
+    await services.intros.save(userId, clip.url);
+  } catch (error) {
+    console.error(`Error saving intro for ${userId}:`, error);
+    await interaction.editReply(t('intro.unreadable'));
+    return;
+  }
+
+  const enabled = services.guildSettings.get(interaction.guildId!).joinIntros;
+  const minutes = INTRO_COOLDOWN_MS / 60_000;
+  await interaction.editReply(
+    t('intro.saved', { minutes }) + (enabled ? '' : `\n${t('intro.disabledHere')}`)
+  );
+}
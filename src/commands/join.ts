@@ -0,0 +1,59 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { VoiceConnectionStatus } from '@discordjs/voice';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { connectToVoice } from '../utils/playback';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('join')
+  .setDescription('Bring the bot into your voice channel');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const member = interaction.member as any;
+  const channel = member?.voice?.channel;
+
+  if (!channel) {
+    await interaction.reply({ content: t('common.joinVoiceFirst'), ephemeral: true });
+    return;
+  }
+
+  const existing = queueManager.getQueue(guildId).voiceConnection;
+  const connected = existing && existing.state.status !== VoiceConnectionStatus.Destroyed;
+  if (connected && existing?.joinConfig.channelId === channel.id) {
+    await interaction.reply({ content: t('join.already', { channel: channel.name }), ephemeral: true });
+    return;
+  }
+
+  await interaction.deferReply();
+
+  try {
+    await connectToVoice(interaction, queueManager, musicPlayer);
+  } catch (error) {
+    console.error('Join command error:', error);
+    await interaction.editReply(t('join.failed', { channel: channel.name }));
+    return;
+  }
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(connected ? t('join.moved') : t('join.joined'))
+    .setDescription(
+      connected
+        ? t('join.movedDescription', { channel: channel.name })
+        : t('join.joinedDescription', { channel: channel.name })
+    )
+    .setFooter({ text: t('join.footer', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+}
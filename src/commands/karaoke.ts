@@ -0,0 +1,50 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { karaoke } from '../utils/karaoke';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('karaoke')
+  .setDescription('Show the lyrics line by line as the song plays')
+  .addSubcommand((subcommand) =>
+    subcommand.setName('start').setDescription('Post lyrics here that follow along with the music')
+  )
+  .addSubcommand((subcommand) => subcommand.setName('stop').setDescription('Stop following the lyrics'));
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (interaction.options.getSubcommand() === 'stop') {
+    if (!karaoke.stop(guildId)) {
+      await interaction.reply({ content: t('karaoke.notOn'), ephemeral: true });
+      return;
+    }
+    await interaction.reply(t('karaoke.over'));
+    return;
+  }
+
+  if (!queueManager.getCurrentSong(guildId)) {
+    await interaction.reply({ content: t('common.nothingPlaying'), ephemeral: true });
+    return;
+  }
+
+  const channel = interaction.channel;
+  if (!channel?.isTextBased() || !('send' in channel)) {
+    await interaction.reply({ content: t('karaoke.cantPost'), ephemeral: true });
+    return;
+  }
+
+  // Lyrics go in a message of their own, since the interaction's token expires long before a queue ends
+  await interaction.reply(t('karaoke.started'));
+  const message = await channel.send(t('karaoke.searching'));
+  karaoke.start(guildId, message, queueManager, t);
+}
@@ -1,6 +1,11 @@
 import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
 import { QueueManager } from '../utils/queueManager';
 import { MusicPlayer } from '../utils/musicPlayer';
+import { describeBandwidth } from '../utils/bandwidth';
+import { playbackActors } from '../utils/guildActor';
+import { hasDjRole, isInBotVoiceChannel } from '../utils/permissions';
+import { describeVote, getVoiceListenerIds } from '../utils/votes';
+import { BotServices } from '../types/index';
 
 export const data = new SlashCommandBuilder()
   .setName('leave')
@@ -11,29 +16,49 @@ export async function execute(
   queueManager: QueueManager,
   musicPlayer: MusicPlayer,
   _startPlayerUpdate: (guildId: string) => void,
-  _cleanupOldMessages: (channelId: string) => Promise<void>
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
 ): Promise<void> {
   const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
   const queue = queueManager.getQueue(guildId);
 
   if (!queue.voiceConnection) {
     await interaction.reply({
-      content: "❌ Bot is not in a voice channel",
+      content: t('common.notInVoice'),
       ephemeral: true,
     });
     return;
   }
 
-  // Stop music and disconnect
-  musicPlayer.stop();
-  queue.voiceConnection.destroy();
-  queueManager.clearQueue(guildId);
+  if (!hasDjRole(interaction, services.guildSettings.get(guildId))) {
+    if (!isInBotVoiceChannel(interaction)) {
+      await interaction.reply({ content: t('leave.joinToVote'), ephemeral: true });
+      return;
+    }
+    const vote = services.votes.castVote(guildId, 'leave', interaction.user.id, getVoiceListenerIds(interaction.guild));
+    if (!vote.passed) {
+      await interaction.reply(describeVote(t('common.voteLeave'), vote, t));
+      return;
+    }
+  }
+  services.votes.reset(guildId);
+
+  // Clear the queue before stopping, so the player going idle doesn't start the next song
+  const usage = await playbackActors.run(guildId, () => {
+    const connection = queue.voiceConnection;
+    queueManager.clearQueue(guildId);
+    musicPlayer.stop();
+    connection?.destroy();
+    return musicPlayer.resetBandwidthUsage();
+  });
 
   const embed = new EmbedBuilder()
     .setColor('#FF0000')
-    .setTitle('👋 Left Voice Channel')
-    .setDescription('Disconnected and cleared queue')
-    .setFooter({ text: `Left by ${interaction.user.username}` })
+    .setTitle(t('leave.title'))
+    .setDescription(t('leave.description'))
+    .addFields({ name: t('common.dataUsed'), value: describeBandwidth(usage, t), inline: false })
+    .setFooter({ text: t('leave.footer', { user: interaction.user.username }) })
     .setTimestamp();
 
   await interaction.reply({ embeds: [embed] });
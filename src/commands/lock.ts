@@ -0,0 +1,42 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { hasDjRole } from '../utils/permissions';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('lock')
+  .setDescription('Let only DJs add songs until /unlock (DJs and admins only)');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const settings = services.guildSettings.get(guildId);
+
+  if (!hasDjRole(interaction, settings)) {
+    await interaction.reply({ content: t('lock.djOnly'), ephemeral: true });
+    return;
+  }
+  if (settings.queueLocked) {
+    await interaction.reply({ content: t('lock.alreadyLocked'), ephemeral: true });
+    return;
+  }
+
+  services.guildSettings.update(guildId, { queueLocked: true });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('lock.title'))
+    .setDescription(t('lock.description'))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
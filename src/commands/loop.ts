@@ -0,0 +1,61 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { isDj } from '../utils/permissions';
+import { LOOP_MODE_LABELS } from '../utils/playerControls';
+import { BotServices, LoopMode } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('loop')
+  .setDescription('Repeat the current song or the whole queue')
+  .addStringOption((option) =>
+    option
+      .setName('mode')
+      .setDescription('What to repeat')
+      .setRequired(true)
+      .addChoices(
+        { name: 'Off', value: 'off' },
+        { name: 'This song', value: 'track' },
+        { name: 'The queue', value: 'queue' }
+      )
+  )
+  .addIntegerOption((option) =>
+    option
+      .setName('times')
+      .setDescription('With "This song": how many more times to play it before moving on')
+      .setMinValue(1)
+      .setMaxValue(100)
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (!isDj(interaction, services.guildSettings.get(guildId))) {
+    await interaction.reply({ content: t('loop.djOnly'), ephemeral: true });
+    return;
+  }
+
+  if (!queueManager.getCurrentSong(guildId)) {
+    await interaction.reply({ content: t('loop.nothingPlaying'), ephemeral: true });
+    return;
+  }
+
+  const mode = interaction.options.getString('mode', true) as LoopMode;
+  const times = interaction.options.getInteger('times') ?? undefined;
+  if (times !== undefined && mode !== 'track') {
+    await interaction.reply({ content: t('loop.timesTrackOnly'), ephemeral: true });
+    return;
+  }
+
+  queueManager.setLoopMode(guildId, mode, times);
+  const label = times !== undefined ? t('loop.trackTimes', { count: times }) : t(LOOP_MODE_LABELS[mode]);
+  await interaction.reply(`${mode === 'track' ? '🔂' : '🔁'} ${label}`);
+}
@@ -0,0 +1,142 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, CommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueLimits, QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { YouTubeUtil } from '../utils/youtubeUtil';
+import { startPlayback } from '../utils/playback';
+import { getMixSeed, mixFeeds } from '../utils/mixFeed';
+import { describeBlockedSong, filterBlacklisted, findBlacklistMatch } from '../utils/blacklist';
+import { describeError, logError } from '../utils/errors';
+import { checkExtractionLimit } from '../utils/rateLimiter';
+import { getQueueLimits } from '../utils/permissions';
+import { formatTrack } from '../utils/titleParser';
+import { Translator } from '../utils/i18n';
+import { BlacklistEntry, BotServices, Song } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('mix')
+  .setDescription("Keep the queue going with YouTube's mix for a song")
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('start')
+      .setDescription('Queue a song and keep adding songs from its mix as the queue runs low')
+      .addStringOption((option) =>
+        option.setName('song').setDescription('YouTube URL, Mix link or song name').setRequired(true)
+      )
+  )
+  .addSubcommand((subcommand) => subcommand.setName('stop').setDescription('Stop adding songs from the mix'));
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (interaction.options.getSubcommand() === 'stop') {
+    if (!mixFeeds.isActive(guildId, queueManager)) {
+      await interaction.reply({ content: t('mix.notRunning'), ephemeral: true });
+      return;
+    }
+    mixFeeds.stop(guildId);
+    await interaction.reply(t('mix.stopped'));
+    return;
+  }
+
+  const member = interaction.member as any;
+  if (!member?.voice?.channel) {
+    await interaction.reply(t('common.joinVoiceFirst'));
+    return;
+  }
+  if (!(await checkExtractionLimit(interaction, services))) return;
+
+  await interaction.deferReply();
+  try {
+    const input = YouTubeUtil.normalizeUrl(interaction.options.getString('song', true));
+    const userId = interaction.user.id;
+    const seedId = getMixSeed(input);
+    let seed: Song | null;
+    if (seedId) {
+      seed = await YouTubeUtil.fetchSongInfo(`https://www.youtube.com/watch?v=${seedId}`, userId, guildId);
+    } else if (YouTubeUtil.isUrl(input)) {
+      seed = await YouTubeUtil.fetchSongInfo(input, userId, guildId);
+    } else {
+      seed = await YouTubeUtil.searchSong(input, userId, guildId);
+    }
+    if (!seed) {
+      await interaction.editReply(t('play.noResults', { query: input }));
+      return;
+    }
+
+    const settings = services.guildSettings.get(guildId);
+    await startMix(interaction, seed, queueManager, musicPlayer, getQueueLimits(interaction, settings), settings.blacklist, t);
+  } catch (error) {
+    logError('Mix command error', error);
+    await interaction.editReply(describeError(error, t, 'play.failed'));
+  }
+}
+
+/**
+ * Queues `seed` and the first songs of its YouTube Mix, then keeps the mix
+ * going as the queue drains. The interaction must already be deferred.
+ */
+export async function startMix(
+  interaction: CommandInteraction,
+  seed: Song,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  limits: QueueLimits | undefined,
+  blacklist: BlacklistEntry[],
+  t: Translator
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const videoId = seed.source.kind === 'youtube' ? seed.source.videoId : undefined;
+  if (!videoId) {
+    await interaction.editReply(t('mix.notYouTube'));
+    return;
+  }
+  const blockedBy = findBlacklistMatch(seed, blacklist);
+  if (blockedBy) {
+    await interaction.editReply(describeBlockedSong(seed, blockedBy, t));
+    return;
+  }
+
+  const generation = queueManager.getGeneration(guildId);
+  const batch = await mixFeeds.start(guildId, videoId, interaction.user.id, queueManager);
+  if (batch.length === 0) {
+    mixFeeds.stop(guildId);
+    await interaction.editReply(t('mix.empty'));
+    return;
+  }
+
+  // The seed may already be playing or queued; the mix still follows on from it
+  const songs = queueManager.withoutQueued(guildId, [seed, ...filterBlacklisted(batch, blacklist).allowed]);
+  let added: number;
+  try {
+    added = queueManager.addSongs(guildId, songs, generation, limits);
+  } catch (error) {
+    mixFeeds.stop(guildId);
+    throw error;
+  }
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('mix.started'))
+    .setDescription(`**${formatTrack(seed)}**`)
+    .setThumbnail(seed.thumbnail || 'https://via.placeholder.com/160x160?text=YouTube')
+    .addFields(
+      { name: t('play.songs'), value: `${added}`, inline: true },
+      { name: t('common.queueSize'), value: `${queueManager.getQueueSize(guildId)}`, inline: true }
+    )
+    .setFooter({ text: t('mix.footer') })
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+
+  if (!queueManager.isPlaying(guildId)) {
+    await startPlayback(interaction, queueManager, musicPlayer);
+  }
+}
@@ -0,0 +1,51 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('move')
+  .setDescription('Move a song to a different position in the queue')
+  .addStringOption((option) =>
+    option.setName('song').setDescription('Queue position or ID (e.g. kobe), as shown by /queue').setRequired(true)
+  )
+  .addIntegerOption((option) =>
+    option.setName('to').setDescription('New position (1 plays next)').setRequired(true).setMinValue(1)
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const reference = interaction.options.getString('song', true);
+  const to = interaction.options.getInteger('to', true);
+  const queueSize = queueManager.getQueueSize(guildId);
+
+  if (queueSize === 0) {
+    await interaction.reply({ content: t('common.queueEmpty'), ephemeral: true });
+    return;
+  }
+
+  const from = queueManager.findSongIndex(guildId, reference);
+  if (from === -1) {
+    await interaction.reply({
+      content: t('common.noSongMatches', { reference, size: queueSize }),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  const moved = queueManager.moveSong(guildId, from, to - 1);
+  if (!moved) {
+    await interaction.reply({ content: t('move.badPosition', { size: queueSize }), ephemeral: true });
+    return;
+  }
+
+  await interaction.reply(t('move.done', { title: moved.title, id: moved.queueId ?? '', position: to }));
+}
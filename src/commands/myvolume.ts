@@ -0,0 +1,67 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { applyVolume } from '../utils/playback';
+import { MAX_VOLUME } from '../utils/userPreferences';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('myvolume')
+  .setDescription('Set the volume your own songs play at when you are listening alone')
+  .addIntegerOption((option) =>
+    option
+      .setName('percent')
+      .setDescription(`Volume from 0 to ${MAX_VOLUME}; leave out to see your current setting`)
+      .setMinValue(0)
+      .setMaxValue(MAX_VOLUME)
+  )
+  .addBooleanOption((option) =>
+    option.setName('clear').setDescription("Forget your preference and use the server's volume")
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const userId = interaction.user.id;
+  const percent = interaction.options.getInteger('percent');
+  const clear = interaction.options.getBoolean('clear') ?? false;
+  const guildVolume = services.guildSettings.get(guildId).volume;
+
+  if (!clear && percent === null) {
+    const { volume } = services.userPreferences.get(userId);
+    await interaction.reply({
+      content:
+        volume === undefined
+          ? t('myVolume.none', { server: guildVolume })
+          : t('myVolume.current', { volume, server: guildVolume }),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  const volume = clear ? undefined : (percent as number);
+  services.userPreferences.update(userId, { volume });
+
+  await interaction.deferReply({ ephemeral: true });
+  // Takes effect right away if this user is listening alone to their own song
+  await applyVolume(guildId, queueManager, musicPlayer);
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(volume === undefined ? t('myVolume.cleared') : t('myVolume.set', { volume }))
+    .setDescription(
+      volume === undefined
+        ? t('myVolume.clearedDescription', { server: guildVolume })
+        : t('myVolume.setDescription', { server: guildVolume })
+    )
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+}
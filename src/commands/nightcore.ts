@@ -0,0 +1,40 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer, DEFAULT_FILTERS } from '../utils/musicPlayer';
+import { applyFilters } from '../utils/playback';
+import { BotServices, FeatureFlag } from '../types/index';
+
+const NIGHTCORE_RATE = 1.25;
+
+export const feature: FeatureFlag = 'filters';
+
+export const data = new SlashCommandBuilder()
+  .setName('nightcore')
+  .setDescription('Toggle nightcore mode (faster and higher pitched)');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const current = musicPlayer.getFilters();
+  const enabling = current.pitch !== NIGHTCORE_RATE;
+  const filters = enabling ? { speed: NIGHTCORE_RATE, pitch: NIGHTCORE_RATE } : { ...DEFAULT_FILTERS };
+
+  await interaction.deferReply();
+  await applyFilters(guildId, queueManager, musicPlayer, filters);
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(enabling ? t('nightcore.on') : t('nightcore.off'))
+    .setDescription(enabling ? t('nightcore.onDescription', { rate: NIGHTCORE_RATE }) : t('nightcore.offDescription'))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+}
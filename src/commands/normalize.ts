@@ -0,0 +1,42 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { applyLoudnessNormalization } from '../utils/playback';
+import { isAdmin } from '../utils/permissions';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('normalize')
+  .setDescription('Toggle loudness normalization so every song plays at a similar volume (admins only)');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (!isAdmin(interaction)) {
+    await interaction.reply({ content: t('normalize.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  const enabled = !services.guildSettings.get(guildId).normalizeLoudness;
+  services.guildSettings.update(guildId, { normalizeLoudness: enabled });
+
+  await interaction.deferReply();
+  await applyLoudnessNormalization(guildId, queueManager, musicPlayer, enabled);
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(enabled ? t('normalize.on') : t('normalize.off'))
+    .setDescription(enabled ? t('normalize.onDescription') : t('normalize.offDescription'))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+}
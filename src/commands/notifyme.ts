@@ -0,0 +1,58 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { MessageKey } from '../utils/i18n';
+import { BotServices, StartNotification } from '../types/index';
+
+const DESCRIPTIONS: Record<StartNotification, MessageKey> = {
+  dm: 'notifyMe.dm',
+  ping: 'notifyMe.ping',
+};
+
+export const data = new SlashCommandBuilder()
+  .setName('notifyme')
+  .setDescription('Get told when a song you requested starts playing')
+  .addStringOption((option) =>
+    option
+      .setName('how')
+      .setDescription('How to tell you; leave out to see your current setting')
+      .addChoices(
+        { name: 'Direct message', value: 'dm' },
+        { name: 'Ping in the server', value: 'ping' },
+        { name: 'Off', value: 'off' }
+      )
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const userId = interaction.user.id;
+  const how = interaction.options.getString('how');
+
+  if (how === null) {
+    const { startNotification } = services.userPreferences.get(userId);
+    await interaction.reply({
+      content: startNotification
+        ? t('notifyMe.current', { how: t(DESCRIPTIONS[startNotification]) })
+        : t('notifyMe.currentOff'),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  const startNotification = how === 'off' ? undefined : (how as StartNotification);
+  services.userPreferences.update(userId, { startNotification });
+
+  await interaction.reply({
+    content: startNotification
+      ? t('notifyMe.changed', { how: t(DESCRIPTIONS[startNotification]) })
+      : t('notifyMe.off'),
+    ephemeral: true,
+  });
+}
@@ -1,6 +1,8 @@
 import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
 import { QueueManager } from '../utils/queueManager';
 import { MusicPlayer } from '../utils/musicPlayer';
+import { formatTrack } from '../utils/titleParser';
+import { BotServices } from '../types/index';
 
 export const data = new SlashCommandBuilder()
   .setName('pause')
@@ -11,13 +13,15 @@ export async function execute(
   queueManager: QueueManager,
   musicPlayer: MusicPlayer,
   _startPlayerUpdate: (guildId: string) => void,
-  _cleanupOldMessages: (channelId: string) => Promise<void>
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
 ): Promise<void> {
   const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
   const currentSong = queueManager.getCurrentSong(guildId);
 
   if (!currentSong) {
-    await interaction.reply("❌ Nothing is currently playing");
+    await interaction.reply(t('common.nothingPlaying'));
     return;
   }
 
@@ -25,9 +29,9 @@ export async function execute(
 
   const embed = new EmbedBuilder()
     .setColor('#FF0000')
-    .setTitle('⏸️ Paused')
-    .setDescription(currentSong.title)
-    .setFooter({ text: 'Use /resume to continue' })
+    .setTitle(t('pause.title'))
+    .setDescription(formatTrack(currentSong))
+    .setFooter({ text: t('pause.footer') })
     .setTimestamp();
 
   await interaction.reply({ embeds: [embed] });
@@ -1,58 +1,199 @@
 import {
-  ActionRowBuilder,
-  ButtonBuilder,
-  ButtonStyle,
+  AttachmentBuilder,
+  AutocompleteInteraction,
   ChatInputCommandInteraction,
+  CommandInteraction,
   EmbedBuilder,
   SlashCommandBuilder,
 } from 'discord.js';
-import { VoiceConnectionStatus, entersState, joinVoiceChannel } from '@discordjs/voice';
 import { YouTubeUtil } from '../utils/youtubeUtil';
 import { MusicPlayer } from '../utils/musicPlayer';
-import { QueueManager } from '../utils/queueManager';
+import { QueueLimits, QueueManager } from '../utils/queueManager';
+import { startPlayback } from '../utils/playback';
+import { buildPlayerControls } from '../utils/playerControls';
+import { Translator } from '../utils/i18n';
+import { shuffleInPlace } from '../utils/shuffle';
+import { DedupeCache } from '../utils/dedupeCache';
+import { getDisplayThumbnail } from '../utils/thumbnailProxy';
+import { describeError, logError } from '../utils/errors';
+import { checkExtractionLimit } from '../utils/rateLimiter';
+import { splitIntoChapters } from '../utils/chapters';
+import { formatTrack } from '../utils/titleParser';
+import { getMixSeed } from '../utils/mixFeed';
+import { findExtractor } from '../utils/sourceExtractor';
+import { startMix } from './mix';
+import { getQueueLimits } from '../utils/permissions';
+import { describeBlockedSong, filterBlacklisted, findBlacklistMatch } from '../utils/blacklist';
+import { DeezerCollection, DeezerTrack, deezerTrackQuery, fetchDeezerTracks, isDeezerUrl } from '../utils/deezer';
+import { MAX_IMPORT_ENTRIES, resolveImportEntries } from '../utils/playlistImport';
+import { ProgressMessage } from '../utils/progressMessage';
+import { BlacklistEntry, BotServices, Song } from '../types/index';
+
+// Identical requests from the same user this close together are double-sends
+const recentRequests = new DedupeCache(3000);
+// Deezer tracks listed by name when they couldn't be found on YouTube
+const MAX_REPORTED_UNMATCHED = 10;
 
 export const data = new SlashCommandBuilder()
   .setName('play')
-  .setDescription('Play a song from YouTube')
+  .setDescription('Play a song from YouTube or a Twitch stream')
   .addStringOption((option) =>
     option
       .setName('url')
-      .setDescription('YouTube URL or song name')
+      .setDescription('YouTube or Twitch URL, or song name')
       .setRequired(true)
+      .setAutocomplete(true)
+  )
+  .addBooleanOption((option) =>
+    option
+      .setName('shuffle')
+      .setDescription('Shuffle the songs when queueing a playlist')
+  )
+  .addBooleanOption((option) =>
+    option
+      .setName('split_chapters')
+      .setDescription('Queue each chapter of the video as its own song')
+  );
+
+export async function autocomplete(interaction: AutocompleteInteraction, services: BotServices): Promise<void> {
+  const partial = interaction.options.getFocused();
+  const { aliases } = services.guildSettings.get(interaction.guildId!);
+
+  // Server aliases first, then the configured suggestion provider
+  const aliasChoices = Object.keys(aliases)
+    .filter((alias) => alias.startsWith(partial.trim().toLowerCase()))
+    .map((alias) => ({ name: `⭐ ${alias}`, value: alias }));
+  const suggestions = await services.suggestionProvider.suggest(
+    interaction.guildId!,
+    partial,
+    Math.max(25 - aliasChoices.length, 0)
   );
 
+  await interaction.respond([...aliasChoices, ...suggestions].slice(0, 25));
+}
+
 export async function execute(
   interaction: ChatInputCommandInteraction,
   queueManager: QueueManager,
   musicPlayer: MusicPlayer,
   startPlayerUpdate: (guildId: string) => void,
-  cleanupOldMessages: (channelId: string) => Promise<void>
+  cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
 ): Promise<void> {
-  const urlOrQuery = interaction.options.getString('url', true);
+  await queueRequest(
+    interaction,
+    interaction.options.getString('url', true),
+    {
+      shuffle: interaction.options.getBoolean('shuffle') ?? false,
+      splitChapters: interaction.options.getBoolean('split_chapters') ?? false,
+    },
+    queueManager,
+    musicPlayer,
+    startPlayerUpdate,
+    cleanupOldMessages,
+    services
+  );
+}
+
+export interface QueueRequestOptions {
+  /** Shuffle a playlist's songs before queueing them. */
+  shuffle: boolean;
+  /** Queue each chapter of the video as its own song. */
+  splitChapters: boolean;
+}
+
+/**
+ * Everything /play does with a URL, search or alias, for other ways of asking
+ * for a song too. Callers that need time to work out the input can defer first.
+ */
+export async function queueRequest(
+  interaction: CommandInteraction,
+  input: string,
+  options: QueueRequestOptions,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  startPlayerUpdate: (guildId: string) => void,
+  cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  // Server aliases stand in for their saved URL
+  const urlOrQuery = YouTubeUtil.normalizeUrl(services.guildSettings.resolveAlias(interaction.guildId!, input) ?? input);
+  const t = services.guildSettings.translator(interaction.guildId!);
   const member = interaction.member as any;
 
   if (!member?.voice?.channel) {
-    await interaction.reply("❌ You must be in a voice channel to use this command!");
+    await (interaction.deferred ? interaction.editReply(t('common.joinVoiceFirst')) : interaction.reply(t('common.joinVoiceFirst')));
     return;
   }
 
+  const requestKey = `${interaction.guildId}:${interaction.user.id}:${urlOrQuery.trim().toLowerCase()}`;
+  if (recentRequests.isDuplicate(requestKey)) {
+    await (interaction.deferred
+      ? interaction.editReply(t('play.alreadyAdding'))
+      : interaction.reply({ content: t('play.alreadyAdding'), ephemeral: true }));
+    return;
+  }
+
+  if (!(await checkExtractionLimit(interaction, services))) return;
+
   // Cleanup old messages BEFORE deferReply to avoid deleting the deferred message
   await cleanupOldMessages(interaction.channelId!);
 
-  await interaction.deferReply();
+  if (!interaction.deferred) {
+    await interaction.deferReply();
+  }
 
   try {
-    // Validate and fetch song info
-    if (!(await YouTubeUtil.isValidUrl(urlOrQuery))) {
-      await interaction.editReply("❌ Invalid YouTube URL!");
+    // Validate and fetch song info; anything that isn't a URL is treated as a search query
+    let songInfo: Song;
+    const generation = queueManager.getGeneration(interaction.guildId!);
+    const settings = services.guildSettings.get(interaction.guildId!);
+    const limits = getQueueLimits(interaction, settings);
+    if (YouTubeUtil.isUrl(urlOrQuery)) {
+      if (isDeezerUrl(urlOrQuery)) {
+        await enqueueDeezer(interaction, queueManager, musicPlayer, urlOrQuery, options.shuffle, limits, settings.blacklist, t);
+        return;
+      }
+      const extractor = findExtractor(urlOrQuery);
+      if (!extractor) {
+        await interaction.editReply(t('play.invalidUrl'));
+        return;
+      }
+      const mixSeed = getMixSeed(urlOrQuery);
+      if (mixSeed) {
+        const seed = await YouTubeUtil.fetchSongInfo(`https://www.youtube.com/watch?v=${mixSeed}`, interaction.user.id, interaction.guildId!);
+        await startMix(interaction, seed, queueManager, musicPlayer, limits, settings.blacklist, t);
+        return;
+      }
+      if (YouTubeUtil.isPlaylistUrl(urlOrQuery)) {
+        await enqueuePlaylist(interaction, queueManager, musicPlayer, urlOrQuery, options.shuffle, limits, settings.blacklist, t);
+        return;
+      }
+      songInfo = await extractor.extract(urlOrQuery, interaction.user.id, interaction.guildId!);
+    } else {
+      const searchResult = await YouTubeUtil.searchSong(urlOrQuery, interaction.user.id, interaction.guildId!);
+      if (!searchResult) {
+        await interaction.editReply(t('play.noResults', { query: urlOrQuery }));
+        return;
+      }
+      songInfo = searchResult;
+    }
+
+    const blockedBy = findBlacklistMatch(songInfo, settings.blacklist);
+    if (blockedBy) {
+      await interaction.editReply(describeBlockedSong(songInfo, blockedBy, t));
       return;
     }
 
-    const songInfo = await YouTubeUtil.fetchSongInfo(urlOrQuery, interaction.user.id);
     const queue = queueManager.getQueue(interaction.guildId!);
 
+    if (options.splitChapters && songInfo.chapters) {
+      await enqueueChapters(interaction, queueManager, musicPlayer, songInfo, generation, limits, t);
+      return;
+    }
+
     // Add song to queue
-    queueManager.addSong(interaction.guildId!, songInfo);
+    queueManager.addSong(interaction.guildId!, songInfo, limits);
 
     // Create embed response
     const queueSize = queueManager.getQueueSize(interaction.guildId!);
@@ -60,48 +201,39 @@ export async function execute(
 
     const embed = new EmbedBuilder()
       .setColor('#1DB954')
-      .setTitle(isFirstOrSoloSong ? '▶️ Now Playing' : '✅ Added to Queue')
-      .setDescription(`**${songInfo.title}**`)
+      .setTitle(isFirstOrSoloSong ? t('play.nowPlaying') : t('play.addedToQueue'))
+      .setDescription(`**${formatTrack(songInfo)}**`)
       .setThumbnail(songInfo.thumbnail || 'https://via.placeholder.com/160x160?text=YouTube')
       .addFields(
         {
-          name: '⏱️ Duration',
-          value: YouTubeUtil.formatDuration(songInfo.duration),
+          name: t('common.duration'),
+          value: songInfo.isLive ? t('player.live') : YouTubeUtil.formatDuration(songInfo.duration),
           inline: true,
         },
         {
-          name: isFirstOrSoloSong ? '🎯 Status' : '📍 Position',
-          value: isFirstOrSoloSong ? 'Now Playing' : `#${queueSize} in Queue`,
+          name: isFirstOrSoloSong ? t('play.status') : t('play.position'),
+          value: isFirstOrSoloSong ? t('play.statusNowPlaying') : t('play.positionInQueue', { position: queueSize }),
           inline: true,
         }
       )
-      .setFooter({ text: `Added by ${interaction.user.username}` })
+      .setFooter({ text: t('common.addedBy', { user: interaction.user.username }) })
       .setTimestamp();
+    if (songInfo.album) {
+      embed.addFields({ name: t('common.album'), value: songInfo.album, inline: true });
+    }
 
-    const controlRow = new ActionRowBuilder<ButtonBuilder>().addComponents(
-      new ButtonBuilder()
-        .setCustomId('music:toggle')
-        .setEmoji(musicPlayer.isPlaying() ? '⏸️' : '▶️')
-        .setStyle(ButtonStyle.Success),
-      new ButtonBuilder()
-        .setCustomId('music:skip')
-        .setEmoji('⏭️')
-        .setStyle(ButtonStyle.Secondary),
-      new ButtonBuilder()
-        .setCustomId('music:stop')
-        .setEmoji('⏹️')
-        .setStyle(ButtonStyle.Danger)
-    );
-
-    const infoRow = new ActionRowBuilder<ButtonBuilder>().addComponents(
-      new ButtonBuilder()
-        .setCustomId('music:refresh')
-        .setLabel('Queue')
-        .setEmoji('📋')
-        .setStyle(ButtonStyle.Secondary)
-    );
-
-    const playerMsg = await interaction.editReply({ embeds: [embed], components: [controlRow, infoRow] });
+    const files: AttachmentBuilder[] = [];
+    if (songInfo.thumbnail) {
+      const thumbnail = await getDisplayThumbnail(songInfo.thumbnail);
+      embed.setThumbnail(thumbnail.url);
+      if (thumbnail.file) files.push(thumbnail.file);
+    }
+
+    const playerMsg = await interaction.editReply({
+      embeds: [embed],
+      components: buildPlayerControls(queueManager, interaction.guildId!, musicPlayer, t),
+      files,
+    });
     queueManager.setPlayerMessage(interaction.guildId!, playerMsg.id, interaction.channelId!);
     startPlayerUpdate(interaction.guildId!);
 
@@ -110,47 +242,242 @@ export async function execute(
       await startPlayback(interaction, queueManager, musicPlayer);
     }
   } catch (error) {
-    console.error('Play command error:', error);
-    await interaction.editReply("❌ Error processing your request. Please make sure the URL is valid.");
+    logError('Play command error', error);
+    await interaction.editReply(describeError(error, t, 'play.failed'));
   }
 }
 
-async function startPlayback(
-  interaction: ChatInputCommandInteraction,
+async function enqueuePlaylist(
+  interaction: CommandInteraction,
   queueManager: QueueManager,
-  musicPlayer: MusicPlayer
+  musicPlayer: MusicPlayer,
+  url: string,
+  shuffle: boolean,
+  limits: QueueLimits | undefined,
+  blacklist: BlacklistEntry[],
+  t: Translator
 ): Promise<void> {
-  const member = interaction.member as any;
-  const channel = member.voice.channel;
+  const guildId = interaction.guildId!;
 
-  if (!channel) {
+  // Only the flat listing is fetched here; each song's details are filled in as it nears the front
+  const generation = queueManager.getGeneration(guildId);
+  const entries = await YouTubeUtil.fetchPlaylistEntries(url, interaction.user.id, guildId);
+  if (entries.length === 0) {
+    await interaction.editReply(t('play.playlistFailed'));
     return;
   }
 
-  try {
-    const queue = queueManager.getQueue(interaction.guildId!);
-    let connection = queue.voiceConnection;
-
-    if (!connection || connection.state.status === VoiceConnectionStatus.Destroyed) {
-      connection = joinVoiceChannel({
-        channelId: channel.id,
-        guildId: interaction.guildId!,
-        adapterCreator: (interaction.guild as any).voiceAdapterCreator,
-      });
-      await entersState(connection, VoiceConnectionStatus.Ready, 20_000);
-      queue.voiceConnection = connection;
-    }
+  const { allowed, blocked } = filterBlacklisted(entries, blacklist);
+  if (allowed.length === 0) {
+    await interaction.editReply(t('play.playlistAllBlocked'));
+    return;
+  }
+  const songs = queueManager.withoutQueued(guildId, allowed);
+  if (songs.length === 0) {
+    await interaction.editReply(t('play.playlistAllQueued'));
+    return;
+  }
 
-    const nextSong = queueManager.dequeueNextSong(interaction.guildId!);
-    if (nextSong) {
-      queueManager.setCurrentSong(interaction.guildId!, nextSong);
-      queueManager.setPlaying(interaction.guildId!, true);
-      queueManager.setSongStartTime(interaction.guildId!, Date.now());
-      await musicPlayer.playSong(connection, nextSong);
-    }
+  if (shuffle) {
+    shuffleInPlace(songs);
+  }
+
+  const added = queueManager.addSongs(guildId, songs, generation, limits);
+  if (added === 0) {
+    await interaction.editReply(t('play.playlistCleared'));
+    return;
+  }
+  const skipped = [
+    blocked.length > 0 ? t('play.skippedBlocked', { count: blocked.length }) : '',
+    allowed.length > songs.length ? t('play.skippedQueued', { count: allowed.length - songs.length }) : '',
+    songs.length > added ? t('play.skippedTooLong', { count: songs.length - added }) : '',
+  ].filter(Boolean);
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(shuffle ? t('play.shuffledPlaylistAdded') : t('play.playlistAdded'))
+    .setThumbnail(songs[0].thumbnail || 'https://via.placeholder.com/160x160?text=YouTube')
+    .addFields(
+      {
+        name: t('play.songs'),
+        value: skipped.length > 0 ? t('play.songsSkipped', { added, skipped: skipped.join(', ') }) : `${added}`,
+        inline: true,
+      },
+      {
+        name: t('common.queueSize'),
+        value: `${queueManager.getQueueSize(guildId)}`,
+        inline: true,
+      }
+    )
+    .setFooter({ text: t('common.addedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+
+  if (!queueManager.isPlaying(guildId)) {
+    await startPlayback(interaction, queueManager, musicPlayer);
+  }
+}
+
+/**
+ * Queues a Deezer track, playlist or album by finding each track on YouTube,
+ * since Deezer's audio can't be streamed. Tracks with no match are listed in
+ * the reply.
+ */
+async function enqueueDeezer(
+  interaction: CommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  url: string,
+  shuffle: boolean,
+  limits: QueueLimits | undefined,
+  blacklist: BlacklistEntry[],
+  t: Translator
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const userId = interaction.user.id;
+
+  let collection: DeezerCollection;
+  try {
+    collection = await fetchDeezerTracks(url, MAX_IMPORT_ENTRIES);
   } catch (error) {
-    console.error('Error starting playback:', error);
-    queueManager.setCurrentSong(interaction.guildId!, undefined);
-    queueManager.setPlaying(interaction.guildId!, false);
+    console.error('Deezer lookup error:', error);
+    await interaction.editReply(t('deezer.failed'));
+    return;
+  }
+  if (collection.tracks.length === 0) {
+    await interaction.editReply(t('deezer.empty'));
+    return;
+  }
+  // Checked up front, so a long playlist isn't searched for only to be turned away
+  queueManager.checkLimits(guildId, userId, collection.tracks.length, limits);
+
+  // Searching can take a while; if the queue is cleared meanwhile, nothing is added
+  const generation = queueManager.getGeneration(guildId);
+  const entries = collection.tracks.map((track, index) => ({ line: index + 1, input: deezerTrackQuery(track) }));
+  const progress = new ProgressMessage((content) => interaction.editReply(content));
+  const resolved = await resolveImportEntries(entries, userId, guildId, ({ done, total }) =>
+    progress.update(t('deezer.matching', { done, total }))
+  );
+  await progress.stop();
+
+  if (resolved.songs.length === 0) {
+    await interaction.editReply(t('deezer.noneMatched'));
+    return;
+  }
+  // Entries are numbered from 1, and the songs found keep the tracks' order
+  const failedLines = new Set(resolved.failures.map((failure) => failure.line));
+  const unmatched = collection.tracks.filter((_, index) => failedLines.has(index + 1));
+  const matched = collection.tracks.filter((_, index) => !failedLines.has(index + 1));
+  resolved.songs.forEach((song, index) => {
+    // YouTube rarely knows the album; Deezer does
+    if (!song.album) song.album = matched[index]?.album;
+  });
+
+  const { allowed, blocked } = filterBlacklisted(resolved.songs, blacklist);
+  const songs = queueManager.withoutQueued(guildId, allowed);
+  if (shuffle) {
+    shuffleInPlace(songs);
+  }
+  const added = songs.length > 0 ? queueManager.addSongs(guildId, songs, generation, limits) : 0;
+  if (songs.length > 0 && added === 0) {
+    await interaction.editReply(t('play.playlistCleared'));
+    return;
+  }
+  const skipped = [
+    blocked.length > 0 ? t('play.skippedBlocked', { count: blocked.length }) : '',
+    allowed.length > songs.length ? t('play.skippedQueued', { count: allowed.length - songs.length }) : '',
+    songs.length > added ? t('play.skippedTooLong', { count: songs.length - added }) : '',
+  ].filter(Boolean);
+
+  const embed = new EmbedBuilder()
+    .setColor(unmatched.length === 0 ? '#1DB954' : '#FFA500')
+    .setTitle(t('deezer.added'))
+    .setDescription(`**${collection.name}**`)
+    .setThumbnail(songs[0]?.thumbnail || 'https://via.placeholder.com/160x160?text=YouTube')
+    .addFields(
+      {
+        name: t('play.songs'),
+        value: skipped.length > 0 ? t('play.songsSkipped', { added, skipped: skipped.join(', ') }) : `${added}`,
+        inline: true,
+      },
+      {
+        name: t('common.queueSize'),
+        value: `${queueManager.getQueueSize(guildId)}`,
+        inline: true,
+      }
+    )
+    .setFooter({ text: t('common.addedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+  if (unmatched.length > 0) {
+    embed.addFields({
+      name: t('deezer.unmatched', { count: unmatched.length }),
+      value: formatUnmatched(unmatched, t),
+      inline: false,
+    });
+  }
+
+  await interaction.editReply({ content: '', embeds: [embed] });
+
+  if (added > 0 && !queueManager.isPlaying(guildId)) {
+    await startPlayback(interaction, queueManager, musicPlayer);
+  }
+}
+
+function formatUnmatched(tracks: DeezerTrack[], t: Translator): string {
+  const lines = tracks.slice(0, MAX_REPORTED_UNMATCHED).map((track) => `${track.artist} – ${track.title}`.slice(0, 90));
+  if (tracks.length > MAX_REPORTED_UNMATCHED) {
+    lines.push(t('deezer.moreUnmatched', { count: tracks.length - MAX_REPORTED_UNMATCHED }));
+  }
+  return lines.join('\n');
+}
+
+/** Queues each chapter of a long upload (e.g. a full album) as a separate song. */
+async function enqueueChapters(
+  interaction: CommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  song: Song,
+  generation: number,
+  limits: QueueLimits | undefined,
+  t: Translator
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const songs = queueManager.withoutQueued(guildId, splitIntoChapters(song));
+  if (songs.length === 0) {
+    await interaction.editReply(t('play.chaptersAllQueued'));
+    return;
+  }
+
+  const added = queueManager.addSongs(guildId, songs, generation, limits);
+  if (added === 0) {
+    await interaction.editReply(t('play.chaptersCleared'));
+    return;
+  }
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('play.chaptersAdded'))
+    .setDescription(`**${song.title}**`)
+    .setThumbnail(song.thumbnail || 'https://via.placeholder.com/160x160?text=YouTube')
+    .addFields(
+      {
+        name: t('play.chapters'),
+        value: `${added}`,
+        inline: true,
+      },
+      {
+        name: t('common.queueSize'),
+        value: `${queueManager.getQueueSize(guildId)}`,
+        inline: true,
+      }
+    )
+    .setFooter({ text: t('common.addedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+
+  if (!queueManager.isPlaying(guildId)) {
+    await startPlayback(interaction, queueManager, musicPlayer);
   }
 }
@@ -0,0 +1,348 @@
+import {
+  ActionRowBuilder,
+  AutocompleteInteraction,
+  ChatInputCommandInteraction,
+  EmbedBuilder,
+  ModalBuilder,
+  ModalSubmitInteraction,
+  SlashCommandBuilder,
+  TextInputBuilder,
+  TextInputStyle,
+} from 'discord.js';
+import { QueueLimitError, QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { YouTubeUtil } from '../utils/youtubeUtil';
+import { resolveSong } from '../utils/sourceExtractor';
+import { PlaylistError } from '../utils/playlistManager';
+import { formatImportFailures, formatImportProgress, parseImportFile, resolveImportEntries } from '../utils/playlistImport';
+import { ProgressMessage } from '../utils/progressMessage';
+import { checkExtractionLimit } from '../utils/rateLimiter';
+import { startPlayback } from '../utils/playback';
+import { getQueueLimits } from '../utils/permissions';
+import { filterBlacklisted } from '../utils/blacklist';
+import { describeError } from '../utils/errors';
+import { Translator } from '../utils/i18n';
+import { BotServices } from '../types/index';
+
+const PLAYLIST_PREVIEW_SIZE = 15;
+// Songs that can be pasted into the create form at once; more can be added with /import
+const MAX_CREATE_SONGS = 50;
+const MAX_REPORTED_FAILURES = 10;
+const CREATE_MODAL_PREFIX = 'playlist:create:';
+
+export const data = new SlashCommandBuilder()
+  .setName('playlist')
+  .setDescription('Manage your saved playlists')
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('create')
+      .setDescription('Create a new playlist, pasting in its first songs')
+      .addStringOption((option) => option.setName('name').setDescription('Playlist name').setRequired(true).setMaxLength(50))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('add')
+      .setDescription('Add a song to a playlist')
+      .addStringOption((option) =>
+        option.setName('name').setDescription('Playlist name').setRequired(true).setAutocomplete(true)
+      )
+      .addStringOption((option) => option.setName('url').setDescription('YouTube URL or song name').setRequired(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('remove')
+      .setDescription('Remove a song from a playlist')
+      .addStringOption((option) =>
+        option.setName('name').setDescription('Playlist name').setRequired(true).setAutocomplete(true)
+      )
+      .addIntegerOption((option) =>
+        option.setName('position').setDescription('Position of the song in the playlist').setRequired(true).setMinValue(1)
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('show')
+      .setDescription('Show your playlists, or the songs in one')
+      .addStringOption((option) => option.setName('name').setDescription('Playlist name').setAutocomplete(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('play')
+      .setDescription('Add every song in a playlist to the queue')
+      .addStringOption((option) =>
+        option.setName('name').setDescription('Playlist name').setRequired(true).setAutocomplete(true)
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('delete')
+      .setDescription('Delete a playlist')
+      .addStringOption((option) =>
+        option.setName('name').setDescription('Playlist name').setRequired(true).setAutocomplete(true)
+      )
+  );
+
+export async function autocomplete(interaction: AutocompleteInteraction, services: BotServices): Promise<void> {
+  const partial = interaction.options.getFocused().toLowerCase();
+  const choices = services.playlistManager
+    .getPlaylists(interaction.guildId!, interaction.user.id)
+    .filter((playlist) => playlist.name.toLowerCase().includes(partial))
+    .slice(0, 25)
+    .map((playlist) => ({ name: playlist.name, value: playlist.name }));
+
+  await interaction.respond(choices);
+}
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const userId = interaction.user.id;
+  const playlists = services.playlistManager;
+  const subcommand = interaction.options.getSubcommand();
+
+  try {
+    switch (subcommand) {
+      case 'create': {
+        const name = interaction.options.getString('name', true).trim();
+        // Checked before the form opens, so nobody pastes a list only to be told the name is taken
+        if (playlists.getPlaylist(guildId, userId, name)) {
+          throw new PlaylistError('playlist.exists', { name });
+        }
+        await interaction.showModal(buildCreateModal(name, t));
+        return;
+      }
+
+      case 'add': {
+        const name = interaction.options.getString('name', true);
+        const urlOrQuery = interaction.options.getString('url', true);
+        if (!(await checkExtractionLimit(interaction, services))) return;
+        await interaction.deferReply();
+
+        const song = await resolveSong(urlOrQuery, userId, guildId);
+        if (!song) {
+          await interaction.editReply(t('playlist.songNotFound', { query: urlOrQuery }));
+          return;
+        }
+
+        const playlist = playlists.addSongs(guildId, userId, name, [song]);
+        await interaction.editReply(
+          t('playlist.added', { track: song.title, name: playlist.name, position: playlist.songs.length })
+        );
+        return;
+      }
+
+      case 'remove': {
+        const name = interaction.options.getString('name', true);
+        const removed = playlists.removeSong(guildId, userId, name, interaction.options.getInteger('position', true));
+        await interaction.reply(t('playlist.removed', { track: removed.title, name }));
+        return;
+      }
+
+      case 'show': {
+        const name = interaction.options.getString('name');
+        await interaction.reply({ embeds: [buildShowEmbed(services, guildId, userId, name, t)], ephemeral: true });
+        return;
+      }
+
+      case 'play': {
+        const name = interaction.options.getString('name', true);
+        const playlist = playlists.getPlaylist(guildId, userId, name);
+        if (!playlist) {
+          throw new PlaylistError('playlist.notFound', { name });
+        }
+        if (playlist.songs.length === 0) {
+          throw new PlaylistError('playlist.isEmpty', { name: playlist.name });
+        }
+
+        const member = interaction.member as any;
+        if (!member?.voice?.channel) {
+          await interaction.reply(t('common.joinVoiceFirst'));
+          return;
+        }
+
+        const settings = services.guildSettings.get(guildId);
+        const { allowed, blocked } = filterBlacklisted(playlist.songs, settings.blacklist);
+        if (allowed.length === 0) {
+          throw new PlaylistError('playlist.allBlocked', { name: playlist.name });
+        }
+
+        // Queue copies so the requester is whoever played the playlist
+        const songs = queueManager.withoutQueued(
+          guildId,
+          allowed.map((song) => ({ ...song, addedBy: userId }))
+        );
+        if (songs.length === 0) {
+          throw new PlaylistError('playlist.allQueued', { name: playlist.name });
+        }
+        const added = queueManager.addSongs(guildId, songs, undefined, getQueueLimits(interaction, settings));
+        const skipped = [
+          blocked.length > 0 ? t('play.skippedBlocked', { count: blocked.length }) : '',
+          allowed.length > songs.length ? t('play.skippedQueued', { count: allowed.length - songs.length }) : '',
+        ].filter(Boolean);
+
+        const embed = new EmbedBuilder()
+          .setColor('#1DB954')
+          .setTitle(t('playlist.queued'))
+          .setDescription(`**${playlist.name}**`)
+          .addFields({
+            name: t('play.songs'),
+            value: skipped.length > 0 ? t('play.songsSkipped', { added, skipped: skipped.join(', ') }) : `${added}`,
+            inline: true,
+          })
+          .setFooter({ text: t('common.addedBy', { user: interaction.user.username }) })
+          .setTimestamp();
+
+        await interaction.reply({ embeds: [embed] });
+
+        if (!queueManager.isPlaying(guildId)) {
+          await startPlayback(interaction, queueManager, musicPlayer);
+        }
+        return;
+      }
+
+      case 'delete': {
+        const deleted = playlists.deletePlaylist(guildId, userId, interaction.options.getString('name', true));
+        await interaction.reply(t('playlist.deleted', { name: deleted.name }));
+        return;
+      }
+    }
+  } catch (error) {
+    if (!(error instanceof PlaylistError || error instanceof QueueLimitError)) {
+      throw error;
+    }
+
+    const content = describeError(error, t);
+    if (interaction.deferred) {
+      await interaction.editReply(content);
+    } else {
+      await interaction.reply({ content, ephemeral: true });
+    }
+  }
+}
+
+function buildShowEmbed(
+  services: BotServices,
+  guildId: string,
+  userId: string,
+  name: string | null,
+  t: Translator
+): EmbedBuilder {
+  const embed = new EmbedBuilder().setColor('#1DB954');
+
+  if (!name) {
+    const userPlaylists = services.playlistManager.getPlaylists(guildId, userId);
+    return embed
+      .setTitle(t('playlist.yours'))
+      .setDescription(
+        userPlaylists.length > 0
+          ? userPlaylists.map((playlist) => `• **${playlist.name}** — ${songCount(playlist.songs.length, t)}`).join('\n')
+          : t('playlist.noneYet')
+      );
+  }
+
+  const playlist = services.playlistManager.getPlaylist(guildId, userId, name);
+  if (!playlist) {
+    throw new PlaylistError('playlist.notFound', { name });
+  }
+
+  const totalDuration = playlist.songs.reduce((total, song) => total + song.duration, 0);
+  embed
+    .setTitle(`📚 ${playlist.name}`)
+    .setDescription(
+      playlist.songs.length > 0
+        ? playlist.songs
+            .slice(0, PLAYLIST_PREVIEW_SIZE)
+            .map((song, index) => `${index + 1}. [${song.title}](${song.url})`)
+            .join('\n')
+        : t('playlist.emptyHint')
+    )
+    .setFooter({
+      text:
+        playlist.songs.length > PLAYLIST_PREVIEW_SIZE
+          ? `${t('player.moreSongs', { count: playlist.songs.length - PLAYLIST_PREVIEW_SIZE })} • ${YouTubeUtil.formatDuration(totalDuration)}`
+          : `${songCount(playlist.songs.length, t)} • ${YouTubeUtil.formatDuration(totalDuration)}`,
+    });
+
+  return embed;
+}
+
+function songCount(count: number, t: Translator): string {
+  return count === 1 ? t('player.songCountOne') : t('player.songCount', { count });
+}
+
+function buildCreateModal(name: string, t: Translator): ModalBuilder {
+  return new ModalBuilder()
+    .setCustomId(CREATE_MODAL_PREFIX + name)
+    .setTitle(t('playlist.createTitle', { name }).slice(0, 45))
+    .addComponents(
+      new ActionRowBuilder<TextInputBuilder>().addComponents(
+        new TextInputBuilder()
+          .setCustomId('songs')
+          .setLabel(t('playlist.createLabel', { max: MAX_CREATE_SONGS }))
+          .setPlaceholder(t('playlist.createPlaceholder'))
+          .setStyle(TextInputStyle.Paragraph)
+          .setRequired(false)
+          .setMaxLength(4000)
+      )
+    );
+}
+
+/** The create form (`playlist:create:<name>`): makes the playlist with whichever pasted songs were found. */
+export async function handleModal(interaction: ModalSubmitInteraction, services: BotServices): Promise<void> {
+  if (!interaction.customId.startsWith(CREATE_MODAL_PREFIX)) return;
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const userId = interaction.user.id;
+  const name = interaction.customId.slice(CREATE_MODAL_PREFIX.length);
+
+  const entries = parseImportFile(interaction.fields.getTextInputValue('songs'), 'text');
+  const tooMany = entries.slice(MAX_CREATE_SONGS).map((entry) => ({
+    ...entry,
+    reason: t('playlist.createOverflow', { max: MAX_CREATE_SONGS }),
+  }));
+
+  // An empty form looks nothing up
+  if (entries.length > 0 && !(await checkExtractionLimit(interaction, services))) return;
+  await interaction.deferReply();
+  const progress = new ProgressMessage((content) => interaction.editReply(content));
+  const resolved = await resolveImportEntries(entries.slice(0, MAX_CREATE_SONGS), userId, guildId, (update) =>
+    progress.update(formatImportProgress(update, t))
+  );
+  await progress.stop();
+  const failures = [...resolved.failures, ...tooMany];
+
+  let playlistName: string;
+  try {
+    // Created only now, so closing the form leaves nothing behind
+    const playlist = services.playlistManager.createPlaylist(guildId, userId, name);
+    if (resolved.songs.length > 0) {
+      services.playlistManager.addSongs(guildId, userId, playlist.name, resolved.songs);
+    }
+    playlistName = playlist.name;
+  } catch (error) {
+    if (!(error instanceof PlaylistError)) throw error;
+    await interaction.editReply(describeError(error, t));
+    return;
+  }
+
+  let description = t('playlist.createdWith', { name: playlistName, songs: songCount(resolved.songs.length, t) });
+  if (failures.length > 0) {
+    description += `\n\n**${t('import.notAdded', { count: failures.length })}**\n${formatImportFailures(failures, MAX_REPORTED_FAILURES, t)}`;
+  }
+
+  const embed = new EmbedBuilder()
+    .setColor(failures.length === 0 ? '#1DB954' : '#FFA500')
+    .setTitle(t('playlist.created'))
+    .setDescription(description)
+    .setFooter({ text: t('playlist.createdBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ content: '', embeds: [embed] });
+}
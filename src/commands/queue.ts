@@ -1,96 +1,88 @@
-import {
-  ActionRowBuilder,
-  ButtonBuilder,
-  ButtonStyle,
-  SlashCommandBuilder,
-  ChatInputCommandInteraction,
-  EmbedBuilder,
-} from 'discord.js';
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
 import { QueueManager } from '../utils/queueManager';
-import { YouTubeUtil } from '../utils/youtubeUtil';
 import { MusicPlayer } from '../utils/musicPlayer';
+import { buildPlayerView, getRenderer } from '../utils/renderers';
+import { buildPlayerControls } from '../utils/playerControls';
+import { createThemedEmbed } from '../utils/embedTheme';
+import { fuzzyScore } from '../utils/fuzzySearch';
+import { formatTrack } from '../utils/titleParser';
+import { Translator } from '../utils/i18n';
+import { BotServices, EmbedTheme } from '../types/index';
+
+const QUEUE_PREVIEW_SIZE = 7;
+const MAX_FIND_RESULTS = 10;
 
 export const data = new SlashCommandBuilder()
   .setName('queue')
-  .setDescription('Show the current music queue');
+  .setDescription('Show the current music queue')
+  .addStringOption((option) =>
+    option.setName('find').setDescription('Only list queued songs whose title or channel matches this').setMaxLength(100)
+  );
 
 export async function execute(
   interaction: ChatInputCommandInteraction,
   queueManager: QueueManager,
   musicPlayer: MusicPlayer,
   startPlayerUpdate: (guildId: string) => void,
-  cleanupOldMessages: (channelId: string) => Promise<void>
+  cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
 ): Promise<void> {
   const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const query = interaction.options.getString('find');
+  if (query) {
+    await findInQueue(interaction, queueManager, query, t, services.guildSettings.get(guildId).theme);
+    return;
+  }
+
   const currentSong = queueManager.getCurrentSong(guildId);
   const upcomingSongs = queueManager.getAllSongs(guildId);
 
   if (!currentSong && upcomingSongs.length === 0) {
-    await interaction.reply({ content: '🎵 Queue is empty', ephemeral: true });
+    await interaction.reply({ content: t('common.queueEmpty'), ephemeral: true });
     return;
   }
 
   // Cleanup old messages BEFORE replying
   await cleanupOldMessages(interaction.channelId!);
 
-  const embed = new EmbedBuilder()
-    .setColor('#1DB954')
-    .setTitle('🎵 Music Queue')
-    .setThumbnail(currentSong?.thumbnail || 'https://via.placeholder.com/160x160?text=No+Song');
+  const settings = services.guildSettings.get(guildId);
+  const renderer = getRenderer(settings.renderer);
+  const rendered = await renderer.renderQueue(buildPlayerView(queueManager, guildId, QUEUE_PREVIEW_SIZE, t, settings.theme, musicPlayer.getFilters()));
 
-  // Current song
-  if (currentSong) {
-    embed.setDescription(`**${currentSong.title}**\n\n\`${YouTubeUtil.formatDuration(currentSong.duration)}\``);
-  }
-
-  // Upcoming songs
-  if (upcomingSongs.length > 0) {
-    const upcomingList = upcomingSongs
-      .slice(0, 7)
-      .map(
-        (song, index) =>
-          `${index + 1}. [${song.title}](${song.url})`
-      )
-      .join('\n');
+  const playerMsg = await interaction.reply({ ...rendered, components: buildPlayerControls(queueManager, guildId, musicPlayer, t) });
+  queueManager.setPlayerMessage(interaction.guildId!, playerMsg.id, interaction.channelId!);
+  startPlayerUpdate(interaction.guildId!);
+}
 
-    embed.addFields({
-      name: `📋 Up Next (${upcomingSongs.length})`,
-      value: upcomingList || 'No upcoming songs',
-      inline: false,
-    });
+/** Lists queued songs matching `query`, best match first, with the positions and IDs other commands take. */
+async function findInQueue(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  query: string,
+  t: Translator,
+  theme: EmbedTheme
+): Promise<void> {
+  const matches = queueManager
+    .getFullQueue(interaction.guildId!)
+    .map((song, index) => ({ song, position: index + 1, score: fuzzyScore(query, `${song.title} ${song.channel ?? ''}`) }))
+    .filter((match) => match.score > 0)
+    .sort((a, b) => b.score - a.score || a.position - b.position);
 
-    if (upcomingSongs.length > 7) {
-      embed.setFooter({ text: `... and ${upcomingSongs.length - 7} more songs` });
-    }
+  if (matches.length === 0) {
+    await interaction.reply({ content: t('queue.findNone', { query }), ephemeral: true });
+    return;
   }
 
-  const controlRow = new ActionRowBuilder<ButtonBuilder>().addComponents(
-    new ButtonBuilder()
-      .setCustomId('music:toggle')
-      .setEmoji('▶️')
-      .setStyle(ButtonStyle.Success)
-      .setDisabled(!currentSong),
-    new ButtonBuilder()
-      .setCustomId('music:skip')
-      .setEmoji('⏭️')
-      .setStyle(ButtonStyle.Secondary)
-      .setDisabled(!currentSong),
-    new ButtonBuilder()
-      .setCustomId('music:stop')
-      .setEmoji('⏹️')
-      .setStyle(ButtonStyle.Danger)
-      .setDisabled(!currentSong)
-  );
-
-  const infoRow = new ActionRowBuilder<ButtonBuilder>().addComponents(
-    new ButtonBuilder()
-      .setCustomId('music:refresh')
-      .setLabel('Refresh')
-      .setEmoji('🔄')
-      .setStyle(ButtonStyle.Secondary)
-  );
+  const lines = matches
+    .slice(0, MAX_FIND_RESULTS)
+    .map(({ song, position }) => `**#${position}** \`${song.queueId ?? '?'}\` ${formatTrack(song)} · <@${song.addedBy}>`);
+  if (matches.length > MAX_FIND_RESULTS) {
+    lines.push(t('player.moreSongs', { count: matches.length - MAX_FIND_RESULTS }));
+  }
 
-  const playerMsg = await interaction.reply({ embeds: [embed], components: [controlRow, infoRow] });
-  queueManager.setPlayerMessage(interaction.guildId!, playerMsg.id, interaction.channelId!);
-  startPlayerUpdate(interaction.guildId!);
+  const embed = createThemedEmbed(theme, t('queue.findFooter'))
+    .setTitle(t('queue.findTitle', { query, count: matches.length }))
+    .setDescription(lines.join('\n'));
+  await interaction.reply({ embeds: [embed], ephemeral: true });
 }
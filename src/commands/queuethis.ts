@@ -0,0 +1,66 @@
+import {
+  ApplicationCommandType,
+  ContextMenuCommandBuilder,
+  Message,
+  MessageContextMenuCommandInteraction,
+} from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { isSpotifyTrackUrl, spotifyTrackToQuery } from '../utils/spotify';
+import { findExtractor } from '../utils/sourceExtractor';
+import { queueRequest } from './play';
+import { BotServices } from '../types/index';
+
+export const data = new ContextMenuCommandBuilder().setName('Queue this').setType(ApplicationCommandType.Message);
+
+/** The first link /play can queue, or Spotify track link, in the message's text or embeds. */
+function findSongLink(message: Message): string | undefined {
+  const texts = [message.content, ...message.embeds.flatMap((embed) => [embed.url ?? '', embed.description ?? ''])];
+  const links = texts.flatMap((text) => text.match(/https?:\/\/[^\s<>()]+/g) ?? []);
+  return links.find((link) => findExtractor(link) !== undefined || isSpotifyTrackUrl(link));
+}
+
+export async function execute(
+  interaction: MessageContextMenuCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  startPlayerUpdate: (guildId: string) => void,
+  cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const link = findSongLink(interaction.targetMessage);
+  if (!link) {
+    await interaction.reply({ content: t('queueThis.noLinks'), ephemeral: true });
+    return;
+  }
+
+  let input = link;
+  if (isSpotifyTrackUrl(link)) {
+    // Spotify can take longer to answer than Discord waits for a reply
+    await interaction.deferReply();
+    try {
+      const query = await spotifyTrackToQuery(link);
+      if (!query) {
+        await interaction.editReply(t('queueThis.spotifyUnreadable'));
+        return;
+      }
+      input = query;
+    } catch (error) {
+      console.error('Spotify lookup error:', error);
+      await interaction.editReply(t('queueThis.spotifyFailed'));
+      return;
+    }
+  }
+
+  await queueRequest(
+    interaction,
+    input,
+    { shuffle: false, splitChapters: false },
+    queueManager,
+    musicPlayer,
+    startPlayerUpdate,
+    cleanupOldMessages,
+    services
+  );
+}
@@ -0,0 +1,43 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('remove')
+  .setDescription('Remove a song from the queue')
+  .addStringOption((option) =>
+    option.setName('song').setDescription('Queue position or ID (e.g. kobe), as shown by /queue').setRequired(true)
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const reference = interaction.options.getString('song', true);
+  const queueSize = queueManager.getQueueSize(guildId);
+
+  if (queueSize === 0) {
+    await interaction.reply({ content: t('common.queueEmpty'), ephemeral: true });
+    return;
+  }
+
+  // Resolved now so a song added or moved by someone else doesn't change what gets removed
+  const index = queueManager.findSongIndex(guildId, reference);
+  const removed = index === -1 ? null : queueManager.removeSong(guildId, index);
+  if (!removed) {
+    await interaction.reply({
+      content: t('common.noSongMatches', { reference, size: queueSize }),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  await interaction.reply(t('remove.done', { title: removed.title, id: removed.queueId ?? '', position: index + 1 }));
+}
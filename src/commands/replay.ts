@@ -0,0 +1,37 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { seekTo } from '../utils/playback';
+import { formatTrack } from '../utils/titleParser';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('replay')
+  .setDescription('Restart the current song from the beginning');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const currentSong = queueManager.getCurrentSong(guildId);
+
+  if (!currentSong) {
+    await interaction.reply(t('common.nothingPlaying'));
+    return;
+  }
+  if (currentSong.isLive) {
+    await interaction.reply({ content: t('replay.live'), ephemeral: true });
+    return;
+  }
+
+  // Restarts the stream in place; the queue is left alone
+  await interaction.deferReply();
+  await seekTo(guildId, queueManager, musicPlayer, 0);
+  await interaction.editReply(t('replay.done', { track: formatTrack(currentSong) }));
+}
@@ -1,6 +1,8 @@
 import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
 import { QueueManager } from '../utils/queueManager';
 import { MusicPlayer } from '../utils/musicPlayer';
+import { formatTrack } from '../utils/titleParser';
+import { BotServices } from '../types/index';
 
 export const data = new SlashCommandBuilder()
   .setName('resume')
@@ -11,13 +13,15 @@ export async function execute(
   queueManager: QueueManager,
   musicPlayer: MusicPlayer,
   _startPlayerUpdate: (guildId: string) => void,
-  _cleanupOldMessages: (channelId: string) => Promise<void>
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
 ): Promise<void> {
   const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
   const currentSong = queueManager.getCurrentSong(guildId);
 
   if (!currentSong) {
-    await interaction.reply("❌ Nothing is paused");
+    await interaction.reply(t('common.nothingPaused'));
     return;
   }
 
@@ -25,8 +29,8 @@ export async function execute(
 
   const embed = new EmbedBuilder()
     .setColor('#FF0000')
-    .setTitle('▶️ Resumed')
-    .setDescription(currentSong.title)
+    .setTitle(t('resume.title'))
+    .setDescription(formatTrack(currentSong))
     .setTimestamp();
 
   await interaction.reply({ embeds: [embed] });
@@ -0,0 +1,23 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { seekRelative } from './ff';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('rw')
+  .setDescription('Rewind the current song')
+  .addIntegerOption((option) =>
+    option.setName('seconds').setDescription('How far to jump back (default 10)').setMinValue(1).setMaxValue(3600)
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  await seekRelative(interaction, queueManager, musicPlayer, services, -(interaction.options.getInteger('seconds') ?? 10));
+}
@@ -0,0 +1,613 @@
+import {
+  AutocompleteInteraction,
+  SlashCommandBuilder,
+  ChatInputCommandInteraction,
+  ChannelType,
+  EmbedBuilder,
+  PermissionFlagsBits,
+  TextChannel,
+  NewsChannel,
+} from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { isAdmin } from '../utils/permissions';
+import { ALWAYS_ENABLED_COMMANDS, getCommandNames } from '../utils/commandRegistry';
+import { createApiKey } from '../utils/restApi';
+import { webServer } from '../utils/webServer';
+import { speechEngine } from '../utils/tts';
+import { lavalink } from '../utils/lavalink';
+import { REQUEST_CHANNELS_ENABLED } from '../utils/requestChannel';
+import { LOCALES } from '../utils/i18n';
+import {
+  EMOJI_SETS,
+  MAX_FOOTER_TEXT_LENGTH,
+  createDefaultTheme,
+  createThemedEmbed,
+  parseAccentColor,
+} from '../utils/embedTheme';
+import { BotServices, EmbedTheme, EmojiSet, GuildSettings, Locale } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('settings')
+  .setDescription('Server settings for the bot (admins only)')
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('announce')
+      .setDescription('Post now-playing and queue notices in one channel')
+      .addChannelOption((option) =>
+        option
+          .setName('channel')
+          .setDescription('Where to post them (leave empty to go back to where commands are used)')
+          .addChannelTypes(ChannelType.GuildText, ChannelType.GuildAnnouncement)
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('requestchannel')
+      .setDescription('Queue every message posted in a channel as a song request')
+      .addChannelOption((option) =>
+        option
+          .setName('channel')
+          .setDescription('The request channel (leave empty to turn request-channel mode off)')
+          .addChannelTypes(ChannelType.GuildText)
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('nowplaying')
+      .setDescription('Turn the message posted when each song starts on its own on or off')
+      .addBooleanOption((option) => option.setName('enabled').setDescription('Post now-playing messages').setRequired(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('autodedupe')
+      .setDescription("Leave out songs that are already in the queue when they're added again")
+      .addBooleanOption((option) => option.setName('enabled').setDescription('Skip songs already queued').setRequired(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('tts')
+      .setDescription('Say the title of each song in voice before it plays')
+      .addBooleanOption((option) => option.setName('enabled').setDescription('Speak announcements').setRequired(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('intros')
+      .setDescription("Play members' intro clips over the music when they join my voice channel")
+      .addBooleanOption((option) => option.setName('enabled').setDescription('Play intros').setRequired(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('sticky')
+      .setDescription('Keep one pinned now-playing message that updates itself')
+      .addBooleanOption((option) => option.setName('enabled').setDescription('Use the sticky player').setRequired(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('commandchannel')
+      .setDescription('Choose the text channels commands can be used in')
+      .addChannelOption((option) =>
+        option
+          .setName('channel')
+          .setDescription('Channel to allow or disallow')
+          .setRequired(true)
+          .addChannelTypes(ChannelType.GuildText, ChannelType.GuildAnnouncement)
+      )
+      .addBooleanOption((option) =>
+        option.setName('allowed').setDescription('Allow commands there; once none are allowed, any channel works').setRequired(true)
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('command')
+      .setDescription('Turn a command off or back on for this server')
+      .addStringOption((option) =>
+        option.setName('name').setDescription('Command name, without the slash').setRequired(true).setAutocomplete(true)
+      )
+      .addBooleanOption((option) => option.setName('enabled').setDescription('Allow the command').setRequired(true))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('limits')
+      .setDescription("Cap how many and how long songs can be queued; DJs and admins aren't limited")
+      .addIntegerOption((option) =>
+        option.setName('per_user').setDescription('Most upcoming songs one person can have queued; 0 for no limit').setMinValue(0)
+      )
+      .addIntegerOption((option) =>
+        option.setName('total').setDescription('Most upcoming songs in the queue; 0 for no limit').setMinValue(0)
+      )
+      .addIntegerOption((option) =>
+        option.setName('song_minutes').setDescription('Longest song that can be queued, in minutes; 0 for no limit').setMinValue(0)
+      )
+      .addIntegerOption((option) =>
+        option
+          .setName('live_minutes')
+          .setDescription('How long a live stream plays before moving on, in minutes; 0 plays it until it ends')
+          .setMinValue(0)
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('dj')
+      .setDescription('Limit stopping, shuffling and looping for everyone to one role')
+      .addRoleOption((option) =>
+        option.setName('role').setDescription('The DJ role (leave empty to let anyone use them)')
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('follow')
+      .setDescription('Move with one member when they switch voice channels')
+      .addUserOption((option) => option.setName('user').setDescription('Who to follow (leave empty to stop following)'))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('language')
+      .setDescription('Choose the language for replies and player messages')
+      .addStringOption((option) =>
+        option
+          .setName('language')
+          .setDescription('Language to use')
+          .setRequired(true)
+          .addChoices(...(Object.keys(LOCALES) as Locale[]).map((locale) => ({ name: LOCALES[locale], value: locale })))
+      )
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('theme')
+      .setDescription('Change the color, footer and emoji of the player and queue')
+      .addStringOption((option) => option.setName('color').setDescription('Accent color as a hex code, e.g. #5865F2'))
+      .addStringOption((option) =>
+        option
+          .setName('footer')
+          .setDescription('Text for the footer; "none" removes it')
+          .setMaxLength(MAX_FOOTER_TEXT_LENGTH)
+      )
+      .addStringOption((option) =>
+        option
+          .setName('emoji')
+          .setDescription('Which emoji to use')
+          .addChoices(...(Object.keys(EMOJI_SETS) as EmojiSet[]).map((set) => ({ name: EMOJI_SETS[set], value: set })))
+      )
+      .addBooleanOption((option) => option.setName('reset').setDescription('Go back to the default look'))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('apikey')
+      .setDescription('Create a key for the REST API, replacing any old one')
+      .addBooleanOption((option) => option.setName('revoke').setDescription('Remove the key without making a new one'))
+  );
+
+export async function autocomplete(interaction: AutocompleteInteraction, services: BotServices): Promise<void> {
+  const partial = interaction.options.getFocused().toLowerCase().replace(/^\//, '');
+  const { disabledCommands } = services.guildSettings.get(interaction.guildId!);
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const choices = getCommandNames()
+    .filter((name) => !ALWAYS_ENABLED_COMMANDS.includes(name) && name.includes(partial))
+    .slice(0, 25)
+    .map((name) => ({
+      name: disabledCommands.includes(name) ? t('settings.commandOffChoice', { name }) : name,
+      value: name,
+    }));
+
+  await interaction.respond(choices);
+}
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  if (!isAdmin(interaction)) {
+    const t = services.guildSettings.translator(interaction.guildId!);
+    await interaction.reply({ content: t('settings.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  switch (interaction.options.getSubcommand()) {
+    case 'announce':
+      await setAnnounceChannel(interaction, services);
+      return;
+    case 'requestchannel':
+      await setRequestChannel(interaction, services);
+      return;
+    case 'nowplaying':
+      await setNowPlayingAnnouncements(interaction, services);
+      return;
+    case 'autodedupe':
+      await setAutoDedupe(interaction, services);
+      return;
+    case 'tts':
+      await setTtsAnnouncements(interaction, services);
+      return;
+    case 'intros':
+      await setJoinIntros(interaction, services);
+      return;
+    case 'sticky':
+      await setStickyPlayer(interaction, queueManager, services);
+      return;
+    case 'commandchannel':
+      await setCommandChannel(interaction, services);
+      return;
+    case 'command':
+      await setCommandEnabled(interaction, services);
+      return;
+    case 'limits':
+      await setQueueLimits(interaction, services);
+      return;
+    case 'dj':
+      await setDjRole(interaction, services);
+      return;
+    case 'follow':
+      await setFollowedUser(interaction, services);
+      return;
+    case 'language':
+      await setLanguage(interaction, services);
+      return;
+    case 'theme':
+      await setTheme(interaction, services);
+      return;
+    case 'apikey':
+      await setApiKey(interaction, services);
+      return;
+  }
+}
+
+async function setAnnounceChannel(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const channel = interaction.options.getChannel('channel', false, [ChannelType.GuildText, ChannelType.GuildAnnouncement]) as
+    | TextChannel
+    | NewsChannel
+    | null;
+
+  if (!channel) {
+    services.guildSettings.update(guildId, { announceChannelId: undefined });
+    await interaction.reply(t('settings.announceCleared'));
+    return;
+  }
+
+  const me = interaction.guild?.members.me;
+  const permissions = me ? channel.permissionsFor(me) : null;
+  if (!permissions?.has([PermissionFlagsBits.ViewChannel, PermissionFlagsBits.SendMessages])) {
+    await interaction.reply({ content: t('settings.announceNoPermission', { channel: `${channel}` }), ephemeral: true });
+    return;
+  }
+
+  services.guildSettings.update(guildId, { announceChannelId: channel.id });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('settings.announceTitle'))
+    .setDescription(t('settings.announceDescription', { channel: `${channel}` }))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
+
+async function setRequestChannel(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const channel = interaction.options.getChannel('channel', false, [ChannelType.GuildText]) as TextChannel | null;
+
+  if (!channel) {
+    services.guildSettings.update(guildId, { requestChannelId: undefined });
+    await interaction.reply(t('settings.requestOff'));
+    return;
+  }
+
+  if (!REQUEST_CHANNELS_ENABLED) {
+    await interaction.reply({
+      content: t('settings.requestUnavailable'),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  const me = interaction.guild?.members.me;
+  const permissions = me ? channel.permissionsFor(me) : null;
+  const needed = [
+    PermissionFlagsBits.ViewChannel,
+    PermissionFlagsBits.SendMessages,
+    PermissionFlagsBits.AddReactions,
+    PermissionFlagsBits.ReadMessageHistory,
+    PermissionFlagsBits.ManageMessages,
+  ];
+  if (!permissions?.has(needed)) {
+    await interaction.reply({
+      content: t('settings.requestNoPermission', { channel: `${channel}` }),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  services.guildSettings.update(guildId, { requestChannelId: channel.id });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('settings.requestTitle'))
+    .setDescription(t('settings.requestDescription', { channel: `${channel}` }))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
+
+async function setNowPlayingAnnouncements(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const enabled = interaction.options.getBoolean('enabled', true);
+  services.guildSettings.update(interaction.guildId!, { announceNowPlaying: enabled });
+  await interaction.reply(t(enabled ? 'settings.nowPlayingOn' : 'settings.nowPlayingOff'));
+}
+
+async function setAutoDedupe(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const enabled = interaction.options.getBoolean('enabled', true);
+  services.guildSettings.update(interaction.guildId!, { autoDedupe: enabled });
+  await interaction.reply(t(enabled ? 'settings.autoDedupeOn' : 'settings.autoDedupeOff'));
+}
+
+async function setTtsAnnouncements(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const enabled = interaction.options.getBoolean('enabled', true);
+  if (enabled && (!speechEngine || lavalink)) {
+    await interaction.reply({
+      content: t(lavalink ? 'settings.ttsLavalink' : 'settings.ttsNoEngine'),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  services.guildSettings.update(interaction.guildId!, { ttsAnnouncements: enabled });
+  await interaction.reply(t(enabled ? 'settings.ttsOn' : 'settings.ttsOff'));
+}
+
+async function setJoinIntros(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const enabled = interaction.options.getBoolean('enabled', true);
+  if (enabled && lavalink) {
+    await interaction.reply({ content: t('settings.introsLavalink'), ephemeral: true });
+    return;
+  }
+
+  services.guildSettings.update(interaction.guildId!, { joinIntros: enabled });
+  await interaction.reply(t(enabled ? 'settings.introsOn' : 'settings.introsOff'));
+}
+
+async function setStickyPlayer(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const enabled = interaction.options.getBoolean('enabled', true);
+  services.guildSettings.update(guildId, { stickyPlayer: enabled });
+
+  if (!enabled) {
+    // Forget the old message so turning it back on posts a fresh one
+    const queue = queueManager.getQueue(guildId);
+    queue.stickyChannelId = undefined;
+    queue.stickyMessageId = undefined;
+    queue.stickyIdle = undefined;
+  }
+
+  await interaction.reply(t(enabled ? 'settings.stickyOn' : 'settings.stickyOff'));
+}
+
+async function setDjRole(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const role = interaction.options.getRole('role');
+  services.guildSettings.update(interaction.guildId!, { djRoleId: role?.id });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t(role ? 'settings.djSet' : 'settings.djCleared'))
+    .setDescription(role ? t('settings.djSetDescription', { role: `${role}` }) : t('settings.djClearedDescription'))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
+
+async function setFollowedUser(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const user = interaction.options.getUser('user');
+  if (user?.bot) {
+    await interaction.reply({ content: t('settings.followBot'), ephemeral: true });
+    return;
+  }
+  services.guildSettings.update(interaction.guildId!, { followUserId: user?.id });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t(user ? 'settings.following' : 'settings.notFollowing'))
+    .setDescription(user ? t('settings.followingDescription', { user: `${user}` }) : t('settings.notFollowingDescription'))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
+
+async function setLanguage(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const guildId = interaction.guildId!;
+  const language = interaction.options.getString('language', true) as Locale;
+  services.guildSettings.update(guildId, { language });
+  // Answered in the new language, so the change is visible right away
+  const t = services.guildSettings.translator(guildId);
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('settings.languageTitle'))
+    .setDescription(t('settings.languageDescription', { language: LOCALES[language] }))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
+
+async function setTheme(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const colorInput = interaction.options.getString('color');
+  const footer = interaction.options.getString('footer');
+  const emojiSet = interaction.options.getString('emoji') as EmojiSet | null;
+
+  const color = colorInput === null ? undefined : parseAccentColor(colorInput);
+  if (colorInput !== null && !color) {
+    await interaction.reply({ content: t('settings.badColor'), ephemeral: true });
+    return;
+  }
+
+  // Options left out keep their current value, so running it bare shows the theme
+  const theme: EmbedTheme = interaction.options.getBoolean('reset')
+    ? createDefaultTheme()
+    : { ...services.guildSettings.get(guildId).theme };
+  if (color) theme.color = color;
+  if (footer !== null) theme.footerText = footer.trim().toLowerCase() === 'none' ? undefined : footer.trim() || undefined;
+  if (emojiSet) theme.emojiSet = emojiSet;
+  services.guildSettings.update(guildId, { theme });
+
+  // Shown in the new theme, as a preview
+  const embed = createThemedEmbed(theme, t('common.changedBy', { user: interaction.user.username }))
+    .setTitle(t('settings.themeTitle'))
+    .addFields(
+      { name: t('settings.themeColor'), value: `\`${theme.color}\``, inline: true },
+      { name: t('settings.themeEmoji'), value: EMOJI_SETS[theme.emojiSet], inline: true },
+      { name: t('settings.themeFooter'), value: theme.footerText ?? t('settings.themeNoFooter'), inline: true }
+    )
+    .setDescription(t('settings.themeDescription'))
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
+
+async function setApiKey(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (interaction.options.getBoolean('revoke')) {
+    services.guildSettings.update(guildId, { apiKey: undefined });
+    await interaction.reply({ content: t('settings.apiKeyRevoked'), ephemeral: true });
+    return;
+  }
+
+  if (!webServer) {
+    await interaction.reply({
+      content: t('settings.apiKeyNoServer'),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  const { key, hash } = createApiKey();
+  services.guildSettings.update(guildId, { apiKey: { hash, createdBy: interaction.user.id } });
+  await interaction.reply({
+    content: t('settings.apiKeyCreated', { key, url: `${webServer.publicUrl}/api` }),
+    ephemeral: true,
+  });
+}
+
+async function setQueueLimits(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const perUser = interaction.options.getInteger('per_user');
+  const total = interaction.options.getInteger('total');
+  const songMinutes = interaction.options.getInteger('song_minutes');
+  const liveMinutes = interaction.options.getInteger('live_minutes');
+
+  // Options left out keep their current value, so running it bare shows the limits
+  const changes: Partial<GuildSettings> = {};
+  if (perUser !== null) changes.maxSongsPerUser = perUser;
+  if (total !== null) changes.maxQueueSize = total;
+  if (songMinutes !== null) changes.maxSongMinutes = songMinutes;
+  if (liveMinutes !== null) changes.maxLiveMinutes = liveMinutes;
+  const settings = services.guildSettings.update(guildId, changes);
+  const describeLimit = (limit: number) => (limit > 0 ? t('settings.limitSongs', { count: limit }) : t('settings.noLimit'));
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('settings.limitsTitle'))
+    .addFields(
+      { name: t('settings.limitPerUser'), value: describeLimit(settings.maxSongsPerUser), inline: true },
+      { name: t('settings.limitTotal'), value: describeLimit(settings.maxQueueSize), inline: true },
+      {
+        name: t('settings.limitSongLength'),
+        value: settings.maxSongMinutes > 0 ? t('settings.limitMinutes', { count: settings.maxSongMinutes }) : t('settings.noLimit'),
+        inline: true,
+      },
+      {
+        name: t('settings.limitLive'),
+        value: settings.maxLiveMinutes > 0 ? t('settings.limitMinutes', { count: settings.maxLiveMinutes }) : t('settings.limitUntilEnd'),
+        inline: true,
+      }
+    )
+    .setDescription(t('settings.limitsDescription'))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
+
+async function setCommandChannel(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const channel = interaction.options.getChannel('channel', true, [ChannelType.GuildText, ChannelType.GuildAnnouncement]);
+  const allowed = interaction.options.getBoolean('allowed', true);
+
+  const others = services.guildSettings.get(guildId).allowedChannelIds.filter((channelId) => channelId !== channel.id);
+  const { allowedChannelIds } = services.guildSettings.update(guildId, {
+    allowedChannelIds: allowed ? [...others, channel.id] : others,
+  });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('settings.commandChannelsTitle'))
+    .setDescription(
+      allowedChannelIds.length > 0
+        ? t('settings.commandChannelsSome', { channels: allowedChannelIds.map((channelId) => `<#${channelId}>`).join(', ') })
+        : t('settings.commandChannelsAny')
+    )
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
+
+async function setCommandEnabled(interaction: ChatInputCommandInteraction, services: BotServices): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const name = interaction.options.getString('name', true).trim().toLowerCase().replace(/^\//, '');
+  const enabled = interaction.options.getBoolean('enabled', true);
+
+  if (!getCommandNames().includes(name)) {
+    await interaction.reply({ content: t('settings.unknownCommand', { name }), ephemeral: true });
+    return;
+  }
+  if (ALWAYS_ENABLED_COMMANDS.includes(name)) {
+    await interaction.reply({ content: t('settings.alwaysOn', { name }), ephemeral: true });
+    return;
+  }
+
+  const others = services.guildSettings.get(guildId).disabledCommands.filter((command) => command !== name);
+  const { disabledCommands } = services.guildSettings.update(guildId, {
+    disabledCommands: enabled ? others : [...others, name],
+  });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t(enabled ? 'settings.commandOn' : 'settings.commandOff', { name }))
+    .setDescription(
+      disabledCommands.length > 0
+        ? t('settings.disabledCommands', { commands: disabledCommands.map((command) => `\`/${command}\``).join(', ') })
+        : t('settings.allCommandsOn')
+    )
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
@@ -0,0 +1,28 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('shuffle')
+  .setDescription('Shuffle the upcoming songs');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (queueManager.getQueueSize(guildId) < 2) {
+    await interaction.reply({ content: t('common.needTwoToShuffle'), ephemeral: true });
+    return;
+  }
+
+  const shuffled = queueManager.shuffleQueue(guildId);
+  await interaction.reply(t('shuffle.done', { count: shuffled }));
+}
@@ -1,50 +1,70 @@
 import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
 import { QueueManager } from '../utils/queueManager';
 import { MusicPlayer } from '../utils/musicPlayer';
+import { formatTrack } from '../utils/titleParser';
+import { skipSong } from '../utils/playback';
+import { hasDjRole, isInBotVoiceChannel } from '../utils/permissions';
+import { describeVote, getVoiceListenerIds } from '../utils/votes';
+import { BotServices } from '../types/index';
 
 export const data = new SlashCommandBuilder()
   .setName('skip')
-  .setDescription('Skip the current song');
+  .setDescription('Skip the current song')
+  .addIntegerOption((option) =>
+    option
+      .setName('count')
+      .setDescription('How many songs to skip, counting the current one')
+      .setMinValue(1)
+      .setMaxValue(100)
+  );
 
 export async function execute(
   interaction: ChatInputCommandInteraction,
   queueManager: QueueManager,
   musicPlayer: MusicPlayer,
   _startPlayerUpdate: (guildId: string) => void,
-  _cleanupOldMessages: (channelId: string) => Promise<void>
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
 ): Promise<void> {
   const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
   const currentSong = queueManager.getCurrentSong(guildId);
 
   if (!currentSong) {
-    await interaction.reply("❌ Nothing is currently playing");
+    await interaction.reply(t('common.nothingPlaying'));
     return;
   }
 
-  // Skip current song
+  // Skipping only your own songs needs nobody else's say
+  if (!hasDjRole(interaction, services.guildSettings.get(guildId)) && !queueManager.isSoleRequester(guildId, interaction.user.id)) {
+    if (!isInBotVoiceChannel(interaction)) {
+      await interaction.reply({ content: t('skip.joinToVote'), ephemeral: true });
+      return;
+    }
+    const vote = services.votes.castVote(guildId, 'skip', interaction.user.id, getVoiceListenerIds(interaction.guild));
+    if (!vote.passed) {
+      await interaction.reply(describeVote(t('common.voteSkip'), vote, t));
+      return;
+    }
+  }
+  services.votes.reset(guildId, 'skip');
+
+  // Skip current song, and any queued songs after it
   const skipped = currentSong;
-  const nextSong = queueManager.dequeueNextSong(guildId);
-  queueManager.setCurrentSong(guildId, undefined);
+  const count = Math.min(interaction.options.getInteger('count') ?? 1, queueManager.getQueueSize(guildId) + 1);
 
   const embed = new EmbedBuilder()
     .setColor('#FF0000')
-    .setTitle('⏭️ Skipped')
-    .setDescription(skipped.title)
-    .setFooter({ text: 'Skipped by ' + interaction.user.username })
+    .setTitle(count > 1 ? t('skip.titleMany', { count }) : t('skip.title'))
+    .setDescription(formatTrack(skipped))
+    .setFooter({ text: t('skip.footer', { user: interaction.user.username }) })
     .setTimestamp();
 
   await interaction.reply({ embeds: [embed] });
 
-  // Play next song if available
+  const nextSong = await skipSong(guildId, queueManager, musicPlayer, skipped, count);
   if (nextSong) {
-    const queue = queueManager.getQueue(guildId);
-    if (queue.voiceConnection) {
-      queueManager.setCurrentSong(guildId, nextSong);
-      queueManager.setPlaying(guildId, true);
-      await musicPlayer.playSong(queue.voiceConnection, nextSong);
-    }
-  } else {
-    musicPlayer.stop();
-    queueManager.setPlaying(guildId, false);
+    embed.addFields({ name: t('skip.nowPlaying'), value: formatTrack(nextSong) });
+    await interaction.editReply({ embeds: [embed] });
   }
 }
@@ -0,0 +1,46 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { applyFilters } from '../utils/playback';
+import { BotServices, FeatureFlag } from '../types/index';
+
+export const feature: FeatureFlag = 'filters';
+
+export const data = new SlashCommandBuilder()
+  .setName('speed')
+  .setDescription('Change the playback speed without changing pitch')
+  .addNumberOption((option) =>
+    option
+      .setName('rate')
+      .setDescription('Playback speed, from 0.5 to 2 (1 is normal)')
+      .setRequired(true)
+      .setMinValue(0.5)
+      .setMaxValue(2)
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const rate = Math.round(interaction.options.getNumber('rate', true) * 100) / 100;
+  // Keep the current pitch so speed and nightcore can be combined
+  const filters = { ...musicPlayer.getFilters(), speed: rate };
+
+  await interaction.deferReply();
+  await applyFilters(guildId, queueManager, musicPlayer, filters);
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('speed.title'))
+    .setDescription(rate === 1 ? t('speed.normal') : t('speed.changed', { rate }))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+}
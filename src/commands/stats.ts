@@ -0,0 +1,118 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { backendHealth } from '../utils/backendHealth';
+import { STREAM_BACKENDS } from '../utils/streamBackends';
+import { SongStats, rankSongs } from '../utils/listeningStats';
+import { Translator } from '../utils/i18n';
+import { BotServices } from '../types/index';
+
+const TOP_SONGS_SHOWN = 5;
+
+export const data = new SlashCommandBuilder()
+  .setName('stats')
+  .setDescription('Listening statistics and bot health')
+  .addSubcommand((subcommand) =>
+    subcommand
+      .setName('listening')
+      .setDescription('Show plays, listening time and top songs for the server or a member')
+      .addUserOption((option) => option.setName('user').setDescription('Member to show (leave empty for the whole server)'))
+  )
+  .addSubcommand((subcommand) =>
+    subcommand.setName('backends').setDescription('Show how reliably each stream backend has been working')
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  if (interaction.options.getSubcommand() === 'backends') {
+    await showBackendHealth(interaction, t);
+    return;
+  }
+  await showListeningStats(interaction, services, t);
+}
+
+/** e.g. `3h 25m`, or `12m` under an hour. */
+function formatListeningTime(seconds: number): string {
+  const hours = Math.floor(seconds / 3600);
+  const minutes = Math.floor((seconds % 3600) / 60);
+  return hours > 0 ? `${hours}h ${minutes}m` : `${minutes}m`;
+}
+
+function describeTopSongs(songs: Record<string, SongStats>, t: Translator): string {
+  const ranked = rankSongs(songs, TOP_SONGS_SHOWN);
+  if (ranked.length === 0) return t('stats.nothingYet');
+  return ranked
+    .map((song, index) => `${index + 1}. [${song.title.slice(0, 80)}](${song.url}) — ${t('history.plays', { count: song.plays })}`)
+    .join('\n');
+}
+
+async function showListeningStats(
+  interaction: ChatInputCommandInteraction,
+  services: BotServices,
+  t: Translator
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const user = interaction.options.getUser('user');
+  const embed = new EmbedBuilder().setColor('#1DB954').setTimestamp();
+
+  if (user) {
+    const stats = services.listeningStats.getUserStats(guildId, user.id);
+    embed
+      .setTitle(t('stats.userTitle', { user: user.username }))
+      .setThumbnail(user.displayAvatarURL())
+      .addFields(
+        { name: t('stats.songsRequested'), value: `${stats.plays}`, inline: true },
+        { name: t('stats.timeListening'), value: formatListeningTime(stats.listenedSeconds), inline: true },
+        { name: t('stats.mostRequested'), value: describeTopSongs(stats.songs, t) }
+      );
+  } else {
+    const stats = services.listeningStats.getGuildStats(guildId);
+    const topRequesters = services.listeningStats
+      .rank(guildId, 'all', TOP_SONGS_SHOWN)
+      .requesters.map(({ userId, plays }, index) => `${index + 1}. <@${userId}> — ${t('top.songs', { count: plays })}`);
+    embed
+      .setTitle(t('stats.serverTitle'))
+      .addFields(
+        { name: t('stats.songsPlayed'), value: `${stats.plays}`, inline: true },
+        { name: t('stats.musicPlayed'), value: formatListeningTime(stats.listenedSeconds), inline: true },
+        { name: t('stats.mostPlayed'), value: describeTopSongs(stats.songs, t) },
+        { name: t('stats.topRequesters'), value: topRequesters.length > 0 ? topRequesters.join('\n') : t('stats.nobodyYet') }
+      );
+  }
+
+  await interaction.reply({ embeds: [embed] });
+}
+
+async function showBackendHealth(interaction: ChatInputCommandInteraction, t: Translator): Promise<void> {
+  const lines = backendHealth.rank(STREAM_BACKENDS).map((backend, index) => {
+    const health = backendHealth.getHealth(backend.name);
+    if (health.attempts === 0) {
+      return `${index + 1}. **${backend.name}** — ${t('stats.noRecentRequests')}`;
+    }
+
+    const successRate = Math.round(((health.attempts - health.failures) / health.attempts) * 100);
+    const icon = successRate >= 90 ? '🟢' : successRate >= 50 ? '🟡' : '🔴';
+    let line = `${index + 1}. ${icon} **${backend.name}** — ${t('stats.successRate', { rate: successRate, attempts: health.attempts })}`;
+    if (health.lastFailureAt && health.lastError) {
+      const time = `<t:${Math.floor(health.lastFailureAt / 1000)}:R>`;
+      line += `\n   ${t('stats.lastFailure', { time, error: health.lastError.slice(0, 100) })}`;
+    }
+    return line;
+  });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('stats.backendsTitle'))
+    .setDescription(lines.join('\n'))
+    .setFooter({ text: t('stats.backendsFooter') })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed], ephemeral: true });
+}
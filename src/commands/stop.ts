@@ -1,6 +1,11 @@
 import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
 import { QueueManager } from '../utils/queueManager';
 import { MusicPlayer } from '../utils/musicPlayer';
+import { describeBandwidth } from '../utils/bandwidth';
+import { playbackActors } from '../utils/guildActor';
+import { hasDjRole, isInBotVoiceChannel } from '../utils/permissions';
+import { describeVote, getVoiceListenerIds } from '../utils/votes';
+import { BotServices } from '../types/index';
 
 export const data = new SlashCommandBuilder()
   .setName('stop')
@@ -11,27 +16,46 @@ export async function execute(
   queueManager: QueueManager,
   musicPlayer: MusicPlayer,
   _startPlayerUpdate: (guildId: string) => void,
-  _cleanupOldMessages: (channelId: string) => Promise<void>
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
 ): Promise<void> {
   const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
   const isPlaying = queueManager.isPlaying(guildId);
 
   if (!isPlaying) {
-    await interaction.reply("❌ Nothing is currently playing");
+    await interaction.reply(t('common.nothingPlaying'));
     return;
   }
 
-  // Stop music and clear queue
-  const queue = queueManager.getQueue(guildId);
-  musicPlayer.stop();
-  queue.voiceConnection?.destroy();
-  queueManager.clearQueue(guildId);
+  if (!hasDjRole(interaction, services.guildSettings.get(guildId))) {
+    if (!isInBotVoiceChannel(interaction)) {
+      await interaction.reply({ content: t('stop.joinToVote'), ephemeral: true });
+      return;
+    }
+    const vote = services.votes.castVote(guildId, 'stop', interaction.user.id, getVoiceListenerIds(interaction.guild));
+    if (!vote.passed) {
+      await interaction.reply(describeVote(t('common.voteStop'), vote, t));
+      return;
+    }
+  }
+  services.votes.reset(guildId);
+
+  // Clear the queue before stopping, so the player going idle doesn't start the next song
+  const usage = await playbackActors.run(guildId, () => {
+    const connection = queueManager.getQueue(guildId).voiceConnection;
+    queueManager.clearQueue(guildId);
+    musicPlayer.stop();
+    connection?.destroy();
+    return musicPlayer.resetBandwidthUsage();
+  });
 
   const embed = new EmbedBuilder()
     .setColor('#FF0000')
-    .setTitle('⏹️ Stopped')
-    .setDescription('Music stopped and queue cleared')
-    .setFooter({ text: 'Stopped by ' + interaction.user.username })
+    .setTitle(t('stop.title'))
+    .setDescription(t('stop.description'))
+    .addFields({ name: t('common.dataUsed'), value: describeBandwidth(usage, t), inline: false })
+    .setFooter({ text: t('stop.footer', { user: interaction.user.username }) })
     .setTimestamp();
 
   await interaction.reply({ embeds: [embed] });
@@ -0,0 +1,48 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { RENDERER_STYLES } from '../utils/renderers';
+import { isAdmin } from '../utils/permissions';
+import { MessageKey } from '../utils/i18n';
+import { BotServices, RendererStyle } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('style')
+  .setDescription('Choose how the player and queue are displayed on this server (admins only)')
+  .addStringOption((option) =>
+    option
+      .setName('style')
+      .setDescription('Display style')
+      .setRequired(true)
+      .addChoices(
+        ...(Object.keys(RENDERER_STYLES) as RendererStyle[]).map((style) => ({
+          name: `${style}: ${RENDERER_STYLES[style]}`,
+          value: style,
+        }))
+      )
+  );
+
+const STYLE_DESCRIPTIONS: Record<RendererStyle, MessageKey> = {
+  classic: 'style.classic',
+  compact: 'style.compact',
+  card: 'style.card',
+};
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  if (!isAdmin(interaction)) {
+    await interaction.reply({ content: t('style.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  const style = interaction.options.getString('style', true) as RendererStyle;
+  services.guildSettings.update(interaction.guildId!, { renderer: style });
+  await interaction.reply(t('style.changed', { style, description: t(STYLE_DESCRIPTIONS[style]) }));
+}
@@ -0,0 +1,50 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { BotServices } from '../types/index';
+
+const MAX_SUGGESTIONS = 10;
+
+export const data = new SlashCommandBuilder()
+  .setName('suggest')
+  .setDescription('Show search suggestions for a partial song name')
+  .addStringOption((option) =>
+    option
+      .setName('partial')
+      .setDescription('The start of a song name')
+      .setRequired(true)
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const partial = interaction.options.getString('partial', true);
+  const provider = services.suggestionProvider;
+
+  if (provider.name === 'none') {
+    await interaction.reply({ content: t('suggest.disabled'), ephemeral: true });
+    return;
+  }
+
+  await interaction.deferReply({ ephemeral: true });
+
+  const suggestions = await provider.suggest(interaction.guildId!, partial, MAX_SUGGESTIONS);
+  if (suggestions.length === 0) {
+    await interaction.editReply(t('suggest.none', { partial }));
+    return;
+  }
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('suggest.title', { partial }))
+    .setDescription(suggestions.map((suggestion, index) => `${index + 1}. ${suggestion.name}`).join('\n'))
+    .setFooter({ text: t('suggest.footer', { source: provider.name }) });
+
+  await interaction.editReply({ embeds: [embed] });
+}
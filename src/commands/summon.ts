@@ -0,0 +1,7 @@
+import { SlashCommandBuilder } from 'discord.js';
+
+export const data = new SlashCommandBuilder()
+  .setName('summon')
+  .setDescription('Bring the bot into your voice channel, moving it if it is elsewhere');
+
+export { execute } from './join';
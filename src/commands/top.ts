@@ -0,0 +1,65 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { StatsPeriod } from '../utils/listeningStats';
+import { MessageKey } from '../utils/i18n';
+import { BotServices } from '../types/index';
+
+const ENTRIES_SHOWN = 10;
+
+const PERIOD_LABELS: Record<StatsPeriod, MessageKey> = {
+  week: 'top.week',
+  month: 'top.month',
+  all: 'top.all',
+};
+
+export const data = new SlashCommandBuilder()
+  .setName('top')
+  .setDescription('Show the most played songs and most active requesters')
+  .addStringOption((option) =>
+    option
+      .setName('period')
+      .setDescription('How far back to look (default: this week)')
+      .addChoices(
+        { name: 'Past 7 days', value: 'week' },
+        { name: 'Past 30 days', value: 'month' },
+        { name: 'All time', value: 'all' }
+      )
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const period = (interaction.options.getString('period') ?? 'week') as StatsPeriod;
+  const { songs, requesters } = services.listeningStats.rank(interaction.guildId!, period, ENTRIES_SHOWN);
+
+  if (songs.length === 0) {
+    await interaction.reply({ content: t('top.nothingPlayed'), ephemeral: true });
+    return;
+  }
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('top.title', { period: t(PERIOD_LABELS[period]) }))
+    // Ten linked titles can outgrow a field, so the songs go in the description
+    .setDescription(
+      songs
+        .map((song, index) => `${index + 1}. [${song.title.slice(0, 80)}](${song.url}) — ${t('history.plays', { count: song.plays })}`)
+        .join('\n')
+    )
+    .addFields({
+      name: t('top.requesters'),
+      value: requesters
+        .map(({ userId, plays }, index) => `${index + 1}. <@${userId}> — ${t('top.songs', { count: plays })}`)
+        .join('\n'),
+    })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
@@ -0,0 +1,42 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { hasDjRole } from '../utils/permissions';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('unlock')
+  .setDescription('Let everyone add songs again (DJs and admins only)');
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const settings = services.guildSettings.get(guildId);
+
+  if (!hasDjRole(interaction, settings)) {
+    await interaction.reply({ content: t('unlock.djOnly'), ephemeral: true });
+    return;
+  }
+  if (!settings.queueLocked) {
+    await interaction.reply({ content: t('unlock.notLocked'), ephemeral: true });
+    return;
+  }
+
+  services.guildSettings.update(guildId, { queueLocked: false });
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('unlock.title'))
+    .setDescription(t('unlock.description'))
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.reply({ embeds: [embed] });
+}
@@ -0,0 +1,65 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { VOICE_INTERRUPTION_POLICIES } from '../utils/guildSettings';
+import { isAdmin } from '../utils/permissions';
+import { MessageKey } from '../utils/i18n';
+import { BotServices, VoiceInterruptionPolicy } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('voicepolicy')
+  .setDescription('Choose what happens when the bot is server-muted mid-song')
+  .addStringOption((option) =>
+    option
+      .setName('policy')
+      .setDescription('What to do (leave empty to see the current policy)')
+      .addChoices(
+        ...(Object.keys(VOICE_INTERRUPTION_POLICIES) as VoiceInterruptionPolicy[]).map((policy) => ({
+          name: `${policy}: ${VOICE_INTERRUPTION_POLICIES[policy]}`,
+          value: policy,
+        }))
+      )
+  );
+
+const POLICY_DESCRIPTIONS: Record<VoiceInterruptionPolicy, MessageKey> = {
+  pause: 'voicePolicy.pause',
+  announce: 'voicePolicy.announce',
+  disconnect: 'voicePolicy.disconnect',
+};
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  _queueManager: QueueManager,
+  _musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+  const policy = interaction.options.getString('policy') as VoiceInterruptionPolicy | null;
+
+  if (!policy) {
+    const current = services.guildSettings.get(guildId).voiceInterruptionPolicy;
+    const lines = (Object.keys(VOICE_INTERRUPTION_POLICIES) as VoiceInterruptionPolicy[]).map(
+      (option) => `${option === current ? '🟢' : '⚪'} **${option}** — ${t(POLICY_DESCRIPTIONS[option])}`
+    );
+
+    const embed = new EmbedBuilder()
+      .setColor('#1DB954')
+      .setTitle(t('voicePolicy.title'))
+      .setDescription(lines.join('\n'))
+      .setFooter({ text: t('voicePolicy.footer') });
+
+    await interaction.reply({ embeds: [embed], ephemeral: true });
+    return;
+  }
+
+  if (!isAdmin(interaction)) {
+    await interaction.reply({ content: t('voicePolicy.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  services.guildSettings.update(guildId, { voiceInterruptionPolicy: policy });
+  await interaction.reply(t('voicePolicy.changed', { policy: t(POLICY_DESCRIPTIONS[policy]) }));
+}
@@ -0,0 +1,55 @@
+import { SlashCommandBuilder, ChatInputCommandInteraction, EmbedBuilder } from 'discord.js';
+import { QueueManager } from '../utils/queueManager';
+import { MusicPlayer } from '../utils/musicPlayer';
+import { applyVolume } from '../utils/playback';
+import { MAX_VOLUME } from '../utils/userPreferences';
+import { isAdmin } from '../utils/permissions';
+import { BotServices } from '../types/index';
+
+export const data = new SlashCommandBuilder()
+  .setName('volume')
+  .setDescription("Set the server's playback volume (admins only)")
+  .addIntegerOption((option) =>
+    option
+      .setName('percent')
+      .setDescription(`Volume from 0 to ${MAX_VOLUME}; 100 is the original level`)
+      .setRequired(true)
+      .setMinValue(0)
+      .setMaxValue(MAX_VOLUME)
+  );
+
+export async function execute(
+  interaction: ChatInputCommandInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  _startPlayerUpdate: (guildId: string) => void,
+  _cleanupOldMessages: (channelId: string) => Promise<void>,
+  services: BotServices
+): Promise<void> {
+  const guildId = interaction.guildId!;
+  const t = services.guildSettings.translator(guildId);
+
+  if (!isAdmin(interaction)) {
+    await interaction.reply({ content: t('volume.adminOnly'), ephemeral: true });
+    return;
+  }
+
+  const volume = interaction.options.getInteger('percent', true);
+  services.guildSettings.update(guildId, { volume });
+
+  await interaction.deferReply();
+  await applyVolume(guildId, queueManager, musicPlayer);
+
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(t('volume.title', { volume }))
+    .setDescription(
+      musicPlayer.getVolume() === volume
+        ? t('volume.everywhere')
+        : t('volume.personal', { volume: musicPlayer.getVolume() })
+    )
+    .setFooter({ text: t('common.changedBy', { user: interaction.user.username }) })
+    .setTimestamp();
+
+  await interaction.editReply({ embeds: [embed] });
+}
@@ -1,23 +1,59 @@
+// Load .env before any module reads process.env at import time
+import 'dotenv/config';
 import {
-  ActionRowBuilder,
-  ButtonBuilder,
-  ButtonStyle,
+  ApplicationCommandType,
+  AutocompleteInteraction,
   Client,
   Collection,
-  EmbedBuilder,
   GatewayIntentBits,
+  MessageComponentInteraction,
+  ModalSubmitInteraction,
+  PermissionFlagsBits,
   REST,
   Routes,
+  VoiceState,
 } from 'discord.js';
-import * as dotenv from 'dotenv';
 import * as path from 'path';
 import * as fs from 'fs';
 import { MusicPlayer } from './utils/musicPlayer';
+import { connectToChannel, createMusicPlayer, mixInClip, skipSong } from './utils/playback';
+import { lavalink } from './utils/lavalink';
+import { tempFiles } from './utils/tempFiles';
+import { PlaybackStateDocument, SavedPlayback, savePlaybackState, takePlaybackState } from './utils/playbackState';
 import { QueueManager } from './utils/queueManager';
-import { AudioPlayerStatus } from '@discordjs/voice';
-import { YouTubeUtil } from './utils/youtubeUtil';
-
-dotenv.config();
+import { VoiceConnectionStatus, entersState } from '@discordjs/voice';
+import { Storage } from './utils/storage';
+import { TtlCache } from './utils/ttlCache';
+import { createSuggestionProvider } from './utils/suggestionProvider';
+import { PlaylistManager } from './utils/playlistManager';
+import { GuildSettingsManager } from './utils/guildSettings';
+import { buildPlayerView, getRenderer } from './utils/renderers';
+import { DJ_ONLY_BUTTON_IDS, LOOP_MODE_LABELS, NEXT_LOOP_MODE, PLAYER_BUTTON_IDS, buildPlayerControls } from './utils/playerControls';
+import { hasDjRole, isAdmin, isDj, isInBotVoiceChannel } from './utils/permissions';
+import { describeBandwidth } from './utils/bandwidth';
+import { startManagedYtDlp } from './utils/ytDlpBinary';
+import { UserPreferencesManager, chooseVolume } from './utils/userPreferences';
+import { VoteTracker, describeVote } from './utils/votes';
+import { ListeningStatsManager } from './utils/listeningStats';
+import { IntroManager } from './utils/intros';
+import { setCommands } from './utils/commandRegistry';
+import { playbackActors } from './utils/guildActor';
+import { playbackEvents } from './utils/playbackEvents';
+import { formatTrack } from './utils/titleParser';
+import { MessageKey, MessageVars } from './utils/i18n';
+import { webServer } from './utils/webServer';
+import { registerDashboardRoutes } from './utils/dashboard';
+import { registerApiRoutes } from './utils/restApi';
+import { registerPlaybackStream } from './utils/playbackStream';
+import { IDLE_CHECK_INTERVAL_MS, IdleTracker } from './utils/idleTracker';
+import { describeError, logError } from './utils/errors';
+import { ExtractionRateLimiter } from './utils/rateLimiter';
+import { trackMetadata } from './utils/trackMetadata';
+import { REQUEST_CHANNELS_ENABLED, handleRequestMessage } from './utils/requestChannel';
+import { pickAutoplaySong } from './utils/autoplay';
+import { icyWatcher } from './utils/icyMetadata';
+import { mixFeeds } from './utils/mixFeed';
+import { BotServices, FeatureFlag, Song } from './types/index';
 
 const TOKEN = process.env.DISCORD_TOKEN;
 const CLIENT_ID = process.env.CLIENT_ID;
@@ -35,35 +71,31 @@ const client = new Client({
     GatewayIntentBits.GuildMembers,
     GatewayIntentBits.GuildVoiceStates,
     GatewayIntentBits.DirectMessages,
+    ...(REQUEST_CHANNELS_ENABLED ? [GatewayIntentBits.GuildMessages, GatewayIntentBits.MessageContent] : []),
   ],
 });
 
 // Create instances
-const queueManager = new QueueManager();
+const storage = new Storage();
+trackMetadata.attach(storage);
+const queueManager = new QueueManager(storage);
+const services: BotServices = {
+  storage,
+  suggestionProvider: createSuggestionProvider(process.env.SUGGESTION_PROVIDER, queueManager),
+  playlistManager: new PlaylistManager(storage),
+  guildSettings: new GuildSettingsManager(storage),
+  userPreferences: new UserPreferencesManager(storage),
+  votes: new VoteTracker(),
+  listeningStats: new ListeningStatsManager(storage),
+  intros: new IntroManager(storage.getDataDir()),
+  rateLimiter: new ExtractionRateLimiter(),
+};
+queueManager.setAutoDedupe((guildId) => services.guildSettings.get(guildId).autoDedupe);
 const musicPlayers = new Map<string, MusicPlayer>();
 const playerUpdateIntervals = new Map<string, NodeJS.Timeout>();
-
-const PLAYER_BUTTON_IDS = {
-  TOGGLE: 'music:toggle',
-  SKIP: 'music:skip',
-  STOP: 'music:stop',
-  REFRESH: 'music:refresh',
-} as const;
-
-function buildProgressBar(elapsed: number, total: number): string {
-  const barLength = 20;
-  const filledLength = Math.round((elapsed / total) * barLength);
-  const emptyLength = barLength - filledLength;
-  const bar =
-    '█'.repeat(Math.max(0, filledLength - 1)) +
-    '🔘' +
-    '░'.repeat(Math.max(0, emptyLength - 1));
-
-  const elapsedStr = YouTubeUtil.formatDuration(elapsed);
-  const totalStr = YouTubeUtil.formatDuration(total);
-
-  return `\`${elapsedStr}\` ${bar} \`${totalStr}\``;
-}
+// Set once a shutdown signal arrives; new commands are turned away from then on
+let shuttingDown = false;
+const idleTracker = new IdleTracker();
 
 function startPlayerUpdate(guildId: string): void {
   if (playerUpdateIntervals.has(guildId)) {
@@ -87,10 +119,7 @@ function startPlayerUpdate(guildId: string): void {
       const playerMessage = await channel.messages.fetch(messageId);
       const musicPlayer = getOrCreateMusicPlayer(guildId);
 
-      await playerMessage.edit({
-        embeds: [buildPlayerEmbed(guildId)],
-        components: buildPlayerControls(guildId, musicPlayer),
-      });
+      await playerMessage.edit(await buildPlayerMessage(guildId, musicPlayer, playerMessage.id));
     } catch (error) {
       console.error(`Error updating player for guild ${guildId}:`, error);
       clearInterval(interval);
@@ -140,139 +169,222 @@ async function cleanupOldPlayerMessages(channelId: string): Promise<void> {
   }
 }
 
+/** Members other than bots in the bot's voice channel. */
+function getListenerIds(guildId: string): string[] {
+  const channelId = queueManager.getQueue(guildId).voiceConnection?.joinConfig.channelId;
+  const channel = channelId ? client.channels.cache.get(channelId) : undefined;
+  if (!channel?.isVoiceBased()) return [];
+  return channel.members.filter((member) => !member.user.bot).map((member) => member.id);
+}
+
 function getOrCreateMusicPlayer(guildId: string): MusicPlayer {
   if (!musicPlayers.has(guildId)) {
-    const guildPlayer = new MusicPlayer();
+    const guildPlayer = createMusicPlayer(guildId);
+    const settings = services.guildSettings.get(guildId);
+    void guildPlayer.setLoudnessNormalization(settings.normalizeLoudness);
+    guildPlayer.setDataSaver(settings.dataSaver);
+    guildPlayer.setNextSongProvider(() => queueManager.getNextSong(guildId));
+    guildPlayer.setVolumeResolver((song) =>
+      chooseVolume(
+        services.guildSettings.get(guildId).volume,
+        services.userPreferences.get(song.addedBy).volume,
+        song.addedBy,
+        getListenerIds(guildId)
+      )
+    );
 
-    guildPlayer.getPlayer().on(AudioPlayerStatus.Idle, async () => {
-      const queue = queueManager.getQueue(guildId);
-      const nextSong = queueManager.dequeueNextSong(guildId);
+    guildPlayer.setAnnouncer((song) =>
+      services.guildSettings.get(guildId).ttsAnnouncements ? `Now playing: ${formatTrack(song)}` : undefined
+    );
 
-      if (nextSong && queue.voiceConnection) {
-        queueManager.setCurrentSong(guildId, nextSong);
-        queueManager.setPlaying(guildId, true);
-        queueManager.setSongStartTime(guildId, Date.now());
+    // Queue changes run one at a time per guild; see GuildActors
+    guildPlayer.onSongEnd(() => playbackActors.run(guildId, () => advanceQueue(guildId, guildPlayer)));
 
-        try {
-          await guildPlayer.playSong(queue.voiceConnection, nextSong);
-          startPlayerUpdate(guildId);
-        } catch (error) {
-          console.error('Error auto-playing next song:', error);
-          queueManager.setCurrentSong(guildId, undefined);
-          queueManager.setPlaying(guildId, false);
-        }
+    musicPlayers.set(guildId, guildPlayer);
+  }
 
-        return;
-      }
+  return musicPlayers.get(guildId)!;
+}
 
+/** Tops the queue up from the guild's running YouTube Mix, if any. Returns how many songs were added. */
+async function refillMix(guildId: string): Promise<number> {
+  try {
+    return await mixFeeds.refill(guildId, queueManager, services.guildSettings.get(guildId).blacklist);
+  } catch (error) {
+    console.error('Error refilling mix:', error);
+    return 0;
+  }
+}
+
+/** With autoplay on, something to play after `finished` now that the queue is empty. */
+async function findAutoplaySong(guildId: string, finished: Song): Promise<Song | undefined> {
+  if (!services.guildSettings.isFeatureEnabled(guildId, 'autoplay') || !client.user) return undefined;
+  try {
+    const { blacklist } = services.guildSettings.get(guildId);
+    return await pickAutoplaySong(finished, guildId, services.listeningStats.getGuildStats(guildId), blacklist, client.user.id);
+  } catch (error) {
+    console.error('Error picking an autoplay song:', error);
+    return undefined;
+  }
+}
+
+/** Starts whatever follows the song that just ended, or marks the queue finished. */
+async function advanceQueue(guildId: string, musicPlayer: MusicPlayer): Promise<void> {
+  // Stopping players during shutdown mustn't start the next song
+  if (shuttingDown) return;
+  const queue = queueManager.getQueue(guildId);
+  // Stopping clears the current song first, so only a song that ran out is set here
+  const finished = queue.currentSong;
+  if (finished) {
+    const elapsed = queueManager.getSongElapsedTime(guildId);
+    playbackEvents.emit('trackEnded', { guildId, song: finished, reason: 'finished', elapsed });
+  }
+  let nextSong = queueManager.takeSongAfter(guildId, finished);
+  // A running mix outranks autoplay; normally it's topped up before the queue gets this far
+  if (!nextSong && finished && queue.voiceConnection && (await refillMix(guildId)) > 0) {
+    nextSong = queueManager.dequeueNextSong(guildId);
+  }
+  if (!nextSong && finished && queue.voiceConnection) {
+    nextSong = await findAutoplaySong(guildId, finished);
+  }
+
+  if (nextSong && queue.voiceConnection) {
+    queueManager.setCurrentSong(guildId, nextSong);
+    queueManager.setPlaying(guildId, true);
+    queueManager.setSongStartTime(guildId, Date.now());
+
+    try {
+      await musicPlayer.playSong(queue.voiceConnection, nextSong);
+      startPlayerUpdate(guildId);
+    } catch (error) {
+      console.error('Error auto-playing next song:', error);
       queueManager.setCurrentSong(guildId, undefined);
       queueManager.setPlaying(guildId, false);
-      stopPlayerUpdate(guildId);
-    });
+      return;
+    }
 
-    musicPlayers.set(guildId, guildPlayer);
+    playbackEvents.emit('trackStarted', { guildId, song: nextSong, automatic: true });
+    return;
   }
 
-  return musicPlayers.get(guildId)!;
+  queueManager.setCurrentSong(guildId, undefined);
+  queueManager.setPlaying(guildId, false);
+  stopPlayerUpdate(guildId);
+  if (finished) {
+    playbackEvents.emit('queueEmpty', { guildId });
+  }
 }
 
-function buildPlayerEmbed(guildId: string): EmbedBuilder {
-  const currentSong = queueManager.getCurrentSong(guildId);
-  const upcomingSongs = queueManager.getAllSongs(guildId);
-  const elapsed = queueManager.getSongElapsedTime(guildId);
-
-  const embed = new EmbedBuilder()
-    .setColor('#1DB954')
-    .setTitle('🎵 Now Playing')
-    .setThumbnail(currentSong?.thumbnail || 'https://via.placeholder.com/160x160?text=No+Song');
-
-  if (currentSong) {
-    embed.setDescription(`**${currentSong.title}**`);
-    
-    // Add progress bar
-    const progressBar = buildProgressBar(elapsed, currentSong.duration);
-    embed.addFields({
-      name: '📊 Progress',
-      value: progressBar,
-      inline: false,
-    });
+// Bot notices are side effects of playback, so they subscribe rather than live in the playback code
+playbackEvents.on('trackStarted', async ({ guildId, song, automatic }) => {
+  // Commands reply about the songs they start, and the sticky player shows the new song itself
+  const { announceNowPlaying, stickyPlayer } = services.guildSettings.get(guildId);
+  if (automatic && announceNowPlaying && !stickyPlayer) {
+    await announce(guildId, 'announce.nowPlaying', { track: formatTrack(song) });
+  }
+  // Songs started by a command were just asked for, so nobody needs telling
+  if (automatic) {
+    notifyRequester(guildId, song).catch((error) => console.error('Error notifying requester:', error));
+  }
+});
 
-    embed.addFields(
-      {
-        name: '⏱️ Duration',
-        value: YouTubeUtil.formatDuration(currentSong.duration),
-        inline: true,
-      },
-      {
-        name: '📋 Queue Size',
-        value: `${upcomingSongs.length} song${upcomingSongs.length !== 1 ? 's' : ''}`,
-        inline: true,
-      }
-    );
+playbackEvents.on('trackStarted', ({ guildId, song }) => {
+  clearTimeout(liveLimitTimers.get(guildId));
+  checkLiveLimit(guildId, song);
+  if (song.source.kind === 'radio') {
+    icyWatcher.watch(guildId, song, () => queueManager.getCurrentSong(guildId) === song);
   } else {
-    embed.setDescription('No song is currently playing.');
+    icyWatcher.stop(guildId);
   }
+  void refillMix(guildId);
+  services.listeningStats.recordPlay(guildId, song);
+  // Votes to skip were about the song that just ended
+  services.votes.reset(guildId, 'skip');
+});
+playbackEvents.on('trackEnded', ({ guildId, elapsed }) =>
+  services.listeningStats.recordListening(guildId, elapsed, getListenerIds(guildId))
+);
+
+playbackEvents.on('queueEmpty', ({ guildId }) => announce(guildId, 'announce.queueFinished'));
+
+playbackEvents.on('voiceDisconnected', ({ guildId }) => {
+  stopPlayerUpdate(guildId);
+  idleTracker.reset(guildId);
+  services.votes.reset(guildId);
+  mixFeeds.stop(guildId);
+  clearTimeout(liveLimitTimers.get(guildId));
+  liveLimitTimers.delete(guildId);
+  icyWatcher.stop(guildId);
+});
 
-  if (upcomingSongs.length > 0) {
-    const upcomingPreview = upcomingSongs
-      .slice(0, 5)
-      .map((song, index) => `${index + 1}. [${song.title}](${song.url})`)
-      .join('\n');
-
-    const footerText =
-      upcomingSongs.length > 5 ? `... and ${upcomingSongs.length - 5} more songs` : 'End of queue';
+// One per guild, while a live stream plays under /settings limits live_minutes
+const liveLimitTimers = new Map<string, NodeJS.Timeout>();
 
-    embed.addFields({
-      name: '📋 Up Next',
-      value: upcomingPreview || 'No upcoming songs',
-      inline: false,
-    });
+/** Moves the queue on once the live stream `song` has played for the guild's limit, or checks again when that's still to come. */
+function checkLiveLimit(guildId: string, song: Song): void {
+  liveLimitTimers.delete(guildId);
+  if (!song.isLive || queueManager.getCurrentSong(guildId) !== song) return;
+  const { maxLiveMinutes } = services.guildSettings.get(guildId);
+  if (maxLiveMinutes <= 0) return;
 
-    embed.setFooter({ text: footerText });
-  } else {
-    embed.setFooter({ text: 'Queue will end after current song' });
+  const remainingSeconds = maxLiveMinutes * 60 - queueManager.getSongElapsedTime(guildId);
+  if (remainingSeconds > 0) {
+    liveLimitTimers.set(guildId, setTimeout(() => checkLiveLimit(guildId, song), remainingSeconds * 1000));
+    return;
   }
 
-  return embed;
+  void playbackActors.run(guildId, async () => {
+    if (queueManager.getCurrentSong(guildId) !== song) return;
+    await announce(guildId, 'announce.liveLimit', { track: formatTrack(song), minutes: maxLiveMinutes });
+    // Ends the stream like a song that finished, so loops, mixes and autoplay carry on as usual
+    musicPlayers.get(guildId)?.stop();
+  });
 }
 
-function buildPlayerControls(guildId: string, musicPlayer: MusicPlayer) {
-  const hasCurrentSong = Boolean(queueManager.getCurrentSong(guildId));
-  const isPlayingNow = musicPlayer.isPlaying();
-
-  const controlRow = new ActionRowBuilder<ButtonBuilder>().addComponents(
-    new ButtonBuilder()
-      .setCustomId(PLAYER_BUTTON_IDS.TOGGLE)
-      .setEmoji(isPlayingNow ? '⏸️' : '▶️')
-      .setStyle(ButtonStyle.Success)
-      .setDisabled(!hasCurrentSong),
-    new ButtonBuilder()
-      .setCustomId(PLAYER_BUTTON_IDS.SKIP)
-      .setEmoji('⏭️')
-      .setStyle(ButtonStyle.Secondary)
-      .setDisabled(!hasCurrentSong),
-    new ButtonBuilder()
-      .setCustomId(PLAYER_BUTTON_IDS.STOP)
-      .setEmoji('⏹️')
-      .setStyle(ButtonStyle.Danger)
-      .setDisabled(!hasCurrentSong)
+const PLAYER_PREVIEW_SIZE = 5;
+
+// Thumbnail source each player message was last edited with, keyed by message ID
+const attachedThumbnails = new TtlCache<string>(60 * 60 * 1000, 500);
+
+/**
+ * Renders the player. Pass the ID of the message being edited so a thumbnail
+ * it already has attached isn't uploaded again on every refresh.
+ */
+async function buildPlayerMessage(guildId: string, musicPlayer: MusicPlayer, messageId?: string) {
+  const settings = services.guildSettings.get(guildId);
+  const renderer = getRenderer(settings.renderer);
+  const t = services.guildSettings.translator(guildId);
+  const { thumbnailSource, ...rendered } = await renderer.renderNowPlaying(
+    buildPlayerView(queueManager, guildId, PLAYER_PREVIEW_SIZE, t, settings.theme, musicPlayer.getFilters())
   );
+  const components = buildPlayerControls(queueManager, guildId, musicPlayer, t);
 
-  const infoRow = new ActionRowBuilder<ButtonBuilder>().addComponents(
-    new ButtonBuilder()
-      .setCustomId(PLAYER_BUTTON_IDS.REFRESH)
-      .setLabel('Queue')
-      .setEmoji('📋')
-      .setStyle(ButtonStyle.Secondary)
-  );
+  if (messageId) {
+    const unchanged = thumbnailSource !== undefined && attachedThumbnails.get(messageId) === thumbnailSource;
+    attachedThumbnails.set(messageId, thumbnailSource ?? '');
+    // Leaving `files` and `attachments` out keeps the message's current attachments
+    if (unchanged) return { content: rendered.content, embeds: rendered.embeds, components };
+  }
 
-  return [controlRow, infoRow];
+  return {
+    ...rendered,
+    components,
+    // Drop the previous render's attachments
+    attachments: [],
+  };
 }
 
 // Load commands
 interface Command {
   data: any;
-  execute: (interaction: any, queueManager: QueueManager, musicPlayer: MusicPlayer, startPlayerUpdate: (guildId: string) => void, cleanupOldMessages: (channelId: string) => Promise<void>) => Promise<void>;
+  execute: (interaction: any, queueManager: QueueManager, musicPlayer: MusicPlayer, startPlayerUpdate: (guildId: string) => void, cleanupOldMessages: (channelId: string) => Promise<void>, services: BotServices) => Promise<void>;
+  autocomplete?: (interaction: AutocompleteInteraction, services: BotServices) => Promise<void>;
+  /** Buttons and menus whose custom ID starts with `<command name>:`, such as /help's pages. */
+  handleComponent?: (interaction: MessageComponentInteraction, services: BotServices) => Promise<void>;
+  /** Submitted forms whose custom ID starts with `<command name>:`, such as /playlist create's. */
+  handleModal?: (interaction: ModalSubmitInteraction, services: BotServices) => Promise<void>;
+  /** Experimental commands name the feature flag that must be enabled for the guild. */
+  feature?: FeatureFlag;
 }
 
 const commands = new Collection<string, Command>();
@@ -287,10 +399,59 @@ for (const file of commandFiles) {
     console.log(`✅ Loaded command: ${command.data.name}`);
   }
 }
+setCommands(
+  commands.map((command) => {
+    const json = command.data.toJSON();
+    return {
+      name: json.name,
+      type: json.type ?? ApplicationCommandType.ChatInput,
+      description: json.description ?? '',
+      options: json.options ?? [],
+      feature: command.feature,
+    };
+  })
+);
 
 // Bot is ready
 client.once('clientReady', async () => {
   console.log(`✅ Logged in as ${client.user?.tag}`);
+  // Clustered, the process with shard 0 does the once-per-bot work; see cluster.ts
+  const isFirstProcess = !client.shard || client.shard.ids.includes(0);
+  // The others find the managed binary once it's in place
+  if (isFirstProcess) {
+    startManagedYtDlp();
+  }
+  const orphanedDownloads = tempFiles.sweepOrphans();
+  if (orphanedDownloads > 0) {
+    console.log(`🧹 Removed ${orphanedDownloads} download(s) left over from a previous run`);
+  }
+  lavalink?.start(client.user!.id);
+  if (webServer && isFirstProcess) {
+    registerDashboardRoutes(webServer, {
+      queueManager,
+      guildSettings: services.guildSettings,
+      getMusicPlayer: getOrCreateMusicPlayer,
+      isGuildAdmin,
+    });
+    const apiContext = {
+      queueManager,
+      guildSettings: services.guildSettings,
+      rateLimiter: services.rateLimiter,
+      getMusicPlayer: getOrCreateMusicPlayer,
+    };
+    registerApiRoutes(webServer, apiContext);
+    registerPlaybackStream(webServer, apiContext);
+    webServer.start();
+  }
+  void resumeSavedPlayback();
+  setInterval(() => {
+    checkIdleGuilds().catch((error) => console.error('Error checking idle voice connections:', error));
+  }, IDLE_CHECK_INTERVAL_MS);
+  setInterval(() => {
+    refreshStickyPlayers().catch((error) => console.error('Error updating sticky players:', error));
+  }, STICKY_UPDATE_INTERVAL_MS);
+
+  if (!isFirstProcess) return;
 
   // Register slash commands
   const commandsData = commands.map((cmd) => cmd.data.toJSON());
@@ -326,36 +487,43 @@ client.on('interactionCreate', async (interaction) => {
     return;
   }
 
+  const t = services.guildSettings.translator(guildId);
+
+  if (shuttingDown) {
+    if (interaction.isAutocomplete()) {
+      await interaction.respond([]).catch(() => undefined);
+    } else if (interaction.isRepliable()) {
+      await interaction.reply({ content: t('guard.restarting'), ephemeral: true }).catch(() => undefined);
+    }
+    return;
+  }
+
   const musicPlayer = getOrCreateMusicPlayer(guildId);
 
   if (interaction.isButton() && interaction.customId.startsWith('music:')) {
     try {
       await interaction.deferUpdate();
       const currentSong = queueManager.getCurrentSong(guildId);
-      const { messageId, channelId } = queueManager.getPlayerMessage(guildId);
+      // Buttons update the message they're on: the player, or the sticky player
+      const playerMessage = interaction.message;
 
-      if (!messageId || !channelId) {
-        await interaction.followUp({ content: 'Player message not found.', ephemeral: true });
+      if (interaction.customId === PLAYER_BUTTON_IDS.REFRESH) {
+        await playerMessage.edit(await buildPlayerMessage(guildId, musicPlayer, playerMessage.id));
         return;
       }
 
-      const channel = await client.channels.fetch(channelId);
-      if (!channel || !channel.isTextBased()) {
+      if (!isInBotVoiceChannel(interaction)) {
+        await interaction.followUp({ content: t('guard.joinForControls'), ephemeral: true });
         return;
       }
 
-      const playerMessage = await channel.messages.fetch(messageId);
-
-      if (interaction.customId === PLAYER_BUTTON_IDS.REFRESH) {
-        await playerMessage.edit({
-          embeds: [buildPlayerEmbed(guildId)],
-          components: buildPlayerControls(guildId, musicPlayer),
-        });
+      if (DJ_ONLY_BUTTON_IDS.includes(interaction.customId) && !isDj(interaction, services.guildSettings.get(guildId))) {
+        await interaction.followUp({ content: t('guard.djButton'), ephemeral: true });
         return;
       }
 
       if (!currentSong) {
-        await interaction.followUp({ content: 'Nothing is currently playing.', ephemeral: true });
+        await interaction.followUp({ content: t('guard.nothingPlaying'), ephemeral: true });
         return;
       }
 
@@ -366,61 +534,127 @@ client.on('interactionCreate', async (interaction) => {
           musicPlayer.unpause();
         }
 
-        await playerMessage.edit({
-          embeds: [buildPlayerEmbed(guildId)],
-          components: buildPlayerControls(guildId, musicPlayer),
-        });
+        await playerMessage.edit(await buildPlayerMessage(guildId, musicPlayer, playerMessage.id));
         return;
       }
 
       if (interaction.customId === PLAYER_BUTTON_IDS.SKIP) {
-        const nextSong = queueManager.dequeueNextSong(guildId);
-        queueManager.setCurrentSong(guildId, undefined);
-
-        if (nextSong) {
-          const queue = queueManager.getQueue(guildId);
-          if (queue.voiceConnection) {
-            queueManager.setCurrentSong(guildId, nextSong);
-            queueManager.setPlaying(guildId, true);
-            queueManager.setSongStartTime(guildId, Date.now());
-            await musicPlayer.playSong(queue.voiceConnection, nextSong);
-            startPlayerUpdate(guildId);
+        if (!hasDjRole(interaction, services.guildSettings.get(guildId)) && !queueManager.isSoleRequester(guildId, interaction.user.id)) {
+          const vote = services.votes.castVote(guildId, 'skip', interaction.user.id, getListenerIds(guildId));
+          if (!vote.passed) {
+            await interaction.followUp({ content: describeVote(t('common.voteSkip'), vote, t), ephemeral: true });
+            return;
           }
-        } else {
-          musicPlayer.stop();
-          queueManager.setPlaying(guildId, false);
+        }
+
+        services.votes.reset(guildId, 'skip');
+        if (await skipSong(guildId, queueManager, musicPlayer, currentSong)) {
+          startPlayerUpdate(guildId);
+        } else if (!queueManager.getCurrentSong(guildId)) {
           stopPlayerUpdate(guildId);
         }
 
-        await playerMessage.edit({
-          embeds: [buildPlayerEmbed(guildId)],
-          components: buildPlayerControls(guildId, musicPlayer),
-        });
+        await playerMessage.edit(await buildPlayerMessage(guildId, musicPlayer, playerMessage.id));
         return;
       }
 
       if (interaction.customId === PLAYER_BUTTON_IDS.STOP) {
-        const queue = queueManager.getQueue(guildId);
-        musicPlayer.stop();
-        queue.voiceConnection?.destroy();
-        queueManager.clearQueue(guildId);
-        stopPlayerUpdate(guildId);
-
-        await playerMessage.edit({
-          embeds: [buildPlayerEmbed(guildId)],
-          components: buildPlayerControls(guildId, musicPlayer),
+        if (!hasDjRole(interaction, services.guildSettings.get(guildId))) {
+          const vote = services.votes.castVote(guildId, 'stop', interaction.user.id, getListenerIds(guildId));
+          if (!vote.passed) {
+            await interaction.followUp({ content: describeVote(t('common.voteStop'), vote, t), ephemeral: true });
+            return;
+          }
+        }
+
+        services.votes.reset(guildId);
+        const usage = await playbackActors.run(guildId, () => {
+          const connection = queueManager.getQueue(guildId).voiceConnection;
+          queueManager.clearQueue(guildId);
+          musicPlayer.stop();
+          connection?.destroy();
+          return musicPlayer.resetBandwidthUsage();
         });
+
+        await playerMessage.edit(await buildPlayerMessage(guildId, musicPlayer, playerMessage.id));
+        await interaction.followUp({ content: t('guard.sessionEnded', { bandwidth: describeBandwidth(usage, t) }), ephemeral: true });
+        return;
+      }
+
+      if (interaction.customId === PLAYER_BUTTON_IDS.SHUFFLE) {
+        if (queueManager.getQueueSize(guildId) < 2) {
+          await interaction.followUp({ content: t('common.needTwoToShuffle'), ephemeral: true });
+          return;
+        }
+
+        queueManager.shuffleQueue(guildId);
+        await playerMessage.edit(await buildPlayerMessage(guildId, musicPlayer, playerMessage.id));
+        return;
+      }
+
+      if (interaction.customId === PLAYER_BUTTON_IDS.LOOP) {
+        const loopMode = NEXT_LOOP_MODE[queueManager.getLoopMode(guildId)];
+        queueManager.setLoopMode(guildId, loopMode);
+
+        await playerMessage.edit(await buildPlayerMessage(guildId, musicPlayer, playerMessage.id));
+        await interaction.followUp({ content: `🔁 ${t(LOOP_MODE_LABELS[loopMode])}`, ephemeral: true });
       }
     } catch (error) {
       console.error('Error handling music button:', error);
       if (!interaction.replied && !interaction.deferred) {
-        await interaction.reply({ content: 'Failed to handle player action.', ephemeral: true });
+        await interaction.reply({ content: t('guard.buttonFailed'), ephemeral: true });
       }
     }
     return;
   }
 
-  if (!interaction.isChatInputCommand()) return;
+  if (interaction.isMessageComponent() || interaction.isModalSubmit()) {
+    const command = commands.get(interaction.customId.split(':')[0]);
+
+    try {
+      if (interaction.isModalSubmit()) {
+        await command?.handleModal?.(interaction, services);
+      } else {
+        await command?.handleComponent?.(interaction, services);
+      }
+    } catch (error) {
+      logError(`Error handling ${interaction.customId}`, error);
+      if (!interaction.replied && !interaction.deferred) {
+        await interaction.reply({ content: describeError(error, t), ephemeral: true });
+      }
+    }
+    return;
+  }
+
+  if (interaction.isAutocomplete()) {
+    const command = commands.get(interaction.commandName);
+    if (!command?.autocomplete) return;
+
+    try {
+      await command.autocomplete(interaction, services);
+    } catch (error) {
+      console.error(`Error autocompleting ${interaction.commandName}:`, error);
+      if (!interaction.responded) {
+        await interaction.respond([]);
+      }
+    }
+    return;
+  }
+
+  if (!interaction.isChatInputCommand() && !interaction.isMessageContextMenuCommand()) return;
+
+  // Admins are exempt so they can always reach /settings, even after removing every allowed channel
+  const { allowedChannelIds } = services.guildSettings.get(guildId);
+  const commandChannelId = interaction.channel?.isThread() ? interaction.channel.parentId : interaction.channelId;
+  if (allowedChannelIds.length > 0 && !isAdmin(interaction) && !allowedChannelIds.includes(commandChannelId ?? '')) {
+    await interaction.reply({
+      content: t('guard.commandChannels', { channels: allowedChannelIds.map((channelId) => `<#${channelId}>`).join(', ') }),
+      ephemeral: true,
+    });
+    return;
+  }
+
+  queueManager.setLastCommandChannel(guildId, interaction.channelId);
 
   const command = commands.get(interaction.commandName);
   if (!command) {
@@ -428,47 +662,405 @@ client.on('interactionCreate', async (interaction) => {
     return;
   }
 
+  if (services.guildSettings.get(guildId).disabledCommands.includes(interaction.commandName)) {
+    await interaction.reply({ content: t('guard.commandDisabled', { command: interaction.commandName }), ephemeral: true });
+    return;
+  }
+
+  if (command.feature && !services.guildSettings.isFeatureEnabled(guildId, command.feature)) {
+    await interaction.reply({
+      content: t('guard.featureDisabled', { command: interaction.commandName }),
+      ephemeral: true,
+    });
+    return;
+  }
+
   try {
-    await command.execute(interaction, queueManager, musicPlayer, startPlayerUpdate, cleanupOldPlayerMessages);
+    await command.execute(interaction, queueManager, musicPlayer, startPlayerUpdate, cleanupOldPlayerMessages, services);
   } catch (error) {
-    console.error(`Error executing ${interaction.commandName}:`, error);
-    if (!interaction.replied) {
+    logError(`Error executing ${interaction.commandName}`, error);
+    if (interaction.deferred && !interaction.replied) {
+      await interaction.editReply(describeError(error, t));
+    } else if (!interaction.replied) {
       await interaction.reply({
-        content: '❌ There was an error while executing this command!',
+        content: describeError(error, t),
         ephemeral: true,
       });
     }
   }
 });
 
+/** Where the bot posts on its own: the bound announcement channel, else the player's or last command's channel. */
+function getAnnounceChannelId(guildId: string): string | undefined {
+  return services.guildSettings.get(guildId).announceChannelId ?? queueManager.getAnnouncementChannelId(guildId);
+}
+
+/** Posts a message from the catalog, in the guild's language. */
+async function announce(guildId: string, key: MessageKey, vars?: MessageVars): Promise<void> {
+  const channelId = getAnnounceChannelId(guildId);
+  if (!channelId) return;
+  const content = services.guildSettings.translator(guildId)(key, vars);
+
+  try {
+    const channel = await client.channels.fetch(channelId);
+    if (channel?.isTextBased() && 'send' in channel) {
+      await channel.send(content);
+    }
+  } catch (error) {
+    console.error(`Error announcing in guild ${guildId}:`, error);
+  }
+}
+
+// Each song's requester is told once, even when a loop plays it again
+const notifiedSongs: WeakSet<Song> = new WeakSet();
+
+/** Lets whoever requested `song` know it started, if they asked to be told with /notifyme. */
+async function notifyRequester(guildId: string, song: Song): Promise<void> {
+  const { startNotification } = services.userPreferences.get(song.addedBy);
+  if (!startNotification || notifiedSongs.has(song)) return;
+  notifiedSongs.add(song);
+
+  if (startNotification === 'dm') {
+    const t = services.guildSettings.translator(guildId);
+    const user = await client.users.fetch(song.addedBy);
+    await user.send(t('announce.requesterDm', { track: formatTrack(song), server: client.guilds.cache.get(guildId)?.name ?? '' }));
+    return;
+  }
+
+  // A ghost ping: the mention notifies them, and deleting it keeps the channel clean
+  const channelId = getAnnounceChannelId(guildId);
+  const channel = channelId ? await client.channels.fetch(channelId) : null;
+  if (channel?.isTextBased() && 'send' in channel) {
+    const ping = await channel.send(`<@${song.addedBy}>`);
+    await ping.delete();
+  }
+}
+
+// The sticky player lives for the whole session, so it's edited less often than the player
+const STICKY_UPDATE_INTERVAL_MS = 10_000;
+
+/** Edits the sticky player, or posts and pins a new one if there's none or it was deleted. */
+async function updateStickyPlayer(guildId: string, musicPlayer: MusicPlayer): Promise<void> {
+  const queue = queueManager.getQueue(guildId);
+
+  if (queue.stickyMessageId && queue.stickyChannelId) {
+    const channel = await client.channels.fetch(queue.stickyChannelId).catch(() => null);
+    const existing = channel?.isTextBased() ? await channel.messages.fetch(queue.stickyMessageId).catch(() => null) : null;
+    if (existing) {
+      await existing.edit(await buildPlayerMessage(guildId, musicPlayer, existing.id));
+      return;
+    }
+  }
+
+  const channelId = getAnnounceChannelId(guildId);
+  const channel = channelId ? await client.channels.fetch(channelId) : null;
+  if (!channel?.isTextBased() || !('send' in channel)) return;
+
+  const posted = await channel.send(await buildPlayerMessage(guildId, musicPlayer));
+  queue.stickyChannelId = channel.id;
+  queue.stickyMessageId = posted.id;
+  await posted.pin().catch((error) => console.error(`Couldn't pin the sticky player in guild ${guildId}:`, error.message));
+}
+
+/** Keeps one now-playing message per sticky-player guild current, instead of a message per song. */
+async function refreshStickyPlayers(): Promise<void> {
+  for (const [guildId, musicPlayer] of musicPlayers) {
+    if (!services.guildSettings.get(guildId).stickyPlayer) continue;
+
+    const queue = queueManager.getQueue(guildId);
+    const playing = queue.currentSong !== undefined;
+    // Once it shows that nothing's playing, there's nothing to update until a song starts
+    if (!playing && (!queue.stickyMessageId || queue.stickyIdle)) continue;
+
+    try {
+      await updateStickyPlayer(guildId, musicPlayer);
+      queue.stickyIdle = !playing;
+    } catch (error) {
+      console.error(`Error updating sticky player for guild ${guildId}:`, error);
+    }
+  }
+}
+
+/** Whether the user can manage the guild, for requests that don't come through Discord. */
+async function isGuildAdmin(guildId: string, userId: string): Promise<boolean> {
+  const guild = client.guilds.cache.get(guildId);
+  const member = await guild?.members.fetch(userId).catch(() => undefined);
+  return Boolean(member?.permissions.has(PermissionFlagsBits.ManageGuild));
+}
+
+/** Stops playback, leaves voice and clears the queue, like /leave. */
+function leaveVoice(guildId: string): Promise<void> {
+  return playbackActors.run(guildId, () => {
+    const musicPlayer = musicPlayers.get(guildId);
+    const connection = queueManager.getQueue(guildId).voiceConnection;
+    queueManager.clearQueue(guildId);
+    musicPlayer?.stop();
+    musicPlayer?.resetBandwidthUsage();
+    connection?.destroy();
+  });
+}
+
+/** Leaves voice in guilds that have had nothing playing for longer than their idle timeout. */
+async function checkIdleGuilds(): Promise<void> {
+  for (const [guildId, musicPlayer] of musicPlayers) {
+    const connection = queueManager.getQueue(guildId).voiceConnection;
+    const connected = connection !== undefined && connection.state.status !== VoiceConnectionStatus.Destroyed;
+    const idle = connected && (!queueManager.getCurrentSong(guildId) || !musicPlayer.isPlaying());
+    const minutes = services.guildSettings.get(guildId).idleTimeoutMinutes;
+
+    const action = idleTracker.update(guildId, idle, minutes * 60_000);
+    if (action === 'warn') {
+      await announce(guildId, 'announce.idleWarning');
+    } else if (action === 'leave') {
+      await announce(guildId, 'announce.idleLeft', { minutes });
+      await leaveVoice(guildId);
+    }
+  }
+}
+
+/** Someone dragged the bot to another channel: carry on there with the same queue. */
+async function handOverToChannel(guildId: string, channelId: string): Promise<void> {
+  const queue = queueManager.getQueue(guildId);
+  const connection = queue.voiceConnection;
+  if (queue.selfMoveChannelId === channelId) {
+    queue.selfMoveChannelId = undefined;
+    return;
+  }
+  if (!connection) return;
+
+  try {
+    // The connection follows the move itself; audio resumes once it's ready again
+    await entersState(connection, VoiceConnectionStatus.Ready, 20_000);
+  } catch (error) {
+    console.error(`Voice connection didn't recover after a move in guild ${guildId}:`, error);
+    await announce(guildId, 'announce.moveFailed', { channel: `<#${channelId}>` });
+    return;
+  }
+
+  if (queue.currentSong) {
+    await announce(guildId, 'announce.moved', { channel: `<#${channelId}>` });
+  }
+}
+
+/** Applies the guild's policy when the bot itself is server-muted or unmuted, and hands over on moves. */
+async function handleOwnVoiceStateChange(oldState: VoiceState, newState: VoiceState): Promise<void> {
+  const guildId = newState.guild.id;
+  const queue = queueManager.getQueue(guildId);
+  if (!newState.channelId) return;
+
+  if (oldState.channelId && oldState.channelId !== newState.channelId) {
+    await handOverToChannel(guildId, newState.channelId);
+  }
+  if (!queue.currentSong) return;
+
+  const wasMuted = Boolean(oldState.serverMute);
+  const isMuted = Boolean(newState.serverMute);
+  const musicPlayer = getOrCreateMusicPlayer(guildId);
+
+  if (wasMuted && !isMuted) {
+    if (queue.pausedByMute) {
+      queue.pausedByMute = false;
+      musicPlayer.unpause();
+      await announce(guildId, 'announce.unmuted');
+    }
+    return;
+  }
+
+  if (!isMuted || wasMuted) return;
+
+  const policy = services.guildSettings.get(guildId).voiceInterruptionPolicy;
+
+  switch (policy) {
+    case 'pause':
+      musicPlayer.pause();
+      queue.pausedByMute = true;
+      await announce(guildId, 'announce.mutedPaused');
+      return;
+    case 'announce':
+      await announce(guildId, 'announce.mutedPlaying');
+      return;
+    case 'disconnect':
+      await leaveVoice(guildId);
+      await announce(guildId, 'announce.mutedLeft');
+      return;
+  }
+}
+
+/** Plays a member's intro over the music when they join the bot's channel, if the guild allows it. */
+async function playJoinIntro(guildId: string, userId: string): Promise<void> {
+  if (!services.guildSettings.get(guildId).joinIntros) return;
+  const clipPath = services.intros.getClipPath(userId);
+  if (!clipPath || !services.intros.claim(guildId, userId)) return;
+
+  const musicPlayer = getOrCreateMusicPlayer(guildId);
+  await playbackActors.run(guildId, () => mixInClip(guildId, queueManager, musicPlayer, clipPath));
+}
+
+/** The followed member moved from the bot's channel to `channelId`: go with them, queue and all. */
+async function followMember(guildId: string, userId: string, channelId: string): Promise<void> {
+  const channel = await client.channels.fetch(channelId).catch(() => null);
+  if (!channel?.isVoiceBased()) return;
+
+  const musicPlayer = getOrCreateMusicPlayer(guildId);
+  try {
+    await playbackActors.run(guildId, () => connectToChannel(channel, queueManager, musicPlayer));
+  } catch (error) {
+    console.error(`Couldn't follow ${userId} to ${channelId} in guild ${guildId}:`, error);
+    await announce(guildId, 'announce.followFailed', { user: `<@${userId}>`, channel: `<#${channelId}>` });
+    return;
+  }
+  await announce(guildId, 'announce.followed', { user: `<@${userId}>`, channel: `<#${channelId}>` });
+}
+
+// Plain messages in a server's song-request channel queue songs
+client.on('messageCreate', (message) => {
+  if (shuttingDown) return;
+  handleRequestMessage(message, queueManager, getOrCreateMusicPlayer, startPlayerUpdate, services).catch((error) =>
+    console.error('Error handling song request message:', error)
+  );
+});
+
 // Handle voice state changes
 client.on('voiceStateUpdate', (oldState, newState) => {
+  if (newState.id === client.user?.id) {
+    handleOwnVoiceStateChange(oldState, newState).catch((error) =>
+      console.error('Error handling own voice state change:', error)
+    );
+    // Being moved, even into an empty channel, is a handover rather than a reason to leave
+    return;
+  }
+
+  const channel = newState.guild.members.me?.voice.channel;
+  const followed = services.guildSettings.get(newState.guild.id).followUserId === newState.id;
+  if (followed && channel && oldState.channelId === channel.id && newState.channelId && newState.channelId !== channel.id) {
+    followMember(newState.guild.id, newState.id, newState.channelId).catch((error) =>
+      console.error('Error following member:', error)
+    );
+    // The channel they left may be empty now, but the bot is on its way out of it anyway
+    return;
+  }
+
+  if (channel && newState.channelId === channel.id && oldState.channelId !== channel.id && !newState.member?.user.bot) {
+    playJoinIntro(newState.guild.id, newState.id).catch((error) => console.error('Error playing join intro:', error));
+  }
+
   // Leave voice channel if bot is alone
-  if (newState.guild.members.me?.voice.channel) {
-    const channel = newState.guild.members.me.voice.channel;
-    const members = channel.members.filter((m) => !m.user.bot);
-
-    if (members.size === 0) {
-      const connection = newState.guild.voiceAdapterCreator as any;
-      if (connection) {
-        connection.disconnect();
-        queueManager.clearQueue(newState.guild.id);
-      }
-    }
+  if (channel && channel.members.filter((m) => !m.user.bot).size === 0) {
+    void leaveVoice(newState.guild.id);
   }
 });
 
-// Handle player end event for auto-skip
+// With Lavalink, the node needs the voice credentials Discord sends the bot
 client.on('raw', async (event) => {
   if (event.t !== 'VOICE_SERVER_UPDATE' && event.t !== 'VOICE_STATE_UPDATE') return;
+  lavalink?.handleRawPacket(event);
 });
 
 // Login to Discord
 client.login(TOKEN);
 
-// Graceful shutdown
-process.on('SIGINT', () => {
-  console.log('\n👋 Shutting down gracefully...');
-  client.destroy();
+/** Rejoins a guild's voice channel and carries on with the queue and song it had at shutdown. */
+async function resumeGuild(guildId: string, saved: SavedPlayback): Promise<void> {
+  const channel = await client.channels.fetch(saved.voiceChannelId).catch(() => null);
+  if (!channel?.isVoiceBased() || channel.guildId !== guildId) {
+    console.log(`[RESUME] Voice channel for guild ${guildId} is gone, not resuming`);
+    return;
+  }
+
+  const musicPlayer = getOrCreateMusicPlayer(guildId);
+  if (saved.textChannelId) {
+    queueManager.setLastCommandChannel(guildId, saved.textChannelId);
+  }
+  // The song that was playing keeps its ID, so the requeued songs' new ones go after it
+  queueManager.claimQueueId(guildId, saved.currentSong?.queueId);
+  queueManager.addSongs(guildId, saved.songs);
+
+  const song = saved.currentSong ?? queueManager.dequeueNextSong(guildId);
+  if (!song) return;
+  const position = saved.currentSong ? saved.position : 0;
+
+  try {
+    await playbackActors.run(guildId, async () => {
+      const connection = await connectToChannel(channel, queueManager, musicPlayer);
+      queueManager.setCurrentSong(guildId, song);
+      queueManager.setPlaying(guildId, true);
+      await musicPlayer.playSong(connection, song, position);
+      queueManager.setSongPosition(guildId, position);
+      playbackEvents.emit('trackStarted', { guildId, song, automatic: false });
+    });
+  } catch (error) {
+    console.error(`[RESUME] Failed to resume playback in guild ${guildId}:`, error);
+    queueManager.setCurrentSong(guildId, undefined, false);
+    queueManager.setPlaying(guildId, false);
+    queueManager.addSongToFront(guildId, song);
+    await announce(guildId, 'announce.resumeFailed');
+    return;
+  }
+
+  console.log(`[RESUME] Resumed ${song.title} in guild ${guildId} at ${position}s`);
+  await announce(guildId, 'announce.resumed', { track: formatTrack(song) });
+}
+
+async function resumeSavedPlayback(): Promise<void> {
+  // Clustered, each process resumes the guilds on its own shards; alone, it takes everything
+  const state = takePlaybackState(storage, (guildId) => !client.shard || client.guilds.cache.has(guildId));
+  await Promise.all(Object.entries(state).map(([guildId, saved]) => resumeGuild(guildId, saved)));
+}
+
+// Give up on announcements and cleanup that take longer than this
+const SHUTDOWN_TIMEOUT_MS = 10_000;
+
+/** Snapshot of every guild that's playing, so the next start can pick up where this one stopped. */
+function capturePlaybackState(): PlaybackStateDocument {
+  const state: PlaybackStateDocument = {};
+  for (const guildId of musicPlayers.keys()) {
+    const queue = queueManager.getQueue(guildId);
+    const voiceChannelId = queue.voiceConnection?.joinConfig.channelId;
+    if (!voiceChannelId || !queue.currentSong) continue;
+
+    state[guildId] = {
+      voiceChannelId,
+      textChannelId: getAnnounceChannelId(guildId),
+      currentSong: queue.currentSong,
+      position: queueManager.getSongElapsedTime(guildId),
+      songs: queueManager.getFullQueue(guildId),
+      savedAt: Date.now(),
+    };
+  }
+  return state;
+}
+
+async function shutdown(signal: string): Promise<void> {
+  if (shuttingDown) return;
+  shuttingDown = true;
+  console.log(`\n👋 ${signal} received, shutting down gracefully...`);
+  setTimeout(() => {
+    console.error('Shutdown took too long, exiting anyway');
+    process.exit(1);
+  }, SHUTDOWN_TIMEOUT_MS).unref();
+
+  const state = capturePlaybackState();
+  try {
+    savePlaybackState(storage, state);
+  } catch (error) {
+    console.error('Error saving playback state:', error);
+  }
+
+  await Promise.all(
+    Object.keys(state).map((guildId) => announce(guildId, 'announce.restarting'))
+  );
+
+  for (const [guildId, musicPlayer] of musicPlayers) {
+    stopPlayerUpdate(guildId);
+    musicPlayer.stop();
+    queueManager.getQueue(guildId).voiceConnection?.destroy();
+  }
+
+  await webServer?.stop();
+  storage.flush();
+  await client.destroy();
   process.exit(0);
-});
+}
+
+process.on('SIGINT', () => void shutdown('SIGINT'));
+process.on('SIGTERM', () => void shutdown('SIGTERM'));
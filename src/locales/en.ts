@@ -0,0 +1,555 @@
+/**
+ * English messages, and the list of keys every other locale must translate.
+ * `{name}` marks a value filled in when the message is sent.
+ */
+export const en = {
+  // Shared replies
+  'common.nothingPlaying': '❌ Nothing is currently playing',
+  'common.nothingPaused': '❌ Nothing is paused',
+  'common.queueEmpty': '🎵 Queue is empty',
+  'common.notInVoice': '❌ Bot is not in a voice channel',
+  'common.joinVoiceFirst': '❌ You must be in a voice channel to use this command!',
+  'common.noSongMatches': '❌ No queued song matches `{reference}`. Use a position between 1 and {size} or an ID from /queue',
+  'common.needTwoToShuffle': '❌ There need to be at least 2 songs in the queue to shuffle',
+  'common.dataUsed': '📶 Data Used This Session',
+  'common.changedBy': 'Changed by {user}',
+  'common.requestedBy': 'Requested by {user}',
+  'common.addedBy': 'Added by {user}',
+  'common.album': 'Album',
+  'common.duration': '⏱️ Duration',
+  'common.queueSize': '📍 Queue Size',
+  'common.bandwidth': '📥 ~{downloaded} downloaded · 📤 {sent} sent to Discord',
+  'common.vote': '🗳️ Vote to {action}: {votes}/{needed}. Others in the voice channel can vote with the same command',
+  'common.voteStop': 'stop the music and clear the queue',
+  'common.voteLeave': 'make me leave and clear the queue',
+  'common.voteClear': 'clear the queue',
+  'common.voteSkip': 'skip this song',
+
+  // Player message and queue; their emoji come from the guild's theme
+  'player.nowPlaying': 'Now Playing',
+  'player.queueTitle': 'Music Queue',
+  'player.queueTitleCount': 'Music Queue ({count})',
+  'player.compactQueueTitle': '**Queue ({count})**',
+  'player.chapter': 'Chapter',
+  'player.progress': 'Progress',
+  'player.duration': 'Duration',
+  'player.live': '🔴 LIVE',
+  'player.onAir': 'On air: {title}',
+  'player.queueSizeLabel': 'Queue Size',
+  'player.songCountOne': '1 song',
+  'player.songCount': '{count} songs',
+  'player.filters': 'Filters',
+  'player.requestedBy': 'Requested by',
+  'player.requestedByUser': 'Requested by {user}',
+  'player.speed': '{value}x speed',
+  'player.pitch': '{value}x pitch',
+  'player.upNext': 'Up Next',
+  'player.upNextCount': 'Up Next ({count})',
+  'player.moreSongs': '... and {count} more songs',
+  'player.playsIn': 'plays in ~{time}',
+  'player.totalLength': 'Total queue length: {time}',
+  'player.endOfQueue': 'End of queue',
+  'player.lastSong': 'Queue will end after current song',
+  'player.noSong': 'No song is currently playing.',
+  'player.nothingPlaying': 'Nothing playing',
+  'player.queueButton': 'Queue',
+  'player.loopOff': 'Loop off',
+  'player.loopTrack': 'Looping this song',
+  'player.loopQueue': 'Looping the queue',
+
+  // Player buttons and command guards
+  'guard.restarting': '🔄 The bot is restarting, try again in a moment',
+  'guard.joinForControls': '❌ Join my voice channel to use the player controls',
+  'guard.djButton': '❌ Only DJs can use this button',
+  'guard.nothingPlaying': 'Nothing is currently playing.',
+  'guard.sessionEnded': '⏹️ Session ended · {bandwidth}',
+  'guard.buttonFailed': 'Failed to handle player action.',
+  'guard.commandChannels': '🎵 Music commands go in {channels}',
+  'guard.commandDisabled': '🚫 `/{command}` is turned off on this server',
+  'guard.featureDisabled': "🧪 `/{command}` is an experimental feature that isn't enabled on this server",
+  'guard.commandFailed': '❌ There was an error while executing this command!',
+  'guard.userRateLimited': "⏳ You're adding songs faster than I can look them up. Try again in {seconds}s",
+  'guard.guildRateLimited': '⏳ This server is looking up a lot of songs right now. Try again in {seconds}s',
+
+  // Why a request failed; see utils/errors
+  'error.voiceConnectFailed': "❌ I couldn't connect to your voice channel. Check that I can Connect and Speak there",
+  'error.extractionFailed': "❌ I couldn't load that song. Try again, or try another link",
+  'error.ageRestricted': "🔞 That video is age-restricted. Ask the bot owner to set up YouTube cookies to play it",
+  'error.membersOnly': '🔒 That video is members-only or needs a signed-in account. Ask the bot owner to set up YouTube cookies to play it',
+  'error.cookiesNoAccess': "🔒 That video needs a signed-in account, and the bot's account doesn't have access to it",
+  'error.geoBlocked': "🌍 That video isn't available in the bot's country",
+  'error.unavailable': '🚫 That video is private, removed or otherwise unavailable',
+  'error.offline': "📴 That channel isn't live right now",
+  'error.rateLimited': '⏳ YouTube is rate-limiting the bot right now. Try again in a few minutes',
+  'error.timeout': '⌛ Loading that took too long. Try again in a moment',
+
+  // Queue limits and saved playlists; see QueueLimitError and PlaylistError
+  'queueLimit.locked': 'The queue is locked, so only DJs can add songs right now',
+  'queueLimit.songTooLong': '**{track}** is longer than the {minutes}-minute limit',
+  'queueLimit.alreadyQueued': '**{track}** is already in the queue',
+  'queueLimit.allTooLong': 'All {count} songs are longer than the {minutes}-minute limit',
+  'queueLimit.full': 'The queue is full ({max} songs max)',
+  'queueLimit.noRoom': 'Only {room} more song(s) fit in the queue ({max} max), so none of these {count} were added',
+  'queueLimit.perUser': 'You can have up to {max} songs in the queue at once and already have {queued}',
+  'playlist.emptyName': 'Playlist name cannot be empty',
+  'playlist.exists': 'You already have a playlist called **{name}**',
+  'playlist.tooMany': 'You can only have {max} playlists',
+  'playlist.full': 'Playlists can hold at most {max} songs',
+  'playlist.badPosition': 'Position must be between 1 and {size}',
+  'playlist.notFound': "You don't have a playlist called **{name}**",
+
+  // Messages the bot posts on its own
+  'announce.nowPlaying': '🎶 Now playing **{track}**',
+  'announce.queueFinished': '✅ Queue finished',
+  'announce.requesterDm': '🎶 Your song **{track}** is playing now in **{server}**',
+  'announce.idleWarning': "💤 Nothing's playing, so I'll leave voice in a minute unless something starts",
+  'announce.idleLeft': '👋 Leaving voice after {minutes} minute(s) with nothing playing',
+  'announce.moveFailed': "⚠️ I was moved to {channel} but couldn't reconnect. Use /join to bring me back",
+  'announce.moved': '🔀 I was moved to {channel}; the queue carries on here',
+  'announce.unmuted': '🔊 I was unmuted, so playback has resumed',
+  'announce.mutedPaused': "⏸️ I was server-muted, so playback is paused. It resumes when I'm unmuted",
+  'announce.mutedPlaying': '⚠️ I was server-muted; the music keeps playing',
+  'announce.mutedLeft': '👋 I was server-muted, so I left and cleared the queue',
+  'announce.followFailed': "⚠️ I couldn't follow {user} to {channel}. Use /join to bring me there",
+  'announce.followed': '🚶 Followed {user} to {channel}',
+  'announce.resumeFailed': "⚠️ I couldn't resume playback after restarting. The queue is still here; adding a song with /play starts it again",
+  'announce.resumed': '▶️ Back after a restart, resuming **{track}**',
+  'announce.restarting': '🔄 The bot is restarting; playback will stop for a moment',
+  'announce.liveLimit': '⏱️ **{track}** has played live for {minutes} minutes, so the queue is moving on. Admins can change this with `/settings limits live_minutes:`',
+
+  // /play
+  'play.alreadyAdding': '👌 Already adding that one',
+  'play.invalidUrl': '❌ Only YouTube, Twitch and internet radio links are supported!',
+  'play.noResults': '❌ No results found for **{query}**',
+  'requestChannel.noPlaylists': "❌ Playlists can't be queued from the request channel; use `/play` for them",
+  'play.nowPlaying': '▶️ Now Playing',
+  'play.addedToQueue': '✅ Added to Queue',
+  'play.status': '🎯 Status',
+  'play.statusNowPlaying': 'Now Playing',
+  'play.position': '📍 Position',
+  'play.positionInQueue': '#{position} in Queue',
+  'play.failed': '❌ Error processing your request. Please make sure the URL is valid.',
+  'play.playlistFailed': "❌ Couldn't load that playlist. Make sure it's public.",
+  'play.playlistAllBlocked': '❌ Every song in that playlist is blocked on this server',
+  'play.playlistCleared': '🛑 The queue was cleared while the playlist was loading, so nothing was added',
+  'play.playlistAdded': '✅ Playlist Added',
+  'play.shuffledPlaylistAdded': '🔀 Shuffled Playlist Added',
+  'play.songs': '📋 Songs',
+  'play.songsSkipped': '{added} (skipped {skipped})',
+  'play.skippedBlocked': '{count} blocked',
+  'play.skippedTooLong': '{count} over the length limit',
+  'play.skippedQueued': '{count} already queued',
+  'play.playlistAllQueued': '❌ Every song in that playlist is already in the queue',
+  'play.chaptersAllQueued': '❌ Every chapter of that video is already in the queue',
+  'play.chaptersCleared': '🛑 The queue was cleared while the video was loading, so nothing was added',
+  'play.chaptersAdded': '✅ Chapters Added',
+  'play.chapters': '📖 Chapters',
+
+  // /play with a Deezer link
+  'deezer.failed': "❌ Couldn't read that from Deezer. Make sure the link is public",
+  'deezer.empty': '❌ That Deezer playlist has no tracks',
+  'deezer.matching': '⏳ Finding Deezer tracks on YouTube: {done}/{total}…',
+  'deezer.noneMatched': "❌ Couldn't find any of those tracks on YouTube",
+  'deezer.added': '✅ Added from Deezer',
+  'deezer.unmatched': '❓ Not found on YouTube ({count})',
+  'deezer.moreUnmatched': '... and {count} more',
+
+  // "Queue this" message menu
+  'queueThis.noLinks': '❌ There are no song or Spotify track links in that message',
+  'queueThis.spotifyUnreadable': "❌ Couldn't read that Spotify track",
+  'queueThis.spotifyFailed': "❌ Couldn't reach Spotify to look that track up",
+
+  // Progress and failures while importing a playlist file or song list
+  'import.progress': '⏳ Resolved {done}/{total}…',
+  'import.progressFailed': '⏳ Resolved {done}/{total}, {failed} failed…',
+  'import.failureLine': 'Line {line}: `{input}` — {reason}',
+  'import.moreFailures': '... and {count} more',
+  'import.notAdded': '❌ Not added ({count})',
+
+  // Playback commands
+  'replay.done': '🔁 Restarted **{track}** from the beginning',
+  'replay.live': "❌ Live streams can't be restarted",
+  'seek.notSeekable': "❌ This song can't be fast-forwarded or rewound, e.g. because it's a live stream",
+  'seek.forward': '⏩ Forwarded {seconds}s in **{track}** to `{position} / {duration}`',
+  'seek.back': '⏪ Rewound {seconds}s in **{track}** to `{position} / {duration}`',
+  'skip.title': '⏭️ Skipped',
+  'skip.footer': 'Skipped by {user}',
+  'skip.joinToVote': '❌ Join my voice channel to vote to skip',
+  'skip.titleMany': '⏭️ Skipped {count} songs',
+  'skip.nowPlaying': 'Now playing',
+  'pause.title': '⏸️ Paused',
+  'pause.footer': 'Use /resume to continue',
+  'resume.title': '▶️ Resumed',
+  'stop.joinToVote': '❌ Join my voice channel to vote to stop',
+  'stop.title': '⏹️ Stopped',
+  'stop.description': 'Music stopped and queue cleared',
+  'stop.footer': 'Stopped by {user}',
+  'clear.joinToVote': '❌ Join my voice channel to vote to clear the queue',
+  'clear.done': '🧹 Cleared {count} upcoming song(s); the current song keeps playing',
+  'clear.doneIdle': '🧹 Cleared {count} upcoming song(s)',
+  'mix.started': '📻 Mix Started',
+  'mix.footer': 'More songs from the mix are added as the queue runs low · /mix stop ends it',
+  'mix.empty': "❌ YouTube doesn't have a mix for that song",
+  'mix.notYouTube': '❌ Mixes only work with YouTube videos',
+  'mix.stopped': '📻 Mix stopped; songs already queued still play',
+  'mix.notRunning': '❌ No mix is running',
+  'leave.joinToVote': '❌ Join my voice channel to vote to make me leave',
+  'leave.title': '👋 Left Voice Channel',
+  'leave.description': 'Disconnected and cleared queue',
+  'leave.footer': 'Left by {user}',
+  'join.already': '🔊 Already in **{channel}**',
+  'join.failed': "❌ Couldn't connect to **{channel}**",
+  'join.joined': '🔊 Joined',
+  'join.moved': '🔊 Moved',
+  'join.joinedDescription': 'Connected to **{channel}**. Use /play to start the music',
+  'join.movedDescription': 'Now in **{channel}**. The queue carries on from here',
+  'join.footer': 'Summoned by {user}',
+  'back.noPrevious': '❌ There is no previous song to go back to',
+  'back.title': '⏮️ Back',
+  'loop.djOnly': '❌ Only DJs can change the loop mode',
+  'loop.nothingPlaying': '❌ Nothing is playing',
+  'loop.timesTrackOnly': '❌ `times` only works when looping this song',
+  'loop.trackTimes': 'Playing this song {count} more time(s), then moving on',
+  'shuffle.done': '🔀 Shuffled {count} songs. Songs still being imported will be added after them',
+  'dedupe.none': '✨ No song is in the queue twice',
+  'dedupe.done': '🧹 Removed {count} duplicate song(s), keeping the first of each',
+  'queue.findNone': '🔍 No queued song matches **{query}**',
+  'queue.findTitle': '🔍 {count} queued song(s) matching "{query}"',
+  'queue.findFooter': 'Use the position or ID with /move or /remove',
+  'remove.done': '🗑️ Removed **{title}** (`{id}`) from position {position}',
+  'move.badPosition': '❌ The new position must be between 1 and {size}',
+  'move.done': '↕️ Moved **{title}** (`{id}`) to position {position}',
+  'volume.adminOnly': '❌ You need the Manage Server permission to change the volume',
+  'volume.title': '🔊 Volume Set to {volume}%',
+  'volume.everywhere': 'Applies to everything played in this server',
+  'volume.personal': 'Now playing at {volume}% because the requester is listening alone with their own /myvolume',
+  'grab.title': '💾 Saved Song',
+  'grab.position': '⏱️ Position',
+  'grab.server': '🏠 Server',
+  'grab.unknownServer': 'Unknown server',
+  'grab.sent': '📬 Sent the current song to your DMs',
+  'grab.dmFailed': "❌ I couldn't DM you. Check that direct messages from server members are enabled.",
+
+  // /playlist
+  'playlist.songNotFound': "❌ Couldn't find a YouTube song for **{query}**",
+  'playlist.added': '✅ Added **{track}** to **{name}** (#{position})',
+  'playlist.removed': '🗑️ Removed **{track}** from **{name}**',
+  'playlist.isEmpty': '**{name}** is empty',
+  'playlist.allBlocked': 'Every song in **{name}** is blocked on this server',
+  'playlist.allQueued': 'Every song in **{name}** is already in the queue',
+  'playlist.queued': '✅ Playlist Queued',
+  'playlist.deleted': '🗑️ Deleted playlist **{name}**',
+  'playlist.yours': '📚 Your Playlists',
+  'playlist.noneYet': 'You have no playlists yet. Create one with `/playlist create`.',
+  'playlist.emptyHint': 'This playlist is empty. Add songs with `/playlist add`.',
+  'playlist.createTitle': 'New playlist: {name}',
+  'playlist.createLabel': 'Songs, one per line (up to {max})',
+  'playlist.createPlaceholder': 'YouTube URLs or song names. Leave empty to start with an empty playlist',
+  'playlist.createOverflow': 'only the first {max} are added here; use /import for the rest',
+  'playlist.createdWith': '**{name}** starts with {songs}',
+  'playlist.created': '✅ Playlist Created',
+  'playlist.createdBy': 'Created by {user}',
+
+  // /import
+  'import.nothingGiven': '❌ Attach a playlist file or paste some URLs',
+  'import.joinVoiceFirst': '❌ You must be in a voice channel to import into the queue!',
+  'import.fileTooBig': '❌ Playlist files can be at most {kb} KB',
+  'import.unreadable': "❌ Couldn't read the playlist: {reason}",
+  'import.noSongs': '❌ No songs found to import',
+  'import.tooMany': '❌ Imports are limited to {max} songs (found {count})',
+  'import.resolving': '⏳ Resolving {count} songs...',
+  'import.intoQueue': 'the queue',
+  'import.intoPlaylist': 'playlist **{name}**',
+  'import.cleared': '🛑 The queue was cleared while this import was running, so nothing was added',
+  'import.summary': 'Imported **{imported}** of {total} songs into {destination}',
+  'import.skipped': '(skipped {skipped})',
+  'import.failed': '❌ Failed ({count})',
+  'import.finished': '📥 Import Finished',
+  'import.importedBy': 'Imported by {user}',
+
+  // /history
+  'history.noMatches': '🔍 Nothing played here matches **{text}**',
+  'history.plays': '{count} play(s)',
+  'history.lastPlayed': 'last {time}',
+  'history.title': '🔍 History matching "{text}"',
+  'history.footer': 'Queue one with /history play <number>',
+  'history.searchFirst': '❌ Search with `/history search` first',
+  'history.noSuchResult': '❌ Your last search only had {count} result(s)',
+  'history.gone': "❌ **{track}** isn't available anymore",
+
+  // /blacklist
+  'blacklist.songBlocked': '❌ **{track}** is blocked on this server',
+  'blacklist.songBlockedReason': '❌ **{track}** is blocked on this server: {reason}',
+  'blacklist.title': '🚫 Blacklist',
+  'blacklist.empty': 'Nothing is blocked. Admins can add entries with `/blacklist add`.',
+  'blacklist.adminOnly': '❌ You need the Manage Server permission to change the blacklist',
+  'blacklist.invalidKeyword': "❌ That isn't a valid keyword",
+  'blacklist.invalidId': "❌ That isn't a valid {kind} URL or ID",
+  'blacklist.alreadyBlocked': '❌ {entry} is already blocked',
+  'blacklist.full': '❌ This server already has {max} blacklist entries',
+  'blacklist.added': "🚫 Blocked {entry}. Songs already in the queue aren't removed",
+  'blacklist.notFound': '❌ No {kind} entry for `{value}` is on the blacklist',
+  'blacklist.removed': '🗑️ Unblocked {entry}',
+
+  // /alias
+  'alias.title': '⭐ Song Shortcuts',
+  'alias.empty': 'No shortcuts yet. Admins can add one with `/alias add`.',
+  'alias.more': '…and {count} more',
+  'alias.footer': 'Use a shortcut name with /play',
+  'alias.adminOnly': '❌ You need the Manage Server permission to change shortcuts',
+  'alias.badName': '❌ Shortcut names must be plain words, not URLs',
+  'alias.full': '❌ This server already has {max} shortcuts',
+  'alias.added': '⭐ `/play {name}` now plays {url}',
+  'alias.notFound': '❌ There is no shortcut called **{name}**',
+  'alias.removed': '🗑️ Removed shortcut **{name}**',
+
+  // /top
+  'top.week': 'This Week',
+  'top.month': 'This Month',
+  'top.all': 'All Time',
+  'top.nothingPlayed': '📭 Nothing has been played in that period yet',
+  'top.title': '🏆 Top Songs — {period}',
+  'top.requesters': '🙋 Most Active Requesters',
+  'top.songs': '{count} song(s)',
+
+  // /export
+  'export.noPlaylists': "❌ You don't have any playlists to export",
+  'export.playlistsOne': '📤 Exported 1 playlist',
+  'export.playlists': '📤 Exported {count} playlists',
+  'export.playlistsSkipped': "({count} more didn't fit in one message)",
+  'export.queueOne': '📤 Exported 1 song. Load it elsewhere with `/import`.',
+  'export.queue': '📤 Exported {count} songs. Load it elsewhere with `/import`.',
+
+  // /feature
+  'feature.ownerOnly': '❌ Only the bot owner can manage experimental features',
+  'feature.title': '🧪 Experimental Features',
+  'feature.enabled': '🧪 **{feature}** is now enabled on this server',
+  'feature.disabled': '🧪 **{feature}** is now disabled on this server',
+
+  // /insights
+  'insights.stillLoading': '{count} song(s) still loading',
+  'insights.moreRequesters': '…and {count} more',
+  'insights.title': '📈 Queue Insights',
+  'insights.songs': 'Songs',
+  'insights.runtime': 'Total Runtime',
+  'insights.duplicates': 'Duplicates',
+  'insights.requesters': 'Requesters',
+  'insights.sources': 'Sources',
+  'insights.includesCurrent': 'Includes the song playing now',
+  'insights.upcoming': 'Upcoming songs',
+  'insights.longest': 'Longest',
+  'insights.shortest': 'Shortest',
+
+  // /chapter
+  'chapter.none': '❌ **{track}** has no chapters',
+  'chapter.last': '❌ This is the last chapter',
+  'chapter.onlyCount': '❌ There are only {count} chapters',
+  'chapter.jumped': '📖 Jumped to chapter {chapter}',
+
+  // /stats
+  'stats.nothingYet': 'Nothing yet',
+  'stats.userTitle': '📊 Listening Stats for {user}',
+  'stats.songsRequested': '🎵 Songs Requested',
+  'stats.timeListening': '🎧 Time Listening',
+  'stats.mostRequested': '🔥 Most Requested',
+  'stats.serverTitle': '📊 Server Listening Stats',
+  'stats.songsPlayed': '🎵 Songs Played',
+  'stats.musicPlayed': '🎧 Music Played',
+  'stats.mostPlayed': '🔥 Most Played',
+  'stats.topRequesters': '🙋 Top Requesters',
+  'stats.nobodyYet': 'Nobody yet',
+  'stats.noRecentRequests': 'no recent requests',
+  'stats.successRate': '{rate}% of {attempts} request(s)',
+  'stats.lastFailure': 'Last failure {time}: `{error}`',
+  'stats.backendsTitle': '📊 Stream Backend Health',
+  'stats.backendsFooter': 'Based on the last 30 minutes · backends are tried in this order',
+
+  // /style
+  'style.adminOnly': '❌ You need the Manage Server permission to change the display style',
+  'style.changed': '🎨 The player and queue now use the **{style}** style: {description}',
+  'style.classic': 'embeds with artwork and a progress bar',
+  'style.compact': 'plain text, one line per song',
+  'style.card': 'a generated now-playing image',
+
+  // Audio filters
+  'nightcore.on': '🌙 Nightcore On',
+  'nightcore.off': '🌙 Nightcore Off',
+  'nightcore.onDescription': 'Playing at **{rate}x** speed and pitch',
+  'nightcore.offDescription': 'Back to normal speed and pitch',
+  'speed.title': '⏩ Speed Changed',
+  'speed.normal': 'Playing at normal speed',
+  'speed.changed': 'Playing at **{rate}x** speed',
+  'normalize.adminOnly': '❌ You need the Manage Server permission to change normalization',
+  'normalize.on': '🔊 Normalization On',
+  'normalize.off': '🔊 Normalization Off',
+  'normalize.onDescription': 'Quiet and loud songs will now play at a consistent level',
+  'normalize.offDescription': 'Songs will play at their original volume',
+
+  // /dashboard
+  'dashboard.adminOnly': '❌ You need the Manage Server permission to use the dashboard',
+  'dashboard.notRunning': "❌ The dashboard isn't running; the bot owner can start it by setting `WEB_PORT`",
+  'dashboard.link': "🖥️ [Open the dashboard]({url})\nThe link is yours alone and works for 12 hours; don't share it",
+
+  // /lock and /unlock
+  'lock.djOnly': '❌ Only DJs and admins can lock the queue',
+  'lock.alreadyLocked': '🔒 The queue is already locked. Use /unlock to open it again',
+  'lock.title': '🔒 Queue Locked',
+  'lock.description': 'Only DJs and admins can add songs until someone uses /unlock. Everyone can still see the queue',
+  'unlock.djOnly': '❌ Only DJs and admins can unlock the queue',
+  'unlock.notLocked': "🔓 The queue isn't locked",
+  'unlock.title': '🔓 Queue Unlocked',
+  'unlock.description': 'Everyone can add songs again',
+
+  // /datasaver and /idletimeout
+  'dataSaver.adminOnly': '❌ You need the Manage Server permission to change data saver mode',
+  'dataSaver.on': '📶 Data Saver On',
+  'dataSaver.off': '📶 Data Saver Off',
+  'dataSaver.onDescription': 'Lower-bitrate audio will be used starting with the next song',
+  'dataSaver.offDescription': 'Full-quality audio will be used starting with the next song',
+  'dataSaver.sessionSoFar': 'This Session So Far',
+  'idleTimeout.adminOnly': '❌ You need the Manage Server permission to change the idle timeout',
+  'idleTimeout.set': '💤 Idle Timeout Set to {minutes} Minute(s)',
+  'idleTimeout.off': '💤 Idle Timeout Off',
+  'idleTimeout.setDescription': "I'll leave voice after {minutes} minute(s) with an empty queue or paused music, and post a notice first",
+  'idleTimeout.offDescription': 'I stay in voice until everyone leaves or someone uses /leave',
+
+  // /admin
+  'admin.ownerOnly': '❌ Only the bot owner can run maintenance commands',
+  'admin.unmanaged': '❌ yt-dlp is taken from PATH on this host. Set `YTDLP_MANAGED=true` to let the bot manage it',
+  'admin.installed': '✅ Installed yt-dlp **{version}**',
+  'admin.updatedFrom': '✅ Installed yt-dlp **{version}** (was {previous})',
+  'admin.upToDate': '✅ yt-dlp **{version}** is already the latest release',
+  'admin.updateFailed': '❌ Update failed: {reason}',
+  'admin.stillUsing': '. Still using {version}',
+
+  // /karaoke
+  'karaoke.notOn': "❌ Karaoke isn't on",
+  'karaoke.over': '🎤 Karaoke is over',
+  'karaoke.cantPost': "❌ I can't post lyrics in this channel",
+  'karaoke.started': '🎤 Karaoke time! Lyrics follow the music below until the queue ends',
+  'karaoke.searching': '🔍 Looking for lyrics...',
+  'karaoke.noLyrics': 'No synced lyrics for this one; sit this one out 🎧',
+  'karaoke.footer': 'Karaoke · /karaoke stop to end',
+
+  // /suggest
+  'suggest.disabled': '❌ Search suggestions are disabled on this bot',
+  'suggest.none': '🔍 No suggestions for **{partial}**',
+  'suggest.title': '🔍 Suggestions for "{partial}"',
+  'suggest.footer': 'Source: {source} • Use /play to queue one',
+
+  // /voicepolicy
+  'voicePolicy.pause': 'Pause until unmuted and say why',
+  'voicePolicy.announce': 'Keep playing and post a notice',
+  'voicePolicy.disconnect': 'Leave voice and clear the queue',
+  'voicePolicy.title': '🔇 When Muted or Moved',
+  'voicePolicy.footer': 'Admins can change this with /voicepolicy <policy>',
+  'voicePolicy.adminOnly': '❌ You need the Manage Server permission to change the voice policy',
+  'voicePolicy.changed': "🔇 When server-muted or moved, I'll now: **{policy}**",
+
+  // /myvolume and /notifyme
+  'myVolume.none': "🔊 You have no preferred volume, so the server's {server}% applies",
+  'myVolume.current': "🔊 Your songs play at **{volume}%** when you're listening alone (server volume: {server}%)",
+  'myVolume.cleared': '🔊 Preferred Volume Cleared',
+  'myVolume.set': '🔊 Preferred Volume Set to {volume}%',
+  'myVolume.clearedDescription': "Your songs will play at the server's {server}%",
+  'myVolume.setDescription': "Used for songs you requested while nobody else is listening; otherwise the server's {server}% applies",
+  'notifyMe.dm': "I'll DM you",
+  'notifyMe.ping': "I'll mention you in the announcement channel (the mention is deleted right away)",
+  'notifyMe.current': '🔔 When a song you requested starts after the one before it, {how}',
+  'notifyMe.currentOff': "🔕 You aren't told when your songs start. Use `/notifyme how:` to turn it on",
+  'notifyMe.changed': '🔔 When a song you requested starts after the one before it, {how}. This applies in every server',
+  'notifyMe.off': "🔕 You won't be told when your songs start anymore",
+
+  // /intro
+  'intro.cleared': '🗑️ Your intro is gone',
+  'intro.none': "❌ You don't have an intro",
+  'intro.notAudio': '❌ Upload an audio or video file',
+  'intro.tooBig': '❌ Intros can be at most {mb} MB',
+  'intro.unreadable': "❌ I couldn't read any audio from that file",
+  'intro.saved': '🎺 Intro saved. It plays over the music when you join my voice channel, at most once every {minutes} minutes',
+  'intro.disabledHere': 'Intros are turned off on this server; an admin can turn them on with `/settings intros`',
+
+  // /help
+  'help.playback': 'Playback',
+  'help.playbackDescription': 'Play, pause, skip and shape the sound',
+  'help.queue': 'Queue',
+  'help.queueDescription': 'See and rearrange what plays next',
+  'help.playlists': 'Playlists',
+  'help.playlistsDescription': 'Save, import and export songs',
+  'help.stats': 'Stats',
+  'help.statsDescription': 'What this server listens to',
+  'help.server': 'Server Settings',
+  'help.serverDescription': 'Admin and DJ controls',
+  'help.other': 'Other',
+  'help.otherDescription': 'Everything else',
+  'help.footer': 'Page {page} of {pages} · <required> [optional]',
+  'help.chooseCategory': 'Choose a category',
+
+  // /settings
+  'settings.languageTitle': '🌐 Language Set',
+  'settings.languageDescription': 'Replies and player messages in this server are now in {language}',
+  'settings.adminOnly': '❌ You need the Manage Server permission to change settings',
+  'settings.announceCleared': '📢 Notices will go to the channel with the player, or where the last command was used',
+  'settings.announceNoPermission': "❌ I can't post in {channel}; give me permission to send messages there first",
+  'settings.announceTitle': '📢 Announcement Channel Set',
+  'settings.announceDescription': 'Now-playing, queue-finished and other notices will be posted in {channel}. Command replies stay where the command was used',
+  'settings.requestOff': '🎙️ Request-channel mode is off; messages are left alone again',
+  'settings.requestUnavailable': '❌ Request channels are turned off for this bot. Its host can turn them on with `REQUEST_CHANNELS=true` once the Message Content intent is enabled',
+  'settings.requestNoPermission': '❌ I need permission to send messages, add reactions, read message history and manage messages in {channel} first',
+  'settings.requestTitle': '🎙️ Request Channel Set',
+  'settings.requestDescription': "Every message posted in {channel} is now queued like `/play`. I'll react ✅ or ❌ and tidy the messages away after a few seconds",
+  'settings.nowPlayingOn': "🎶 I'll post a message when each song starts",
+  'settings.nowPlayingOff': '🔕 No more now-playing messages. Command replies, the player and other notices stay as they are',
+  'settings.autoDedupeOn': "🧹 Songs already in the queue won't be added again. Use /dedupe to clear out the ones queued before",
+  'settings.autoDedupeOff': '🔁 Songs can be queued more than once again',
+  'settings.ttsLavalink': "❌ Spoken announcements don't work with Lavalink playback",
+  'settings.ttsNoEngine': '❌ No TTS engine is set up; the bot owner can set `PIPER_MODEL` or `TTS_URL`',
+  'settings.ttsOn': "🗣️ I'll say the title of each song in voice before it starts, from the next song on",
+  'settings.ttsOff': '🔇 No more spoken announcements',
+  'settings.introsLavalink': "❌ Join intros don't work with Lavalink playback",
+  'settings.introsOn': '🎺 Members who set one with `/intro set` get their intro played when they join my voice channel',
+  'settings.introsOff': '🔇 Join intros are off',
+  'settings.stickyOn': "📌 I'll keep one pinned now-playing message up to date with the progress, queue length and filters, instead of posting for every song",
+  'settings.stickyOff': '📌 Sticky player off; the old message stays but is no longer updated',
+  'settings.djSet': '🎧 DJ Role Set',
+  'settings.djCleared': '🎧 DJ Role Cleared',
+  'settings.djSetDescription': 'Only {role} and admins can stop, shuffle or loop playback for everyone. The player buttons only work for people in my voice channel',
+  'settings.djClearedDescription': 'Anyone in my voice channel can use the player controls',
+  'settings.followBot': "❌ I can't follow a bot",
+  'settings.following': '🚶 Following a DJ',
+  'settings.notFollowing': '🚶 Not Following Anyone',
+  'settings.followingDescription': "When {user} moves to another voice channel while I'm with them, I move too and the music carries on there",
+  'settings.notFollowingDescription': 'I stay in my voice channel until someone moves me or uses /join',
+  'settings.badColor': '❌ Colors are hex codes like `#5865F2`',
+  'settings.themeTitle': '🎨 Player Theme',
+  'settings.themeColor': 'Color',
+  'settings.themeEmoji': 'Emoji',
+  'settings.themeFooter': 'Footer',
+  'settings.themeNoFooter': 'None',
+  'settings.themeDescription': 'Applies to the player and /queue from their next update',
+  'settings.apiKeyRevoked': '🔑 API key revoked; requests using it are now refused',
+  'settings.apiKeyNoServer': "❌ The REST API isn't running; the bot owner can start it by setting `WEB_PORT`",
+  'settings.apiKeyCreated': "🔑 Your new API key, replacing any old one. It won't be shown again, so keep it somewhere safe:\n`{key}`\nSend it as `Authorization: Bearer <key>` to {url}",
+  'settings.limitsTitle': '📏 Queue Limits',
+  'settings.limitPerUser': 'Per Person',
+  'settings.limitTotal': 'Whole Queue',
+  'settings.limitSongLength': 'Song Length',
+  'settings.limitLive': 'Live Streams',
+  'settings.limitSongs': '{count} songs',
+  'settings.limitMinutes': '{count} minutes',
+  'settings.noLimit': 'No limit',
+  'settings.limitUntilEnd': 'Until they end',
+  'settings.limitsDescription': 'Admins and members with the DJ role can queue past these. Live streams are cut off for everyone',
+  'settings.commandChannelsTitle': '🎵 Command Channels',
+  'settings.commandChannelsSome': 'Commands only work in {channels}. Elsewhere people get a private pointer to them; admins can use commands anywhere',
+  'settings.commandChannelsAny': 'Commands work in any channel',
+  'settings.unknownCommand': '❌ There is no `/{name}` command',
+  'settings.alwaysOn': "❌ `/{name}` can't be turned off",
+  'settings.commandOn': '✅ /{name} Turned On',
+  'settings.commandOff': '🚫 /{name} Turned Off',
+  'settings.disabledCommands': 'Turned off here: {commands}',
+  'settings.allCommandsOn': 'Every command is on',
+  'settings.commandOffChoice': '{name} (off)',
+};
+
+export type MessageKey = keyof typeof en;
@@ -0,0 +1,552 @@
+import { MessageKey } from './en';
+
+/** Portuguese messages. Typed against the English catalog, so a missing key fails to compile. */
+export const pt: Record<MessageKey, string> = {
+  // Shared replies
+  'common.nothingPlaying': '❌ Nada está tocando no momento',
+  'common.nothingPaused': '❌ Nada está pausado',
+  'common.queueEmpty': '🎵 A fila está vazia',
+  'common.notInVoice': '❌ O bot não está em um canal de voz',
+  'common.joinVoiceFirst': '❌ Você precisa estar em um canal de voz para usar este comando!',
+  'common.noSongMatches': '❌ Nenhuma música na fila corresponde a `{reference}`. Use uma posição entre 1 e {size} ou um ID do /queue',
+  'common.needTwoToShuffle': '❌ É preciso ter pelo menos 2 músicas na fila para embaralhar',
+  'common.dataUsed': '📶 Dados Usados Nesta Sessão',
+  'common.changedBy': 'Alterado por {user}',
+  'common.requestedBy': 'Pedido por {user}',
+  'common.addedBy': 'Adicionado por {user}',
+  'common.album': 'Álbum',
+  'common.duration': '⏱️ Duração',
+  'common.queueSize': '📍 Tamanho da Fila',
+  'common.bandwidth': '📥 ~{downloaded} baixados · 📤 {sent} enviados ao Discord',
+  'common.vote': '🗳️ Votação para {action}: {votes}/{needed}. Os outros no canal de voz podem votar com o mesmo comando',
+  'common.voteStop': 'parar a música e limpar a fila',
+  'common.voteLeave': 'me fazer sair e limpar a fila',
+  'common.voteClear': 'limpar a fila',
+  'common.voteSkip': 'pular esta música',
+
+  // Player message and queue
+  'player.nowPlaying': 'Tocando Agora',
+  'player.queueTitle': 'Fila de Músicas',
+  'player.queueTitleCount': 'Fila de Músicas ({count})',
+  'player.compactQueueTitle': '**Fila ({count})**',
+  'player.chapter': 'Capítulo',
+  'player.progress': 'Progresso',
+  'player.duration': 'Duração',
+  'player.live': '🔴 AO VIVO',
+  'player.onAir': 'No ar: {title}',
+  'player.queueSizeLabel': 'Tamanho da Fila',
+  'player.songCountOne': '1 música',
+  'player.songCount': '{count} músicas',
+  'player.filters': 'Filtros',
+  'player.requestedBy': 'Pedida por',
+  'player.requestedByUser': 'Pedida por {user}',
+  'player.speed': 'velocidade {value}x',
+  'player.pitch': 'tom {value}x',
+  'player.upNext': 'A Seguir',
+  'player.upNextCount': 'A Seguir ({count})',
+  'player.moreSongs': '... e mais {count} músicas',
+  'player.playsIn': 'toca em ~{time}',
+  'player.totalLength': 'Duração total da fila: {time}',
+  'player.endOfQueue': 'Fim da fila',
+  'player.lastSong': 'A fila termina depois da música atual',
+  'player.noSong': 'Nenhuma música está tocando.',
+  'player.nothingPlaying': 'Nada tocando',
+  'player.queueButton': 'Fila',
+  'player.loopOff': 'Repetição desligada',
+  'player.loopTrack': 'Repetindo esta música',
+  'player.loopQueue': 'Repetindo a fila',
+
+  // Player buttons and command guards
+  'guard.restarting': '🔄 O bot está reiniciando, tente de novo em instantes',
+  'guard.joinForControls': '❌ Entre no meu canal de voz para usar os controles do player',
+  'guard.djButton': '❌ Só DJs podem usar este botão',
+  'guard.nothingPlaying': 'Nada está tocando no momento.',
+  'guard.sessionEnded': '⏹️ Sessão encerrada · {bandwidth}',
+  'guard.buttonFailed': 'Não foi possível executar a ação do player.',
+  'guard.commandChannels': '🎵 Comandos de música vão em {channels}',
+  'guard.commandDisabled': '🚫 `/{command}` está desativado neste servidor',
+  'guard.featureDisabled': '🧪 `/{command}` é um recurso experimental que não está ativado neste servidor',
+  'guard.commandFailed': '❌ Ocorreu um erro ao executar este comando!',
+  'guard.userRateLimited': '⏳ Você está adicionando músicas mais rápido do que consigo buscá-las. Tente de novo em {seconds}s',
+  'guard.guildRateLimited': '⏳ Este servidor está buscando muitas músicas agora. Tente de novo em {seconds}s',
+
+  // Why a request failed
+  'error.voiceConnectFailed': '❌ Não consegui conectar no seu canal de voz. Verifique se posso Conectar e Falar lá',
+  'error.extractionFailed': '❌ Não consegui carregar essa música. Tente de novo ou tente outro link',
+  'error.ageRestricted': '🔞 Esse vídeo tem restrição de idade. Peça ao dono do bot para configurar cookies do YouTube para tocá-lo',
+  'error.membersOnly': '🔒 Esse vídeo é só para membros ou precisa de uma conta conectada. Peça ao dono do bot para configurar cookies do YouTube para tocá-lo',
+  'error.cookiesNoAccess': '🔒 Esse vídeo precisa de uma conta conectada, e a conta do bot não tem acesso a ele',
+  'error.geoBlocked': '🌍 Esse vídeo não está disponível no país do bot',
+  'error.unavailable': '🚫 Esse vídeo é privado, foi removido ou está indisponível',
+  'error.offline': '📴 Esse canal não está ao vivo agora',
+  'error.rateLimited': '⏳ O YouTube está limitando o bot agora. Tente de novo em alguns minutos',
+  'error.timeout': '⌛ Carregar isso demorou demais. Tente de novo em instantes',
+
+  // Queue limits and saved playlists; see QueueLimitError and PlaylistError
+  'queueLimit.locked': 'A fila está trancada, então só DJs podem adicionar músicas agora',
+  'queueLimit.songTooLong': '**{track}** passa do limite de {minutes} minutos',
+  'queueLimit.alreadyQueued': '**{track}** já está na fila',
+  'queueLimit.allTooLong': 'Todas as {count} músicas passam do limite de {minutes} minutos',
+  'queueLimit.full': 'A fila está cheia (máximo de {max} músicas)',
+  'queueLimit.noRoom': 'Só cabem mais {room} música(s) na fila (máximo de {max}), então nenhuma destas {count} foi adicionada',
+  'queueLimit.perUser': 'Você pode ter até {max} músicas na fila ao mesmo tempo e já tem {queued}',
+  'playlist.emptyName': 'O nome da playlist não pode ficar vazio',
+  'playlist.exists': 'Você já tem uma playlist chamada **{name}**',
+  'playlist.tooMany': 'Você só pode ter {max} playlists',
+  'playlist.full': 'Playlists podem ter no máximo {max} músicas',
+  'playlist.badPosition': 'A posição precisa estar entre 1 e {size}',
+  'playlist.notFound': 'Você não tem uma playlist chamada **{name}**',
+
+  // Messages the bot posts on its own
+  'announce.nowPlaying': '🎶 Tocando agora **{track}**',
+  'announce.queueFinished': '✅ Fila concluída',
+  'announce.requesterDm': '🎶 Sua música **{track}** está tocando agora em **{server}**',
+  'announce.idleWarning': '💤 Nada está tocando, então vou sair da voz em um minuto se nada começar',
+  'announce.idleLeft': '👋 Saindo da voz depois de {minutes} minuto(s) sem nada tocando',
+  'announce.moveFailed': '⚠️ Fui movido para {channel} mas não consegui reconectar. Use /join para me trazer de volta',
+  'announce.moved': '🔀 Fui movido para {channel}; a fila continua aqui',
+  'announce.unmuted': '🔊 Fui desmutado, então a música voltou a tocar',
+  'announce.mutedPaused': '⏸️ Fui mutado pelo servidor, então a música está pausada. Ela volta quando eu for desmutado',
+  'announce.mutedPlaying': '⚠️ Fui mutado pelo servidor; a música continua tocando',
+  'announce.mutedLeft': '👋 Fui mutado pelo servidor, então saí e limpei a fila',
+  'announce.followFailed': '⚠️ Não consegui seguir {user} até {channel}. Use /join para me levar até lá',
+  'announce.followed': '🚶 Segui {user} até {channel}',
+  'announce.resumeFailed': '⚠️ Não consegui retomar a música depois de reiniciar. A fila continua aqui; adicionar uma música com /play a inicia de novo',
+  'announce.resumed': '▶️ De volta após reiniciar, retomando **{track}**',
+  'announce.restarting': '🔄 O bot está reiniciando; a música vai parar por um momento',
+  'announce.liveLimit': '⏱️ **{track}** está tocando ao vivo há {minutes} minutos, então a fila vai seguir. Admins podem mudar isso com `/settings limits live_minutes:`',
+
+  // /play
+  'play.alreadyAdding': '👌 Já estou adicionando essa',
+  'play.invalidUrl': '❌ Só links do YouTube, da Twitch e de rádios online são aceitos!',
+  'play.noResults': '❌ Nenhum resultado para **{query}**',
+  'requestChannel.noPlaylists': '❌ Playlists não podem ser adicionadas pelo canal de pedidos; use `/play` para elas',
+  'play.nowPlaying': '▶️ Tocando Agora',
+  'play.addedToQueue': '✅ Adicionada à Fila',
+  'play.status': '🎯 Status',
+  'play.statusNowPlaying': 'Tocando Agora',
+  'play.position': '📍 Posição',
+  'play.positionInQueue': '#{position} na Fila',
+  'play.failed': '❌ Erro ao processar seu pedido. Verifique se a URL é válida.',
+  'play.playlistFailed': '❌ Não consegui carregar essa playlist. Verifique se ela é pública.',
+  'play.playlistAllBlocked': '❌ Todas as músicas dessa playlist estão bloqueadas neste servidor',
+  'play.playlistCleared': '🛑 A fila foi limpa enquanto a playlist carregava, então nada foi adicionado',
+  'play.playlistAdded': '✅ Playlist Adicionada',
+  'play.shuffledPlaylistAdded': '🔀 Playlist Embaralhada Adicionada',
+  'play.songs': '📋 Músicas',
+  'play.songsSkipped': '{added} ({skipped} ignoradas)',
+  'play.skippedBlocked': '{count} bloqueadas',
+  'play.skippedTooLong': '{count} acima do limite de duração',
+  'play.skippedQueued': '{count} já na fila',
+  'play.playlistAllQueued': '❌ Todas as músicas dessa playlist já estão na fila',
+  'play.chaptersAllQueued': '❌ Todos os capítulos desse vídeo já estão na fila',
+  'play.chaptersCleared': '🛑 A fila foi limpa enquanto o vídeo carregava, então nada foi adicionado',
+  'play.chaptersAdded': '✅ Capítulos Adicionados',
+  'play.chapters': '📖 Capítulos',
+
+  // /play with a Deezer link
+  'deezer.failed': '❌ Não consegui ler isso do Deezer. Verifique se o link é público',
+  'deezer.empty': '❌ Essa playlist do Deezer não tem faixas',
+  'deezer.matching': '⏳ Procurando as faixas do Deezer no YouTube: {done}/{total}…',
+  'deezer.noneMatched': '❌ Não encontrei nenhuma dessas faixas no YouTube',
+  'deezer.added': '✅ Adicionado do Deezer',
+  'deezer.unmatched': '❓ Não encontradas no YouTube ({count})',
+  'deezer.moreUnmatched': '... e mais {count}',
+
+  // "Queue this" message menu
+  'queueThis.noLinks': '❌ Não há links de música ou de faixas do Spotify nessa mensagem',
+  'queueThis.spotifyUnreadable': '❌ Não consegui ler essa faixa do Spotify',
+  'queueThis.spotifyFailed': '❌ Não consegui falar com o Spotify para buscar essa faixa',
+
+  // Progress and failures while importing a playlist file or song list
+  'import.progress': '⏳ {done}/{total} resolvidas…',
+  'import.progressFailed': '⏳ {done}/{total} resolvidas, {failed} com falha…',
+  'import.failureLine': 'Linha {line}: `{input}` — {reason}',
+  'import.moreFailures': '... e mais {count}',
+  'import.notAdded': '❌ Não adicionadas ({count})',
+
+  // Playback commands
+  'replay.done': '🔁 Recomecei **{track}** do início',
+  'replay.live': '❌ Não dá para recomeçar uma transmissão ao vivo',
+  'seek.notSeekable': '❌ Não dá para avançar ou voltar nesta música, por exemplo porque é uma transmissão ao vivo',
+  'seek.forward': '⏩ Avancei {seconds}s em **{track}** para `{position} / {duration}`',
+  'seek.back': '⏪ Voltei {seconds}s em **{track}** para `{position} / {duration}`',
+  'skip.title': '⏭️ Pulada',
+  'skip.footer': 'Pulada por {user}',
+  'skip.joinToVote': '❌ Entre no meu canal de voz para votar para pular',
+  'skip.titleMany': '⏭️ {count} músicas puladas',
+  'skip.nowPlaying': 'Tocando agora',
+  'pause.title': '⏸️ Pausada',
+  'pause.footer': 'Use /resume para continuar',
+  'resume.title': '▶️ Retomada',
+  'stop.joinToVote': '❌ Entre no meu canal de voz para votar para parar',
+  'stop.title': '⏹️ Parado',
+  'stop.description': 'Música parada e fila limpa',
+  'stop.footer': 'Parado por {user}',
+  'clear.joinToVote': '❌ Entre no meu canal de voz para votar para limpar a fila',
+  'clear.done': '🧹 {count} música(s) removida(s) da fila; a música atual continua tocando',
+  'clear.doneIdle': '🧹 {count} música(s) removida(s) da fila',
+  'mix.started': '📻 Mix Iniciado',
+  'mix.footer': 'Mais músicas do mix são adicionadas quando a fila fica curta · /mix stop encerra',
+  'mix.empty': '❌ O YouTube não tem um mix para essa música',
+  'mix.notYouTube': '❌ Mixes só funcionam com vídeos do YouTube',
+  'mix.stopped': '📻 Mix parado; as músicas já na fila ainda tocam',
+  'mix.notRunning': '❌ Nenhum mix está tocando',
+  'leave.joinToVote': '❌ Entre no meu canal de voz para votar para eu sair',
+  'leave.title': '👋 Saí do Canal de Voz',
+  'leave.description': 'Desconectado e fila limpa',
+  'leave.footer': 'Desconectado por {user}',
+  'join.already': '🔊 Já estou em **{channel}**',
+  'join.failed': '❌ Não consegui conectar em **{channel}**',
+  'join.joined': '🔊 Entrei',
+  'join.moved': '🔊 Mudei de Canal',
+  'join.joinedDescription': 'Conectado em **{channel}**. Use /play para começar a música',
+  'join.movedDescription': 'Agora em **{channel}**. A fila continua daqui',
+  'join.footer': 'Chamado por {user}',
+  'back.noPrevious': '❌ Não há música anterior para voltar',
+  'back.title': '⏮️ Voltar',
+  'loop.djOnly': '❌ Só DJs podem mudar o modo de repetição',
+  'loop.nothingPlaying': '❌ Nada está tocando',
+  'loop.timesTrackOnly': '❌ `times` só funciona ao repetir esta música',
+  'loop.trackTimes': 'Tocando esta música mais {count} vez(es), depois seguindo em frente',
+  'shuffle.done': '🔀 {count} músicas embaralhadas. Músicas ainda sendo importadas entram depois delas',
+  'dedupe.none': '✨ Nenhuma música está duas vezes na fila',
+  'dedupe.done': '🧹 {count} música(s) duplicada(s) removida(s), mantendo a primeira de cada',
+  'queue.findNone': '🔍 Nenhuma música na fila corresponde a **{query}**',
+  'queue.findTitle': '🔍 {count} música(s) na fila para "{query}"',
+  'queue.findFooter': 'Use a posição ou o ID com /move ou /remove',
+  'remove.done': '🗑️ **{title}** (`{id}`) removida da posição {position}',
+  'move.badPosition': '❌ A nova posição precisa estar entre 1 e {size}',
+  'move.done': '↕️ **{title}** (`{id}`) movida para a posição {position}',
+  'volume.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para mudar o volume',
+  'volume.title': '🔊 Volume Ajustado para {volume}%',
+  'volume.everywhere': 'Vale para tudo que tocar neste servidor',
+  'volume.personal': 'Tocando a {volume}% porque quem pediu está ouvindo sozinho com o próprio /myvolume',
+  'grab.title': '💾 Música Salva',
+  'grab.position': '⏱️ Posição',
+  'grab.server': '🏠 Servidor',
+  'grab.unknownServer': 'Servidor desconhecido',
+  'grab.sent': '📬 Enviei a música atual nas suas DMs',
+  'grab.dmFailed': '❌ Não consegui te mandar DM. Verifique se as mensagens diretas de membros do servidor estão ativadas.',
+
+  // /playlist
+  'playlist.songNotFound': '❌ Não encontrei uma música no YouTube para **{query}**',
+  'playlist.added': '✅ **{track}** adicionada a **{name}** (#{position})',
+  'playlist.removed': '🗑️ **{track}** removida de **{name}**',
+  'playlist.isEmpty': '**{name}** está vazia',
+  'playlist.allBlocked': 'Todas as músicas de **{name}** estão bloqueadas neste servidor',
+  'playlist.allQueued': 'Todas as músicas de **{name}** já estão na fila',
+  'playlist.queued': '✅ Playlist na Fila',
+  'playlist.deleted': '🗑️ Playlist **{name}** apagada',
+  'playlist.yours': '📚 Suas Playlists',
+  'playlist.noneYet': 'Você ainda não tem playlists. Crie uma com `/playlist create`.',
+  'playlist.emptyHint': 'Esta playlist está vazia. Adicione músicas com `/playlist add`.',
+  'playlist.createTitle': 'Nova playlist: {name}',
+  'playlist.createLabel': 'Músicas, uma por linha (até {max})',
+  'playlist.createPlaceholder': 'Links do YouTube ou nomes de músicas. Deixe vazio para começar com uma playlist vazia',
+  'playlist.createOverflow': 'só as primeiras {max} são adicionadas aqui; use /import para o resto',
+  'playlist.createdWith': '**{name}** começa com {songs}',
+  'playlist.created': '✅ Playlist Criada',
+  'playlist.createdBy': 'Criada por {user}',
+
+  // /import
+  'import.nothingGiven': '❌ Anexe um arquivo de playlist ou cole alguns links',
+  'import.joinVoiceFirst': '❌ Você precisa estar em um canal de voz para importar para a fila!',
+  'import.fileTooBig': '❌ Arquivos de playlist podem ter no máximo {kb} KB',
+  'import.unreadable': '❌ Não consegui ler a playlist: {reason}',
+  'import.noSongs': '❌ Nenhuma música encontrada para importar',
+  'import.tooMany': '❌ Importações são limitadas a {max} músicas ({count} encontradas)',
+  'import.resolving': '⏳ Buscando {count} músicas...',
+  'import.intoQueue': 'a fila',
+  'import.intoPlaylist': 'a playlist **{name}**',
+  'import.cleared': '🛑 A fila foi limpa durante a importação, então nada foi adicionado',
+  'import.summary': '**{imported}** de {total} músicas importadas para {destination}',
+  'import.skipped': '({skipped} ignoradas)',
+  'import.failed': '❌ Falharam ({count})',
+  'import.finished': '📥 Importação Concluída',
+  'import.importedBy': 'Importado por {user}',
+
+  // /history
+  'history.noMatches': '🔍 Nada tocado aqui corresponde a **{text}**',
+  'history.plays': '{count} reprodução(ões)',
+  'history.lastPlayed': 'última {time}',
+  'history.title': '🔍 Histórico com "{text}"',
+  'history.footer': 'Coloque uma na fila com /history play <número>',
+  'history.searchFirst': '❌ Pesquise com `/history search` primeiro',
+  'history.noSuchResult': '❌ Sua última pesquisa teve só {count} resultado(s)',
+  'history.gone': '❌ **{track}** não está mais disponível',
+
+  // /blacklist
+  'blacklist.songBlocked': '❌ **{track}** está bloqueada neste servidor',
+  'blacklist.songBlockedReason': '❌ **{track}** está bloqueada neste servidor: {reason}',
+  'blacklist.title': '🚫 Lista de Bloqueio',
+  'blacklist.empty': 'Nada está bloqueado. Admins podem adicionar entradas com `/blacklist add`.',
+  'blacklist.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para mudar a lista de bloqueio',
+  'blacklist.invalidKeyword': '❌ Essa não é uma palavra-chave válida',
+  'blacklist.invalidId': '❌ Esse não é um link ou ID de {kind} válido',
+  'blacklist.alreadyBlocked': '❌ {entry} já está bloqueado',
+  'blacklist.full': '❌ Este servidor já tem {max} entradas na lista de bloqueio',
+  'blacklist.added': '🚫 {entry} bloqueado. Músicas que já estão na fila não são removidas',
+  'blacklist.notFound': '❌ Não há entrada de {kind} para `{value}` na lista de bloqueio',
+  'blacklist.removed': '🗑️ {entry} desbloqueado',
+
+  // /alias
+  'alias.title': '⭐ Atalhos de Músicas',
+  'alias.empty': 'Ainda não há atalhos. Admins podem adicionar um com `/alias add`.',
+  'alias.more': '…e mais {count}',
+  'alias.footer': 'Use o nome de um atalho com /play',
+  'alias.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para mudar os atalhos',
+  'alias.badName': '❌ Nomes de atalhos precisam ser palavras simples, não links',
+  'alias.full': '❌ Este servidor já tem {max} atalhos',
+  'alias.added': '⭐ `/play {name}` agora toca {url}',
+  'alias.notFound': '❌ Não existe um atalho chamado **{name}**',
+  'alias.removed': '🗑️ Atalho **{name}** removido',
+
+  // /top
+  'top.week': 'Esta Semana',
+  'top.month': 'Este Mês',
+  'top.all': 'Desde Sempre',
+  'top.nothingPlayed': '📭 Nada foi tocado nesse período ainda',
+  'top.title': '🏆 Músicas Mais Tocadas — {period}',
+  'top.requesters': '🙋 Quem Mais Pediu Músicas',
+  'top.songs': '{count} música(s)',
+
+  // /export
+  'export.noPlaylists': '❌ Você não tem playlists para exportar',
+  'export.playlistsOne': '📤 1 playlist exportada',
+  'export.playlists': '📤 {count} playlists exportadas',
+  'export.playlistsSkipped': '(mais {count} não couberam em uma mensagem)',
+  'export.queueOne': '📤 1 música exportada. Carregue em outro lugar com `/import`.',
+  'export.queue': '📤 {count} músicas exportadas. Carregue em outro lugar com `/import`.',
+
+  // /feature
+  'feature.ownerOnly': '❌ Só o dono do bot pode gerenciar recursos experimentais',
+  'feature.title': '🧪 Recursos Experimentais',
+  'feature.enabled': '🧪 **{feature}** agora está ativado neste servidor',
+  'feature.disabled': '🧪 **{feature}** agora está desativado neste servidor',
+
+  // /insights
+  'insights.stillLoading': '{count} música(s) ainda carregando',
+  'insights.moreRequesters': '…e mais {count}',
+  'insights.title': '📈 Resumo da Fila',
+  'insights.songs': 'Músicas',
+  'insights.runtime': 'Duração Total',
+  'insights.duplicates': 'Repetidas',
+  'insights.requesters': 'Quem Pediu',
+  'insights.sources': 'Fontes',
+  'insights.includesCurrent': 'Inclui a música tocando agora',
+  'insights.upcoming': 'Próximas músicas',
+  'insights.longest': 'Mais Longa',
+  'insights.shortest': 'Mais Curta',
+
+  // /chapter
+  'chapter.none': '❌ **{track}** não tem capítulos',
+  'chapter.last': '❌ Este é o último capítulo',
+  'chapter.onlyCount': '❌ Só existem {count} capítulos',
+  'chapter.jumped': '📖 Pulou para o capítulo {chapter}',
+
+  // /stats
+  'stats.nothingYet': 'Nada ainda',
+  'stats.userTitle': '📊 Estatísticas de {user}',
+  'stats.songsRequested': '🎵 Músicas Pedidas',
+  'stats.timeListening': '🎧 Tempo Ouvindo',
+  'stats.mostRequested': '🔥 Mais Pedidas',
+  'stats.serverTitle': '📊 Estatísticas do Servidor',
+  'stats.songsPlayed': '🎵 Músicas Tocadas',
+  'stats.musicPlayed': '🎧 Tempo de Música',
+  'stats.mostPlayed': '🔥 Mais Tocadas',
+  'stats.topRequesters': '🙋 Quem Mais Pediu',
+  'stats.nobodyYet': 'Ninguém ainda',
+  'stats.noRecentRequests': 'nenhuma requisição recente',
+  'stats.successRate': '{rate}% de {attempts} requisição(ões)',
+  'stats.lastFailure': 'Última falha {time}: `{error}`',
+  'stats.backendsTitle': '📊 Saúde dos Backends de Stream',
+  'stats.backendsFooter': 'Com base nos últimos 30 minutos · os backends são tentados nesta ordem',
+
+  // /style
+  'style.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para mudar o estilo de exibição',
+  'style.changed': '🎨 O player e a fila agora usam o estilo **{style}**: {description}',
+  'style.classic': 'embeds com capa e barra de progresso',
+  'style.compact': 'texto simples, uma linha por música',
+  'style.card': 'uma imagem gerada do que está tocando',
+
+  // Audio filters
+  'nightcore.on': '🌙 Nightcore Ligado',
+  'nightcore.off': '🌙 Nightcore Desligado',
+  'nightcore.onDescription': 'Tocando com velocidade e tom em **{rate}x**',
+  'nightcore.offDescription': 'De volta à velocidade e ao tom normais',
+  'speed.title': '⏩ Velocidade Alterada',
+  'speed.normal': 'Tocando na velocidade normal',
+  'speed.changed': 'Tocando em velocidade **{rate}x**',
+  'normalize.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para mudar a normalização',
+  'normalize.on': '🔊 Normalização Ligada',
+  'normalize.off': '🔊 Normalização Desligada',
+  'normalize.onDescription': 'Músicas baixas e altas agora tocam em um volume uniforme',
+  'normalize.offDescription': 'As músicas tocam no volume original',
+
+  // /dashboard
+  'dashboard.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para usar o painel',
+  'dashboard.notRunning': '❌ O painel não está rodando; o dono do bot pode iniciá-lo definindo `WEB_PORT`',
+  'dashboard.link': '🖥️ [Abrir o painel]({url})\nO link é só seu e funciona por 12 horas; não o compartilhe',
+
+  // /lock and /unlock
+  'lock.djOnly': '❌ Só DJs e admins podem trancar a fila',
+  'lock.alreadyLocked': '🔒 A fila já está trancada. Use /unlock para abri-la de novo',
+  'lock.title': '🔒 Fila Trancada',
+  'lock.description': 'Só DJs e admins podem adicionar músicas até alguém usar /unlock. Todos ainda podem ver a fila',
+  'unlock.djOnly': '❌ Só DJs e admins podem destrancar a fila',
+  'unlock.notLocked': '🔓 A fila não está trancada',
+  'unlock.title': '🔓 Fila Destrancada',
+  'unlock.description': 'Todos podem adicionar músicas de novo',
+
+  // /datasaver and /idletimeout
+  'dataSaver.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para mudar o modo de economia de dados',
+  'dataSaver.on': '📶 Economia de Dados Ligada',
+  'dataSaver.off': '📶 Economia de Dados Desligada',
+  'dataSaver.onDescription': 'Áudio com bitrate menor será usado a partir da próxima música',
+  'dataSaver.offDescription': 'Áudio em qualidade máxima será usado a partir da próxima música',
+  'dataSaver.sessionSoFar': 'Nesta Sessão Até Agora',
+  'idleTimeout.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para mudar o tempo de inatividade',
+  'idleTimeout.set': '💤 Tempo de Inatividade em {minutes} Minuto(s)',
+  'idleTimeout.off': '💤 Tempo de Inatividade Desligado',
+  'idleTimeout.setDescription': 'Vou sair da voz depois de {minutes} minuto(s) com a fila vazia ou a música pausada, e aviso antes',
+  'idleTimeout.offDescription': 'Fico na voz até todos saírem ou alguém usar /leave',
+
+  // /admin
+  'admin.ownerOnly': '❌ Só o dono do bot pode rodar comandos de manutenção',
+  'admin.unmanaged': '❌ O yt-dlp vem do PATH neste servidor. Defina `YTDLP_MANAGED=true` para o bot gerenciá-lo',
+  'admin.installed': '✅ yt-dlp **{version}** instalado',
+  'admin.updatedFrom': '✅ yt-dlp **{version}** instalado (era {previous})',
+  'admin.upToDate': '✅ yt-dlp **{version}** já é a versão mais recente',
+  'admin.updateFailed': '❌ A atualização falhou: {reason}',
+  'admin.stillUsing': '. Ainda usando {version}',
+
+  // /karaoke
+  'karaoke.notOn': '❌ O karaokê não está ligado',
+  'karaoke.over': '🎤 O karaokê acabou',
+  'karaoke.cantPost': '❌ Não consigo postar letras neste canal',
+  'karaoke.started': '🎤 Hora do karaokê! As letras acompanham a música aqui embaixo até a fila acabar',
+  'karaoke.searching': '🔍 Procurando a letra...',
+  'karaoke.noLyrics': 'Sem letra sincronizada para esta; descanse a voz nessa 🎧',
+  'karaoke.footer': 'Karaokê · /karaoke stop para encerrar',
+
+  // /suggest
+  'suggest.disabled': '❌ Sugestões de pesquisa estão desativadas neste bot',
+  'suggest.none': '🔍 Nenhuma sugestão para **{partial}**',
+  'suggest.title': '🔍 Sugestões para "{partial}"',
+  'suggest.footer': 'Fonte: {source} • Use /play para colocar uma na fila',
+
+  // /voicepolicy
+  'voicePolicy.pause': 'Pausar até ser desmutado e explicar o motivo',
+  'voicePolicy.announce': 'Continuar tocando e postar um aviso',
+  'voicePolicy.disconnect': 'Sair da voz e limpar a fila',
+  'voicePolicy.title': '🔇 Quando Mutado ou Movido',
+  'voicePolicy.footer': 'Admins podem mudar isso com /voicepolicy <policy>',
+  'voicePolicy.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para mudar a política de voz',
+  'voicePolicy.changed': '🔇 Quando for mutado pelo servidor ou movido, agora vou: **{policy}**',
+
+  // /myvolume and /notifyme
+  'myVolume.none': '🔊 Você não tem um volume preferido, então vale o do servidor, {server}%',
+  'myVolume.current': '🔊 Suas músicas tocam em **{volume}%** quando você ouve sozinho (volume do servidor: {server}%)',
+  'myVolume.cleared': '🔊 Volume Preferido Removido',
+  'myVolume.set': '🔊 Volume Preferido em {volume}%',
+  'myVolume.clearedDescription': 'Suas músicas vão tocar no volume do servidor, {server}%',
+  'myVolume.setDescription': 'Usado nas músicas que você pediu enquanto ninguém mais está ouvindo; fora isso vale o do servidor, {server}%',
+  'notifyMe.dm': 'eu te mando uma DM',
+  'notifyMe.ping': 'eu te menciono no canal de anúncios (a menção é apagada na hora)',
+  'notifyMe.current': '🔔 Quando uma música que você pediu começa depois da anterior, {how}',
+  'notifyMe.currentOff': '🔕 Você não é avisado quando suas músicas começam. Use `/notifyme how:` para ativar',
+  'notifyMe.changed': '🔔 Quando uma música que você pediu começa depois da anterior, {how}. Isso vale em todos os servidores',
+  'notifyMe.off': '🔕 Você não será mais avisado quando suas músicas começarem',
+
+  // /intro
+  'intro.cleared': '🗑️ Sua intro foi removida',
+  'intro.none': '❌ Você não tem uma intro',
+  'intro.notAudio': '❌ Envie um arquivo de áudio ou vídeo',
+  'intro.tooBig': '❌ Intros podem ter no máximo {mb} MB',
+  'intro.unreadable': '❌ Não consegui ler nenhum áudio desse arquivo',
+  'intro.saved': '🎺 Intro salva. Ela toca por cima da música quando você entra no meu canal de voz, no máximo uma vez a cada {minutes} minutos',
+  'intro.disabledHere': 'As intros estão desligadas neste servidor; um admin pode ligá-las com `/settings intros`',
+
+  // /help
+  'help.playback': 'Reprodução',
+  'help.playbackDescription': 'Tocar, pausar, pular e ajustar o som',
+  'help.queue': 'Fila',
+  'help.queueDescription': 'Ver e reorganizar o que toca a seguir',
+  'help.playlists': 'Playlists',
+  'help.playlistsDescription': 'Salvar, importar e exportar músicas',
+  'help.stats': 'Estatísticas',
+  'help.statsDescription': 'O que este servidor ouve',
+  'help.server': 'Configurações do Servidor',
+  'help.serverDescription': 'Controles de admin e DJ',
+  'help.other': 'Outros',
+  'help.otherDescription': 'Todo o resto',
+  'help.footer': 'Página {page} de {pages} · <obrigatório> [opcional]',
+  'help.chooseCategory': 'Escolha uma categoria',
+
+  // /settings
+  'settings.languageTitle': '🌐 Idioma Definido',
+  'settings.languageDescription': 'As respostas e mensagens do player neste servidor agora estão em {language}',
+  'settings.adminOnly': '❌ Você precisa da permissão Gerenciar Servidor para mudar as configurações',
+  'settings.announceCleared': '📢 Os avisos vão para o canal do player, ou onde o último comando foi usado',
+  'settings.announceNoPermission': '❌ Não consigo postar em {channel}; primeiro me dê permissão para enviar mensagens lá',
+  'settings.announceTitle': '📢 Canal de Avisos Definido',
+  'settings.announceDescription': 'Avisos de música tocando, fila terminada e outros serão postados em {channel}. As respostas aos comandos continuam onde o comando foi usado',
+  'settings.requestOff': '🎙️ O modo de canal de pedidos está desligado; as mensagens não são mais mexidas',
+  'settings.requestUnavailable': '❌ Canais de pedidos estão desligados neste bot. Quem hospeda pode ligá-los com `REQUEST_CHANNELS=true` depois de ativar o intent de Message Content',
+  'settings.requestNoPermission': '❌ Primeiro preciso de permissão para enviar mensagens, adicionar reações, ler o histórico e gerenciar mensagens em {channel}',
+  'settings.requestTitle': '🎙️ Canal de Pedidos Definido',
+  'settings.requestDescription': 'Toda mensagem postada em {channel} agora entra na fila como no `/play`. Vou reagir com ✅ ou ❌ e apagar as mensagens depois de alguns segundos',
+  'settings.nowPlayingOn': '🎶 Vou postar uma mensagem quando cada música começar',
+  'settings.nowPlayingOff': '🔕 Sem mais mensagens de música tocando. Respostas aos comandos, o player e outros avisos continuam como estão',
+  'settings.autoDedupeOn': '🧹 Músicas que já estão na fila não serão adicionadas de novo. Use /dedupe para tirar as que já foram adicionadas antes',
+  'settings.autoDedupeOff': '🔁 Músicas podem entrar na fila mais de uma vez de novo',
+  'settings.ttsLavalink': '❌ Anúncios falados não funcionam com a reprodução pelo Lavalink',
+  'settings.ttsNoEngine': '❌ Nenhum motor de TTS está configurado; o dono do bot pode definir `PIPER_MODEL` ou `TTS_URL`',
+  'settings.ttsOn': '🗣️ Vou falar o título de cada música no canal de voz antes de ela começar, a partir da próxima',
+  'settings.ttsOff': '🔇 Sem mais anúncios falados',
+  'settings.introsLavalink': '❌ Intros de entrada não funcionam com a reprodução pelo Lavalink',
+  'settings.introsOn': '🎺 Membros que definiram uma com `/intro set` têm a intro tocada quando entram no meu canal de voz',
+  'settings.introsOff': '🔇 Intros de entrada desligadas',
+  'settings.stickyOn': '📌 Vou manter uma mensagem fixa de música tocando atualizada com o progresso, o tamanho da fila e os filtros, em vez de postar a cada música',
+  'settings.stickyOff': '📌 Player fixo desligado; a mensagem antiga fica, mas não é mais atualizada',
+  'settings.djSet': '🎧 Cargo de DJ Definido',
+  'settings.djCleared': '🎧 Cargo de DJ Removido',
+  'settings.djSetDescription': 'Só {role} e admins podem parar, embaralhar ou repetir a reprodução para todos. Os botões do player só funcionam para quem está no meu canal de voz',
+  'settings.djClearedDescription': 'Qualquer pessoa no meu canal de voz pode usar os controles do player',
+  'settings.followBot': '❌ Não posso seguir um bot',
+  'settings.following': '🚶 Seguindo um DJ',
+  'settings.notFollowing': '🚶 Sem Seguir Ninguém',
+  'settings.followingDescription': 'Quando {user} mudar de canal de voz enquanto eu estiver junto, eu mudo também e a música continua lá',
+  'settings.notFollowingDescription': 'Fico no meu canal de voz até alguém me mover ou usar /join',
+  'settings.badColor': '❌ Cores são códigos hex como `#5865F2`',
+  'settings.themeTitle': '🎨 Tema do Player',
+  'settings.themeColor': 'Cor',
+  'settings.themeEmoji': 'Emoji',
+  'settings.themeFooter': 'Rodapé',
+  'settings.themeNoFooter': 'Nenhum',
+  'settings.themeDescription': 'Vale para o player e o /queue a partir da próxima atualização',
+  'settings.apiKeyRevoked': '🔑 Chave da API revogada; pedidos usando ela agora são recusados',
+  'settings.apiKeyNoServer': '❌ A API REST não está rodando; o dono do bot pode ligá-la definindo `WEB_PORT`',
+  'settings.apiKeyCreated': '🔑 Sua nova chave da API, substituindo a anterior. Ela não será mostrada de novo, então guarde em um lugar seguro:\n`{key}`\nEnvie como `Authorization: Bearer <key>` para {url}',
+  'settings.limitsTitle': '📏 Limites da Fila',
+  'settings.limitPerUser': 'Por Pessoa',
+  'settings.limitTotal': 'Fila Inteira',
+  'settings.limitSongLength': 'Duração da Música',
+  'settings.limitLive': 'Transmissões ao Vivo',
+  'settings.limitSongs': '{count} músicas',
+  'settings.limitMinutes': '{count} minutos',
+  'settings.noLimit': 'Sem limite',
+  'settings.limitUntilEnd': 'Até acabarem',
+  'settings.limitsDescription': 'Admins e membros com o cargo de DJ podem passar desses limites. Transmissões ao vivo são cortadas para todos',
+  'settings.commandChannelsTitle': '🎵 Canais de Comandos',
+  'settings.commandChannelsSome': 'Comandos só funcionam em {channels}. Em outros canais as pessoas recebem um aviso privado apontando para eles; admins podem usar comandos em qualquer lugar',
+  'settings.commandChannelsAny': 'Comandos funcionam em qualquer canal',
+  'settings.unknownCommand': '❌ Não existe o comando `/{name}`',
+  'settings.alwaysOn': '❌ `/{name}` não pode ser desligado',
+  'settings.commandOn': '✅ /{name} Ligado',
+  'settings.commandOff': '🚫 /{name} Desligado',
+  'settings.disabledCommands': 'Desligados aqui: {commands}',
+  'settings.allCommandsOn': 'Todos os comandos estão ligados',
+  'settings.commandOffChoice': '{name} (desligado)',
+};
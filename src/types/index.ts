@@ -1,19 +1,225 @@
+import type { Storage } from '../utils/storage';
+import type { SuggestionProvider } from '../utils/suggestionProvider';
+import type { PlaylistManager } from '../utils/playlistManager';
+import type { GuildSettingsManager } from '../utils/guildSettings';
+import type { UserPreferencesManager } from '../utils/userPreferences';
+import type { VoteTracker } from '../utils/votes';
+import type { ListeningStatsManager } from '../utils/listeningStats';
+import type { IntroManager } from '../utils/intros';
+import type { ExtractionRateLimiter } from '../utils/rateLimiter';
+
+/**
+ * Where a song's audio comes from, set by the extractor that looked it up, so
+ * playback, autoplay and the blacklist go by its kind instead of guessing
+ * from the link.
+ */
+export type TrackSource =
+  | { kind: 'youtube'; videoId: string }
+  | { kind: 'twitch' }
+  /** An internet radio station, always live, whose current song is read from its ICY metadata. */
+  | { kind: 'radio' };
+
 export interface Song {
   id: string;
   title: string;
+  /** The page to link to; playback goes by `source`. */
   url: string;
+  source: TrackSource;
   duration: number;
   thumbnail?: string;
   addedBy: string;
+  /** Set for songs queued from a flat playlist listing; full metadata is fetched shortly before they play. */
+  needsMetadata?: boolean;
+  /** Short ID assigned when queued (e.g. `kobe`), stable while other songs move around. */
+  queueId?: string;
+  /** The uploading channel, when known. */
+  channelId?: string;
+  channel?: string;
+  /** Performer and song name, cleaned up from the upload title when YouTube doesn't provide them. */
+  artist?: string;
+  track?: string;
+  /** The album, for YouTube Music tracks and songs queued from an album. */
+  album?: string;
+  /** Chapters from the video description, in order. */
+  chapters?: Chapter[];
+  /** Set when only part of the video is played, e.g. one chapter of an album upload; seconds into the video. */
+  clip?: { start: number; end: number };
+  /** A live stream: it has no length, can't be seeked, and plays until the broadcast ends. */
+  isLive?: boolean;
+  /** What the station says is on air, updated while it plays; not saved. */
+  streamTitle?: string;
+}
+
+export interface Chapter {
+  title: string;
+  /** Start and end, in seconds from the start of the song. */
+  start: number;
+  end: number;
+}
+
+export interface AudioFilters {
+  /** Playback rate; 1 is normal speed. */
+  speed: number;
+  /** Pitch multiplier; 1 is the original pitch. */
+  pitch: number;
 }
 
 export interface GuildQueue {
   songs: Song[];
+  history: Song[];
   playing: boolean;
   currentSong?: Song;
   voiceConnection?: any;
   audioPlayer?: any;
   playerMessageId?: string;
   playerChannelId?: string;
+  /** Where the last command was used; fallback for announcements when there's no player message. */
+  lastCommandChannelId?: string;
+  /** Set while paused because the bot was server-muted, so unmuting resumes playback. */
+  pausedByMute?: boolean;
+  /** The sticky player message, when the guild uses one. */
+  stickyChannelId?: string;
+  stickyMessageId?: string;
+  /** Set once the sticky player shows that nothing is playing, so it isn't edited again until a song starts. */
+  stickyIdle?: boolean;
+  /** Channel the bot is moving itself to (e.g. /join), so the move isn't treated as someone dragging it. */
+  selfMoveChannelId?: string;
   songStartTime?: number;
+  /** Song position, in seconds, at `songStartTime`. Non-zero after seeking or changing filters. */
+  songStartOffset?: number;
+  /** Song seconds played per real second; changed by speed filters. */
+  playbackRate?: number;
+  loopMode?: LoopMode;
+  /** Replays left before `track` loop mode turns itself off; unset repeats until the mode is changed. */
+  loopsLeft?: number;
+}
+
+/** What happens when a song ends: move on, play it again, or send it to the back of the queue. */
+export type LoopMode = 'off' | 'track' | 'queue';
+
+export interface Playlist {
+  name: string;
+  ownerId: string;
+  songs: Song[];
+  createdAt: number;
+}
+
+/** What to do when the bot is server-muted mid-song. Being moved to another channel never interrupts playback. */
+export type VoiceInterruptionPolicy = 'pause' | 'announce' | 'disconnect';
+
+/** How the player and queue are displayed; see utils/renderers. */
+export type RendererStyle = 'classic' | 'compact' | 'card';
+
+/** Emoji on the player and queue: the usual colorful ones, plain symbols, or none at all. */
+export type EmojiSet = 'classic' | 'minimal' | 'none';
+
+/** How the player and queue embeds look; see utils/embedTheme. */
+export interface EmbedTheme {
+  /** Accent color, as `#rrggbb`. */
+  color: string;
+  /** Shown in the player and queue footers, ahead of their own notes. */
+  footerText?: string;
+  emojiSet: EmojiSet;
+}
+
+/** Language for replies and player messages; see src/locales. */
+export type Locale = 'en' | 'pt';
+
+/** Experimental subsystems that stay off until the bot owner enables them for a guild. */
+export type FeatureFlag = 'filters' | 'autoplay' | 'dashboard';
+
+export interface GuildSettings {
+  /** Shortcut name -> URL, usable anywhere `/play` accepts a song. */
+  aliases: Record<string, string>;
+  features: Partial<Record<FeatureFlag, boolean>>;
+  /** Apply EBU R128 loudness normalization so tracks play at a consistent level. */
+  normalizeLoudness: boolean;
+  /** Stream low-bitrate formats for hosts on metered connections. */
+  dataSaver: boolean;
+  voiceInterruptionPolicy: VoiceInterruptionPolicy;
+  renderer: RendererStyle;
+  language: Locale;
+  theme: EmbedTheme;
+  /** Playback volume in percent, used unless a listener's own preference applies. */
+  volume: number;
+  /** Minutes with an empty queue or paused music before leaving voice; 0 stays indefinitely. */
+  idleTimeoutMinutes: number;
+  /** Post a message when the next song starts on its own; command replies are unaffected. */
+  announceNowPlaying: boolean;
+  /** Most upcoming songs one member may have queued; 0 for no limit. DJs and admins are exempt. */
+  maxSongsPerUser: number;
+  /** Most upcoming songs in the queue; 0 for no limit. DJs and admins are exempt. */
+  maxQueueSize: number;
+  /** Longest song that can be queued, in minutes; 0 for no limit. DJs and admins are exempt. */
+  maxSongMinutes: number;
+  /** Minutes a live stream plays before the queue moves on; 0 plays it until the broadcast ends. Applies to everyone. */
+  maxLiveMinutes: number;
+  /** Songs that can't be queued here, whoever asks. */
+  blacklist: BlacklistEntry[];
+  /** While set, only DJs and admins can add songs; everyone can still see the queue. */
+  queueLocked: boolean;
+  /** Leave out songs that are already queued when adding, for everyone including DJs. */
+  autoDedupe: boolean;
+  /** Role allowed to control playback for everyone; unset lets anyone. */
+  djRoleId?: string;
+  /** Member the bot moves with when they switch voice channels while it's with them. */
+  followUserId?: string;
+  /** Say "Now playing" and the title in voice before each song; needs a TTS engine and local playback. */
+  ttsAnnouncements: boolean;
+  /** Play members' intro clips over the music when they join the bot's voice channel; needs local playback. */
+  joinIntros: boolean;
+  /** Keep one self-updating, pinned now-playing message instead of a message per song. */
+  stickyPlayer: boolean;
+  /** Commands turned off for this server. */
+  disabledCommands: string[];
+  /** Text channels where commands can be used; empty allows any. Admins can use commands anywhere. */
+  allowedChannelIds: string[];
+  /** Text channel for messages the bot posts on its own; unset uses the player's or last command's channel. */
+  announceChannelId?: string;
+  /** Text channel where any message is a song request; unset turns request-channel mode off. */
+  requestChannelId?: string;
+  /** Key for the REST API; only its hash is kept. */
+  apiKey?: ApiKey;
+}
+
+export interface ApiKey {
+  /** SHA-256 of the key, hex-encoded. */
+  hash: string;
+  /** The admin who made it; songs queued through the API are added as them unless a request names someone. */
+  createdBy: string;
+}
+
+/** What a blacklist entry matches: a video ID, a channel ID or name, or a word or phrase in the title. */
+export type BlacklistKind = 'video' | 'channel' | 'keyword';
+
+export interface BlacklistEntry {
+  kind: BlacklistKind;
+  /** Video ID, channel ID or lowercase channel name, or lowercase keyword. */
+  value: string;
+  reason?: string;
+  addedBy: string;
+}
+
+/** Personal settings that follow a user across servers. */
+export interface UserPreferences {
+  /** Preferred volume in percent, applied when they listen to their own songs alone. */
+  volume?: number;
+  /** How to tell them a song they requested has started; unset doesn't. */
+  startNotification?: StartNotification;
+}
+
+/** A DM, or a mention in the announcement channel that's deleted straight away. */
+export type StartNotification = 'dm' | 'ping';
+
+/** Shared bot-wide services handed to every command alongside the queue and player. */
+export interface BotServices {
+  storage: Storage;
+  suggestionProvider: SuggestionProvider;
+  playlistManager: PlaylistManager;
+  guildSettings: GuildSettingsManager;
+  userPreferences: UserPreferencesManager;
+  votes: VoteTracker;
+  listeningStats: ListeningStatsManager;
+  intros: IntroManager;
+  rateLimiter: ExtractionRateLimiter;
 }
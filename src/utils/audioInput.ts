@@ -0,0 +1,121 @@
+import { ChildProcess } from 'child_process';
+import { Readable } from 'stream';
+import { Song } from '../types/index';
+import { StreamOptions, audioFormatSelector, resolveStream } from './streamBackends';
+import { execYtDlp, spawnYtDlp } from './ytDlp';
+import { getProxyUrl, isHttpProxy } from './proxy';
+import { tempFiles } from './tempFiles';
+import { withRetry } from './retry';
+
+const DOWNLOAD_TIMEOUT_MS = 10 * 60_000;
+
+/**
+ * How audio reaches ffmpeg:
+ * - `direct`: resolve a stream URL and let ffmpeg fetch it (seeks with HTTP ranges);
+ * - `pipe`: yt-dlp downloads and writes to ffmpeg's stdin, for when direct URLs get throttled;
+ * - `download`: fetch the whole file first, then play it. Slow to start, but
+ *   survives flaky connections; an opt-in fallback.
+ */
+export type PlaybackMode = 'direct' | 'pipe' | 'download';
+
+export const PLAYBACK_MODES: PlaybackMode[] = ['direct', 'pipe', 'download'];
+
+export const PLAYBACK_MODE: PlaybackMode = PLAYBACK_MODES.includes(process.env.PLAYBACK_MODE as PlaybackMode)
+  ? (process.env.PLAYBACK_MODE as PlaybackMode)
+  : 'direct';
+
+export interface AudioInput {
+  /** What ffmpeg reads with `-i`: a URL, a file path or `pipe:0`. */
+  path: string;
+  /** Proxy ffmpeg should fetch `path` through, when it's a URL. */
+  httpProxy?: string;
+  /** Process writing the audio to ffmpeg's stdin, in `pipe` mode. */
+  source?: ChildProcess;
+  /** Audio the bot fetches itself and writes to ffmpeg's stdin, e.g. a radio station. */
+  stream?: Readable;
+  /**
+   * Source bitrate for estimating download size from output size. Unset when
+   * downloaded bytes were already counted through `onDownloaded`.
+   */
+  sourceBitrateKbps?: number;
+  /** Releases anything held for this input, e.g. a downloaded file. */
+  release(): void;
+}
+
+export interface AudioInputOptions extends StreamOptions {
+  /** Called with the size of each downloaded chunk when it can be measured. */
+  onDownloaded?: (bytes: number) => void;
+}
+
+export async function openAudioInput(
+  song: Song,
+  guildId: string,
+  options: AudioInputOptions = {},
+  mode: PlaybackMode = PLAYBACK_MODE
+): Promise<AudioInput> {
+  // Stream URLs are tied to the IP that resolved them, so ffmpeg must use the same
+  // proxy; it can't talk to SOCKS proxies, so yt-dlp downloads instead
+  const proxy = getProxyUrl(guildId);
+  if (mode === 'direct' && proxy && !isHttpProxy(proxy)) {
+    mode = 'pipe';
+  }
+  // A live stream's download never finishes
+  if (mode === 'download' && song.isLive) {
+    mode = 'pipe';
+  }
+  options = { ...options, live: song.isLive, source: song.source };
+
+  if (mode === 'pipe') {
+    return openPipeInput(song, guildId, options);
+  }
+  if (mode === 'download') {
+    return openDownloadInput(song, guildId, options);
+  }
+
+  const stream = await resolveStream(song.url, guildId, options);
+  return { path: stream.url, httpProxy: proxy, sourceBitrateKbps: stream.bitrateKbps, release: () => undefined };
+}
+
+function openPipeInput(song: Song, guildId: string, options: AudioInputOptions): AudioInput {
+  const ytDlp = spawnYtDlp(['-f', audioFormatSelector(options), '-o', '-', '--no-playlist', '--quiet', song.url], {
+    stdio: ['ignore', 'pipe', 'pipe'],
+    guildId,
+  });
+
+  ytDlp.stdout?.on('data', (chunk: Buffer) => options.onDownloaded?.(chunk.length));
+  ytDlp.stderr?.on('data', (chunk) => {
+    const msg = chunk.toString().trim();
+    if (msg) console.error('[yt-dlp]', msg);
+  });
+  ytDlp.on('error', (error) => console.error('Error starting yt-dlp:', error));
+
+  return {
+    path: 'pipe:0',
+    source: ytDlp,
+    release: () => {
+      if (!ytDlp.killed) ytDlp.kill('SIGKILL');
+    },
+  };
+}
+
+async function openDownloadInput(song: Song, guildId: string, options: AudioInputOptions): Promise<AudioInput> {
+  return withRetry(`download of ${song.url}`, async (attempt) => {
+    const file = tempFiles.create('audio');
+
+    try {
+      // yt-dlp skips formats bigger than this, which commit() reports as over quota
+      const maxSize = String(tempFiles.getFreeBytes());
+      await execYtDlp(
+        ['-f', audioFormatSelector(options, attempt), '-o', file.path, '--max-filesize', maxSize, '--no-playlist', '--quiet', '--no-part', song.url],
+        // Whole songs take longer than the default lookup timeout on slow links
+        { guildId, timeout: DOWNLOAD_TIMEOUT_MS }
+      );
+      options.onDownloaded?.(tempFiles.commit(file));
+    } catch (error) {
+      file.release();
+      throw error;
+    }
+
+    return { path: file.path, release: file.release };
+  });
+}
@@ -0,0 +1,99 @@
+import { BlacklistEntry, Song } from '../types/index';
+import { GuildListeningStats } from './listeningStats';
+import { YouTubeUtil } from './youtubeUtil';
+import { extractVideoId } from './frontendApis';
+import { findBlacklistMatch } from './blacklist';
+
+// Songs among the guild's latest plays are skipped, so autoplay doesn't go round in circles
+const RECENT_PLAYS_AVOIDED = 50;
+// The guild's own favourites considered alongside YouTube's suggestions
+const HISTORY_CANDIDATES = 25;
+// Candidates looked up before giving up, when the best ones fail or are blacklisted
+const MAX_LOOKUPS = 3;
+
+interface Candidate {
+  url: string;
+  channel?: string;
+  score: number;
+}
+
+/**
+ * How much the guild plays each uploader, from 1 for its most played down
+ * towards 0, keyed by lowercase channel name.
+ */
+export function channelAffinity(stats: GuildListeningStats): Map<string, number> {
+  const plays = new Map<string, number>();
+  for (const song of Object.values(stats.songs)) {
+    if (!song.channel) continue;
+    const channel = song.channel.toLowerCase();
+    plays.set(channel, (plays.get(channel) ?? 0) + song.plays);
+  }
+
+  const most = Math.max(0, ...plays.values());
+  return new Map([...plays].map(([channel, count]): [string, number] => [channel, count / most]));
+}
+
+/**
+ * Picks what to play after `seed` once the queue runs out. Candidates are
+ * YouTube's mix for the seed and the guild's own most played songs; each is
+ * scored by its place in the mix and by how much the guild plays its
+ * uploader, so autoplay leans towards what the server actually listens to.
+ * Anything played recently or blacklisted is skipped.
+ */
+export async function pickAutoplaySong(
+  seed: Song,
+  guildId: string,
+  stats: GuildListeningStats,
+  blacklist: BlacklistEntry[],
+  botUserId: string
+): Promise<Song | undefined> {
+  const affinity = channelAffinity(stats);
+  const heard = new Set(
+    [seed.url, ...stats.recentPlays.slice(-RECENT_PLAYS_AVOIDED).map((play) => play.url)].map((url) => extractVideoId(url) ?? url)
+  );
+  const isFresh = (url: string) => !heard.has(extractVideoId(url) ?? url);
+  const affinityOf = (channel?: string) => (channel ? (affinity.get(channel.toLowerCase()) ?? 0) : 0);
+
+  const candidates: Candidate[] = [];
+  const seedId = seed.source.kind === 'youtube' ? seed.source.videoId : undefined;
+  if (seedId) {
+    const mix = await YouTubeUtil.fetchPlaylistEntries(
+      `https://www.youtube.com/watch?v=${seedId}&list=RD${seedId}`,
+      botUserId,
+      guildId
+    );
+    mix.forEach((song, index) => {
+      // YouTube's order still counts for most of the score
+      candidates.push({ url: song.url, channel: song.channel, score: 1 - index / (mix.length * 2) + affinityOf(song.channel) });
+    });
+  }
+
+  const favourites = Object.values(stats.songs)
+    .sort((a, b) => b.plays - a.plays)
+    .slice(0, HISTORY_CANDIDATES);
+  for (const song of favourites) {
+    candidates.push({ url: song.url, channel: song.channel, score: 0.5 + affinityOf(song.channel) });
+  }
+
+  // A little jitter, so the same seed doesn't always lead to the same song
+  const ranked = candidates
+    .filter((candidate) => isFresh(candidate.url))
+    .map((candidate) => ({ ...candidate, score: candidate.score + Math.random() * 0.2 }))
+    .sort((a, b) => b.score - a.score);
+
+  const tried = new Set<string>();
+  for (const candidate of ranked) {
+    const key = extractVideoId(candidate.url) ?? candidate.url;
+    if (tried.has(key)) continue;
+    if (tried.size >= MAX_LOOKUPS) break;
+    tried.add(key);
+
+    try {
+      const song = await YouTubeUtil.fetchSongInfo(candidate.url, botUserId, guildId, 'prefetch');
+      if (!findBlacklistMatch(song, blacklist)) return song;
+    } catch (error) {
+      console.warn(`[AUTOPLAY] Couldn't look up ${candidate.url}:`, error instanceof Error ? error.message : error);
+    }
+  }
+  return undefined;
+}
@@ -0,0 +1,81 @@
+// Outcomes older than this no longer affect a backend's score
+const HEALTH_WINDOW_MS = 30 * 60 * 1000;
+const MAX_OUTCOMES_PER_BACKEND = 100;
+
+interface Outcome {
+  at: number;
+  ok: boolean;
+}
+
+export interface BackendHealth {
+  name: string;
+  attempts: number;
+  failures: number;
+  /** Estimated success probability between 0 and 1. */
+  score: number;
+  lastFailureAt?: number;
+  lastError?: string;
+}
+
+/**
+ * Tracks recent successes and failures per extraction backend so callers can
+ * try the healthiest one first.
+ */
+export class BackendHealthTracker {
+  private readonly outcomes: Map<string, Outcome[]> = new Map();
+  private readonly lastErrors: Map<string, { at: number; message: string }> = new Map();
+
+  recordSuccess(backend: string): void {
+    this.record(backend, true);
+  }
+
+  recordFailure(backend: string, error: unknown): void {
+    this.record(backend, false);
+    this.lastErrors.set(backend, {
+      at: Date.now(),
+      message: error instanceof Error ? error.message : String(error),
+    });
+  }
+
+  getHealth(backend: string): BackendHealth {
+    const recent = this.getRecentOutcomes(backend);
+    const failures = recent.filter((outcome) => !outcome.ok).length;
+    const lastError = this.lastErrors.get(backend);
+
+    return {
+      name: backend,
+      attempts: recent.length,
+      failures,
+      // Laplace smoothing: an untried backend scores 0.5 rather than 0 or 1
+      score: (recent.length - failures + 1) / (recent.length + 2),
+      lastFailureAt: lastError?.at,
+      lastError: lastError?.message,
+    };
+  }
+
+  /** Orders backends healthiest first; ties keep the given (preference) order. */
+  rank<T extends { name: string }>(backends: T[]): T[] {
+    return backends
+      .map((backend, index) => ({ backend, index, score: this.getHealth(backend.name).score }))
+      .sort((a, b) => b.score - a.score || a.index - b.index)
+      .map(({ backend }) => backend);
+  }
+
+  private record(backend: string, ok: boolean): void {
+    const outcomes = this.getRecentOutcomes(backend);
+    outcomes.push({ at: Date.now(), ok });
+    if (outcomes.length > MAX_OUTCOMES_PER_BACKEND) {
+      outcomes.shift();
+    }
+    this.outcomes.set(backend, outcomes);
+  }
+
+  private getRecentOutcomes(backend: string): Outcome[] {
+    const cutoff = Date.now() - HEALTH_WINDOW_MS;
+    const recent = (this.outcomes.get(backend) ?? []).filter((outcome) => outcome.at >= cutoff);
+    this.outcomes.set(backend, recent);
+    return recent;
+  }
+}
+
+export const backendHealth = new BackendHealthTracker();
@@ -0,0 +1,26 @@
+import { Translator } from './i18n';
+
+export interface BandwidthUsage {
+  /**
+   * Opus audio encoded for Discord. Counted as ffmpeg outputs it, so it also
+   * includes what was still buffered (up to 1 MB) when a song was skipped or stopped.
+   */
+  sentBytes: number;
+  /** Source audio pulled from YouTube. Estimated from the source and output bitrates. */
+  downloadedBytes: number;
+}
+
+export function formatBytes(bytes: number): string {
+  const units = ['B', 'KB', 'MB', 'GB'];
+  let value = bytes;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit++;
+  }
+  return `${value.toFixed(unit === 0 ? 0 : 1)} ${units[unit]}`;
+}
+
+export function describeBandwidth(usage: BandwidthUsage, t: Translator): string {
+  return t('common.bandwidth', { downloaded: formatBytes(usage.downloadedBytes), sent: formatBytes(usage.sentBytes) });
+}
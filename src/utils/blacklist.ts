@@ -0,0 +1,67 @@
+import { BlacklistEntry, BlacklistKind, Song } from '../types/index';
+import { extractVideoId } from './frontendApis';
+import { Translator } from './i18n';
+
+const VIDEO_ID_PATTERN = /^[\w-]{11}$/;
+const CHANNEL_ID_PATTERN = /^UC[\w-]{22}$/;
+const CHANNEL_URL_PATTERN = /youtube\.com\/channel\/(UC[\w-]{22})/;
+
+/**
+ * Turns what an admin typed into the value stored for an entry: video URLs
+ * become IDs, channel URLs become channel IDs, and names and keywords are
+ * lowercased. Returns undefined when a video entry isn't a video.
+ */
+export function normalizeBlacklistValue(kind: BlacklistKind, input: string): string | undefined {
+  const trimmed = input.trim();
+  switch (kind) {
+    case 'video': {
+      const videoId = extractVideoId(trimmed) ?? trimmed;
+      return VIDEO_ID_PATTERN.test(videoId) ? videoId : undefined;
+    }
+    case 'channel': {
+      const channelId = trimmed.match(CHANNEL_URL_PATTERN)?.[1];
+      if (channelId) return channelId;
+      if (CHANNEL_ID_PATTERN.test(trimmed)) return trimmed;
+      return trimmed.toLowerCase() || undefined;
+    }
+    case 'keyword':
+      return trimmed.toLowerCase() || undefined;
+  }
+}
+
+/** The first entry that blocks `song`. Songs without channel details can only match by video or keyword. */
+export function findBlacklistMatch(song: Song, blacklist: BlacklistEntry[]): BlacklistEntry | undefined {
+  const title = song.title.toLowerCase();
+  return blacklist.find((entry) => {
+    switch (entry.kind) {
+      case 'video':
+        return song.id === entry.value || (song.source.kind === 'youtube' && song.source.videoId === entry.value);
+      case 'channel':
+        return song.channelId === entry.value || song.channel?.toLowerCase() === entry.value;
+      case 'keyword':
+        return title.includes(entry.value);
+    }
+  });
+}
+
+/** Splits `songs` into those that may be queued and those the blacklist blocks. */
+export function filterBlacklisted(songs: Song[], blacklist: BlacklistEntry[]): { allowed: Song[]; blocked: Song[] } {
+  const allowed: Song[] = [];
+  const blocked: Song[] = [];
+  for (const song of songs) {
+    (findBlacklistMatch(song, blacklist) ? blocked : allowed).push(song);
+  }
+  return { allowed, blocked };
+}
+
+export function describeBlacklistEntry(entry: BlacklistEntry): string {
+  const reason = entry.reason ? ` — ${entry.reason}` : '';
+  return `**${entry.kind}** \`${entry.value}\`${reason}`;
+}
+
+/** The reply when a song is refused, naming the entry's reason if it has one. */
+export function describeBlockedSong(song: Song, entry: BlacklistEntry, t: Translator): string {
+  return entry.reason
+    ? t('blacklist.songBlockedReason', { track: song.title, reason: entry.reason })
+    : t('blacklist.songBlocked', { track: song.title });
+}
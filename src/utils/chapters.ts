@@ -0,0 +1,55 @@
+import { Chapter, Song } from '../types/index';
+import { parseTrackTitle } from './titleParser';
+
+/**
+ * Reads chapters from yt-dlp's `chapters` field (`start_time`/`end_time`) or
+ * from a stored song (`start`/`end`). Returns undefined when there are none.
+ */
+export function parseChapters(raw: unknown): Chapter[] | undefined {
+  if (!Array.isArray(raw)) return undefined;
+
+  const chapters = raw
+    .map((entry: any): Chapter | null => {
+      const start = Number(entry?.start_time ?? entry?.start);
+      const end = Number(entry?.end_time ?? entry?.end);
+      if (!Number.isFinite(start) || !Number.isFinite(end) || end <= start) return null;
+      return { title: String(entry.title || 'Untitled chapter'), start, end };
+    })
+    .filter((chapter): chapter is Chapter => chapter !== null)
+    .sort((a, b) => a.start - b.start);
+
+  return chapters.length > 0 ? chapters : undefined;
+}
+
+/** The chapter playing at `position` seconds, with its zero-based index. */
+export function getChapterAt(song: Song, position: number): { chapter: Chapter; index: number } | undefined {
+  const chapters = song.chapters ?? [];
+  for (let index = chapters.length - 1; index >= 0; index--) {
+    if (position >= chapters[index].start) {
+      return { chapter: chapters[index], index };
+    }
+  }
+  return undefined;
+}
+
+/** One song per chapter, each playing just its part of the video. */
+export function splitIntoChapters(song: Song): Song[] {
+  return (song.chapters ?? []).map((chapter) => {
+    // Chapters of an album upload usually name just the song, so the upload's artist carries over
+    const parsed = parseTrackTitle(chapter.title.replace(/^\d+\s*[.)-]\s*/, ''));
+    return {
+      ...song,
+      title: chapter.title,
+      artist: parsed.artist ?? song.artist,
+      track: parsed.title,
+      duration: Math.round(chapter.end - chapter.start),
+      chapters: undefined,
+      clip: { start: chapter.start, end: chapter.end },
+    };
+  });
+}
+
+export function formatChapter(song: Song, index: number): string {
+  const chapters = song.chapters ?? [];
+  return `${index + 1}/${chapters.length}: ${chapters[index].title}`;
+}
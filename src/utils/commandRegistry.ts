@@ -0,0 +1,34 @@
+import { APIApplicationCommandOption, ApplicationCommandType } from 'discord.js';
+import { FeatureFlag } from '../types/index';
+
+/** Commands that can't be turned off, so admins can always turn others back on. */
+export const ALWAYS_ENABLED_COMMANDS = ['settings'];
+
+/** A loaded slash command as Discord sees it, for /help and settings that refer to commands by name. */
+export interface RegisteredCommand {
+  name: string;
+  /** Slash command, or a right-click command on messages or users. */
+  type: ApplicationCommandType;
+  /** Empty for right-click commands, which Discord shows without one. */
+  description: string;
+  options: APIApplicationCommandOption[];
+  /** The feature flag a guild needs enabled to use it, if any. */
+  feature?: FeatureFlag;
+}
+
+let registeredCommands: RegisteredCommand[] = [];
+
+/** Called once the command files are loaded at startup. */
+export function setCommands(commands: RegisteredCommand[]): void {
+  registeredCommands = [...commands].sort((a, b) => a.name.localeCompare(b.name));
+}
+
+/** Every loaded slash command, sorted by name. */
+export function getCommands(): RegisteredCommand[] {
+  return registeredCommands;
+}
+
+/** Every loaded slash command's name, sorted. */
+export function getCommandNames(): string[] {
+  return registeredCommands.map((command) => command.name);
+}
@@ -0,0 +1,286 @@
+import * as crypto from 'crypto';
+import { GuildSettings } from '../types/index';
+import { GuildSettingsManager } from './guildSettings';
+import { MAX_IDLE_TIMEOUT_MINUTES } from './idleTracker';
+import { MusicPlayer } from './musicPlayer';
+import { applyVolume } from './playback';
+import { QueueManager } from './queueManager';
+import { MAX_VOLUME } from './userPreferences';
+import { HttpError, WebRequest, WebServer, getBearerToken } from './webServer';
+
+// A dashboard link keeps working for this long after /dashboard hands it out
+const SESSION_TTL_MS = 12 * 60 * 60 * 1000;
+
+interface DashboardSession {
+  guildId: string;
+  userId: string;
+  expiresAt: number;
+}
+
+/**
+ * Dashboard links: each token is tied to the admin who asked for it and their
+ * server. The page sends it with every request, so it can be reused until it
+ * expires after SESSION_TTL_MS; anyone holding the link can use it until then.
+ */
+export class DashboardSessions {
+  private readonly sessions = new Map<string, DashboardSession>();
+
+  create(guildId: string, userId: string): string {
+    this.removeExpired();
+    const token = crypto.randomBytes(24).toString('base64url');
+    this.sessions.set(token, { guildId, userId, expiresAt: Date.now() + SESSION_TTL_MS });
+    return token;
+  }
+
+  get(token: string): DashboardSession | undefined {
+    const session = this.sessions.get(token);
+    if (!session || session.expiresAt <= Date.now()) {
+      this.sessions.delete(token);
+      return undefined;
+    }
+    return session;
+  }
+
+  private removeExpired(): void {
+    const now = Date.now();
+    for (const [token, session] of this.sessions) {
+      if (session.expiresAt <= now) this.sessions.delete(token);
+    }
+  }
+}
+
+export const dashboardSessions = new DashboardSessions();
+
+/** The bot state the dashboard reads and changes. */
+export interface DashboardContext {
+  queueManager: QueueManager;
+  guildSettings: GuildSettingsManager;
+  getMusicPlayer(guildId: string): MusicPlayer;
+  /** Whether the user can still manage the server; checked on every request, not just when the link was made. */
+  isGuildAdmin(guildId: string, userId: string): Promise<boolean>;
+}
+
+type NumberSetting = 'volume' | 'idleTimeoutMinutes' | 'maxSongsPerUser' | 'maxQueueSize' | 'maxSongMinutes';
+type BooleanSetting = 'announceNowPlaying' | 'queueLocked';
+
+/** Settings the dashboard can change, with the same bounds as their commands. */
+const NUMBER_SETTINGS: Record<NumberSetting, { max: number }> = {
+  volume: { max: MAX_VOLUME },
+  idleTimeoutMinutes: { max: MAX_IDLE_TIMEOUT_MINUTES },
+  maxSongsPerUser: { max: Number.MAX_SAFE_INTEGER },
+  maxQueueSize: { max: Number.MAX_SAFE_INTEGER },
+  maxSongMinutes: { max: Number.MAX_SAFE_INTEGER },
+};
+const BOOLEAN_SETTINGS: BooleanSetting[] = ['announceNowPlaying', 'queueLocked'];
+
+function pickSettings(settings: GuildSettings): Pick<GuildSettings, NumberSetting | BooleanSetting> {
+  return {
+    volume: settings.volume,
+    idleTimeoutMinutes: settings.idleTimeoutMinutes,
+    maxSongsPerUser: settings.maxSongsPerUser,
+    maxQueueSize: settings.maxQueueSize,
+    maxSongMinutes: settings.maxSongMinutes,
+    announceNowPlaying: settings.announceNowPlaying,
+    queueLocked: settings.queueLocked,
+  };
+}
+
+/** Turns a settings change from the dashboard into a validated partial, or throws a 400. */
+function parseSettingsChanges(body: any): Partial<GuildSettings> {
+  if (!body || typeof body !== 'object') throw new HttpError(400, 'Expected an object of settings');
+
+  const changes: Partial<GuildSettings> = {};
+  for (const [key, value] of Object.entries(body)) {
+    if (key in NUMBER_SETTINGS) {
+      const { max } = NUMBER_SETTINGS[key as NumberSetting];
+      if (!Number.isInteger(value) || (value as number) < 0 || (value as number) > max) {
+        throw new HttpError(400, `${key} must be a whole number from 0 to ${max}`);
+      }
+      changes[key as NumberSetting] = value as number;
+    } else if (BOOLEAN_SETTINGS.includes(key as BooleanSetting)) {
+      if (typeof value !== 'boolean') throw new HttpError(400, `${key} must be true or false`);
+      changes[key as BooleanSetting] = value;
+    } else {
+      throw new HttpError(400, `${key} can't be changed from the dashboard`);
+    }
+  }
+  return changes;
+}
+
+/**
+ * Adds the dashboard page and the API it uses. Requests carry the token from
+ * /dashboard as a bearer token and only ever see that token's server.
+ */
+export function registerDashboardRoutes(server: WebServer, context: DashboardContext): void {
+  const { queueManager, guildSettings } = context;
+
+  async function authorize(request: WebRequest): Promise<string> {
+    const token = getBearerToken(request);
+    const session = token ? dashboardSessions.get(token) : undefined;
+    if (!session) throw new HttpError(401, 'This dashboard link has expired; use /dashboard for a new one');
+    if (!guildSettings.isFeatureEnabled(session.guildId, 'dashboard')) {
+      throw new HttpError(403, "The dashboard isn't enabled on this server");
+    }
+    if (!(await context.isGuildAdmin(session.guildId, session.userId))) {
+      throw new HttpError(403, 'You need the Manage Server permission to use the dashboard');
+    }
+    return session.guildId;
+  }
+
+  function findQueueIndex(guildId: string, queueId: unknown): number {
+    const index = typeof queueId === 'string' ? queueManager.findSongIndex(guildId, queueId) : -1;
+    if (index === -1) throw new HttpError(409, "That song isn't in the queue anymore");
+    return index;
+  }
+
+  server.route('GET', '/dashboard', () => ({ html: DASHBOARD_PAGE }));
+
+  server.route('GET', '/dashboard/api/state', async (request) => {
+    const guildId = await authorize(request);
+    const current = queueManager.getCurrentSong(guildId);
+    return {
+      body: {
+        nowPlaying: current
+          ? {
+              title: current.title,
+              url: current.url,
+              duration: current.duration,
+              elapsed: Math.floor(queueManager.getSongElapsedTime(guildId)),
+              paused: !context.getMusicPlayer(guildId).isPlaying(),
+            }
+          : null,
+        queue: queueManager.getFullQueue(guildId).map((song) => ({
+          queueId: song.queueId,
+          title: song.title,
+          url: song.url,
+          duration: song.duration,
+          addedBy: song.addedBy,
+        })),
+        settings: pickSettings(guildSettings.get(guildId)),
+      },
+    };
+  });
+
+  server.route('POST', '/dashboard/api/queue/move', async (request) => {
+    const guildId = await authorize(request);
+    const from = findQueueIndex(guildId, request.body?.queueId);
+    const to = request.body?.to;
+    if (!Number.isInteger(to) || !queueManager.moveSong(guildId, from, to)) {
+      throw new HttpError(400, `to must be a position from 0 to ${queueManager.getQueueSize(guildId) - 1}`);
+    }
+    return { status: 204 };
+  });
+
+  server.route('POST', '/dashboard/api/queue/remove', async (request) => {
+    const guildId = await authorize(request);
+    queueManager.removeSong(guildId, findQueueIndex(guildId, request.body?.queueId));
+    return { status: 204 };
+  });
+
+  server.route('PATCH', '/dashboard/api/settings', async (request) => {
+    const guildId = await authorize(request);
+    const changes = parseSettingsChanges(request.body);
+    const settings = guildSettings.update(guildId, changes);
+    if (changes.volume !== undefined) {
+      await applyVolume(guildId, queueManager, context.getMusicPlayer(guildId));
+    }
+    return { body: pickSettings(settings) };
+  });
+}
+
+// Self-contained so the bot serves it without a build step; the token stays in the URL fragment, out of server logs
+const DASHBOARD_PAGE = `<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>GoonBot Dashboard</title>
+<style>
+  body { font-family: system-ui, sans-serif; background: #121212; color: #eee; max-width: 760px; margin: 2rem auto; padding: 0 1rem; }
+  h1, h2 { color: #1DB954; }
+  li { display: flex; gap: .5rem; align-items: center; padding: .3rem 0; border-bottom: 1px solid #333; }
+  li span { flex: 1; }
+  button { background: #333; color: #eee; border: 0; border-radius: 4px; padding: .2rem .5rem; cursor: pointer; }
+  label { display: flex; justify-content: space-between; padding: .3rem 0; }
+  input[type=number] { width: 6rem; }
+  #error { color: #f66; }
+</style>
+</head>
+<body>
+<h1>🎵 GoonBot Dashboard</h1>
+<p id="error"></p>
+<h2>Now Playing</h2>
+<p id="now-playing">Nothing is playing</p>
+<h2>Queue</h2>
+<ol id="queue"></ol>
+<h2>Settings</h2>
+<form id="settings"></form>
+<script>
+const token = location.hash.slice(1);
+const labels = {
+  volume: 'Volume (%)', idleTimeoutMinutes: 'Idle timeout (minutes, 0 = never)',
+  maxSongsPerUser: 'Songs per member (0 = no limit)', maxQueueSize: 'Queue size (0 = no limit)',
+  maxSongMinutes: 'Longest song (minutes, 0 = no limit)', announceNowPlaying: 'Announce songs', queueLocked: 'Queue locked',
+};
+const formatTime = (s) => Math.floor(s / 60) + ':' + String(Math.floor(s % 60)).padStart(2, '0');
+
+async function api(method, path, body) {
+  const response = await fetch('/dashboard/api/' + path, {
+    method,
+    headers: { Authorization: 'Bearer ' + token, 'Content-Type': 'application/json' },
+    body: body === undefined ? undefined : JSON.stringify(body),
+  });
+  if (!response.ok) throw new Error((await response.json()).error);
+  return response.status === 204 ? undefined : response.json();
+}
+
+function showError(error) { document.getElementById('error').textContent = error ? '❌ ' + error.message : ''; }
+
+function render(state) {
+  const now = state.nowPlaying;
+  document.getElementById('now-playing').textContent = now
+    ? (now.paused ? '⏸️ ' : '▶️ ') + now.title + ' (' + formatTime(now.elapsed) + ' / ' + formatTime(now.duration) + ')'
+    : 'Nothing is playing';
+
+  const queue = document.getElementById('queue');
+  queue.replaceChildren(...state.queue.map((song, index) => {
+    const item = document.createElement('li');
+    const title = document.createElement('span');
+    title.textContent = song.title + ' (' + formatTime(song.duration) + ')';
+    const button = (text, action) => {
+      const element = document.createElement('button');
+      element.textContent = text;
+      element.onclick = () => action().then(refresh, showError);
+      return element;
+    };
+    item.append(
+      title,
+      button('⬆️', () => api('POST', 'queue/move', { queueId: song.queueId, to: Math.max(index - 1, 0) })),
+      button('⬇️', () => api('POST', 'queue/move', { queueId: song.queueId, to: Math.min(index + 1, state.queue.length - 1) })),
+      button('🗑️', () => api('POST', 'queue/remove', { queueId: song.queueId }))
+    );
+    return item;
+  }));
+
+  const form = document.getElementById('settings');
+  if (form.contains(document.activeElement)) return;
+  form.replaceChildren(...Object.entries(state.settings).map(([key, value]) => {
+    const label = document.createElement('label');
+    const input = document.createElement('input');
+    input.type = typeof value === 'boolean' ? 'checkbox' : 'number';
+    if (input.type === 'checkbox') input.checked = value; else { input.value = value; input.min = 0; }
+    input.onchange = () => api('PATCH', 'settings', { [key]: input.type === 'checkbox' ? input.checked : Number(input.value) })
+      .then(refresh, (error) => { showError(error); refresh(); });
+    label.append(labels[key] || key, input);
+    return label;
+  }));
+}
+
+function refresh() { return api('GET', 'state').then((state) => { showError(); render(state); }, showError); }
+
+refresh();
+setInterval(refresh, 5000);
+</script>
+</body>
+</html>
+`;
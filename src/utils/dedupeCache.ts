@@ -0,0 +1,31 @@
+/**
+ * Remembers recently seen keys for a short window. Used to drop requests that
+ * arrive twice, e.g. when Discord mobile double-sends a command.
+ */
+export class DedupeCache {
+  private readonly seenAt: Map<string, number> = new Map();
+
+  constructor(private readonly windowMs: number) {}
+
+  /** Returns true if the key was already seen within the window; otherwise records it. */
+  isDuplicate(key: string): boolean {
+    const now = Date.now();
+    this.prune(now);
+
+    const lastSeen = this.seenAt.get(key);
+    if (lastSeen !== undefined && now - lastSeen < this.windowMs) {
+      return true;
+    }
+
+    this.seenAt.set(key, now);
+    return false;
+  }
+
+  private prune(now: number): void {
+    for (const [key, seenAt] of this.seenAt) {
+      if (now - seenAt >= this.windowMs) {
+        this.seenAt.delete(key);
+      }
+    }
+  }
+}
@@ -0,0 +1,105 @@
+const API_BASE = 'https://api.deezer.com';
+const REQUEST_TIMEOUT_MS = 10_000;
+// The API's largest page
+const PAGE_SIZE = 100;
+
+const DEEZER_HOSTS = ['deezer.com', 'www.deezer.com'];
+// Share links redirect to a deezer.com page
+const SHORT_LINK_HOSTS = ['link.deezer.com', 'deezer.page.link'];
+
+export interface DeezerTrack {
+  title: string;
+  artist: string;
+  album?: string;
+  /** Seconds. */
+  duration: number;
+}
+
+export interface DeezerCollection {
+  kind: 'track' | 'playlist' | 'album';
+  /** The playlist or album title, or the track's own. */
+  name: string;
+  tracks: DeezerTrack[];
+}
+
+/** Whether the URL is a Deezer track, playlist or album page, or a share link to one. */
+export function isDeezerUrl(url: string): boolean {
+  try {
+    const parsed = new URL(url);
+    const host = parsed.hostname.toLowerCase();
+    if (SHORT_LINK_HOSTS.includes(host)) return true;
+    return DEEZER_HOSTS.includes(host) && parseDeezerPath(parsed.pathname) !== undefined;
+  } catch {
+    return false;
+  }
+}
+
+// e.g. `/track/3135556` or `/en/playlist/908622995`
+function parseDeezerPath(pathname: string): { kind: DeezerCollection['kind']; id: string } | undefined {
+  const match = /^\/(?:[a-z]{2}(?:-[a-z]{2})?\/)?(track|playlist|album)\/(\d+)/i.exec(pathname);
+  return match ? { kind: match[1].toLowerCase() as DeezerCollection['kind'], id: match[2] } : undefined;
+}
+
+async function getJson(url: string): Promise<any> {
+  const response = await fetch(url, { signal: AbortSignal.timeout(REQUEST_TIMEOUT_MS) });
+  if (!response.ok) throw new Error(`Deezer answered HTTP ${response.status}`);
+  const body = await response.json();
+  // Errors come back as HTTP 200 with an `error` object
+  if (body?.error) throw new Error(`Deezer: ${body.error.message ?? body.error.type ?? 'unknown error'}`);
+  return body;
+}
+
+function toTrack(raw: any, album?: string): DeezerTrack | undefined {
+  const title = typeof raw?.title === 'string' ? raw.title.trim() : '';
+  const artist = typeof raw?.artist?.name === 'string' ? raw.artist.name.trim() : '';
+  if (!title || !artist) return undefined;
+  return {
+    title,
+    artist,
+    album: typeof raw.album?.title === 'string' ? raw.album.title : album,
+    duration: Number(raw.duration) || 0,
+  };
+}
+
+/**
+ * Reads a track, playlist or album from Deezer's public API, which needs no
+ * credentials. Playlists and albums are listed up to `maxTracks`.
+ */
+export async function fetchDeezerTracks(url: string, maxTracks: number): Promise<DeezerCollection> {
+  let parsed = new URL(url);
+  if (SHORT_LINK_HOSTS.includes(parsed.hostname.toLowerCase())) {
+    const response = await fetch(url, { redirect: 'follow', signal: AbortSignal.timeout(REQUEST_TIMEOUT_MS) });
+    parsed = new URL(response.url);
+  }
+  const target = parseDeezerPath(parsed.pathname);
+  if (!target) throw new Error(`Not a Deezer track, playlist or album: ${url}`);
+
+  const info = await getJson(`${API_BASE}/${target.kind}/${target.id}`);
+  if (target.kind === 'track') {
+    const track = toTrack(info);
+    if (!track) throw new Error(`Deezer track ${target.id} has no title`);
+    return { kind: 'track', name: track.title, tracks: [track] };
+  }
+
+  const name = String(info.title ?? '');
+  // Album tracks leave out the album they're on
+  const album = target.kind === 'album' ? name : undefined;
+  const tracks: DeezerTrack[] = [];
+  let next: string | undefined = `${API_BASE}/${target.kind}/${target.id}/tracks?limit=${PAGE_SIZE}`;
+  while (next && tracks.length < maxTracks) {
+    const page = await getJson(next);
+    const data: any[] = Array.isArray(page.data) ? page.data : [];
+    for (const raw of data) {
+      const track = toTrack(raw, album);
+      if (track) tracks.push(track);
+    }
+    next = data.length > 0 && typeof page.next === 'string' ? page.next : undefined;
+  }
+
+  return { kind: target.kind, name, tracks: tracks.slice(0, maxTracks) };
+}
+
+/** "Artist Title", to search YouTube with, since Deezer's audio can't be streamed. */
+export function deezerTrackQuery(track: DeezerTrack): string {
+  return `${track.artist} ${track.title}`;
+}
@@ -0,0 +1,102 @@
+import { EmbedBuilder } from 'discord.js';
+import { EmbedTheme, EmojiSet } from '../types/index';
+
+export const DEFAULT_ACCENT_COLOR = '#1DB954';
+// Discord cuts footers off at 2048 characters; the rest is left for the embed's own notes
+export const MAX_FOOTER_TEXT_LENGTH = 200;
+
+/** The emoji the player and queue put in front of their titles and fields. */
+export interface ThemeEmoji {
+  nowPlaying: string;
+  queue: string;
+  chapter: string;
+  progress: string;
+  duration: string;
+  queueSize: string;
+  upNext: string;
+  filters: string;
+  requester: string;
+  album: string;
+  onAir: string;
+}
+
+export const EMOJI_SETS: Record<EmojiSet, string> = {
+  classic: 'Colorful emoji, as usual',
+  minimal: 'Plain text symbols',
+  none: 'No emoji at all',
+};
+
+const EMOJI: Record<EmojiSet, ThemeEmoji> = {
+  classic: {
+    nowPlaying: '🎵',
+    queue: '📋',
+    chapter: '📖',
+    progress: '📊',
+    duration: '⏱️',
+    queueSize: '📋',
+    upNext: '📋',
+    filters: '🎛️',
+    requester: '👤',
+    album: '💿',
+    onAir: '🎙️',
+  },
+  minimal: {
+    nowPlaying: '♪',
+    queue: '≡',
+    chapter: '§',
+    progress: '▸',
+    duration: '◷',
+    queueSize: '#',
+    upNext: '»',
+    filters: '~',
+    requester: '@',
+    album: '◉',
+    onAir: '∿',
+  },
+  none: {
+    nowPlaying: '',
+    queue: '',
+    chapter: '',
+    progress: '',
+    duration: '',
+    queueSize: '',
+    upNext: '',
+    filters: '',
+    requester: '',
+    album: '',
+    onAir: '',
+  },
+};
+
+export function createDefaultTheme(): EmbedTheme {
+  return { color: DEFAULT_ACCENT_COLOR, emojiSet: 'classic' };
+}
+
+/** `#rrggbb` from `rrggbb` or `#RRGGBB`; undefined if it isn't a hex color. */
+export function parseAccentColor(input: string): string | undefined {
+  const match = /^#?([0-9a-f]{6})$/i.exec(input.trim());
+  return match ? `#${match[1].toLowerCase()}` : undefined;
+}
+
+export function getThemeEmoji(theme: EmbedTheme): ThemeEmoji {
+  return EMOJI[theme.emojiSet] ?? EMOJI.classic;
+}
+
+/** Puts the emoji in front of the text, or leaves the text alone when the set has none. */
+export function withEmoji(emoji: string, text: string): string {
+  return emoji ? `${emoji} ${text}` : text;
+}
+
+/** The footer line: the guild's own text, then the embed's note, whichever are set. */
+export function themedFooter(theme: EmbedTheme, note?: string): string | undefined {
+  const parts = [theme.footerText, note].filter(Boolean);
+  return parts.length > 0 ? parts.join(' · ') : undefined;
+}
+
+/** An embed in the guild's accent color, with its footer text when there's no note to add. */
+export function createThemedEmbed(theme: EmbedTheme, footerNote?: string): EmbedBuilder {
+  const embed = new EmbedBuilder().setColor((parseAccentColor(theme.color) ?? DEFAULT_ACCENT_COLOR) as `#${string}`);
+  const footer = themedFooter(theme, footerNote);
+  if (footer) embed.setFooter({ text: footer });
+  return embed;
+}
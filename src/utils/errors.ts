@@ -0,0 +1,131 @@
+import { MessageKey, MessageVars, Translator, translate } from './i18n';
+
+/**
+ * Why something failed. Each kind has its own reply and log level, so users
+ * get a short, actionable message instead of yt-dlp's stderr.
+ */
+export type GoonErrorKind =
+  | 'notInVoice'
+  | 'voiceConnectFailed'
+  | 'extractionFailed'
+  | 'ageRestricted'
+  | 'membersOnly'
+  | 'cookiesNoAccess'
+  | 'geoBlocked'
+  | 'unavailable'
+  | 'offline'
+  | 'rateLimited'
+  | 'timeout'
+  | 'queueLimit'
+  | 'playlist'
+  | 'internal';
+
+export type LogLevel = 'info' | 'warn' | 'error';
+
+/** A failure the bot knows how to explain. */
+export class GoonError extends Error {
+  constructor(
+    readonly kind: GoonErrorKind,
+    message: string = kind,
+    /** What was originally thrown, for the logs. */
+    readonly cause?: unknown
+  ) {
+    super(message);
+    this.name = 'GoonError';
+  }
+}
+
+/**
+ * A refusal whose reply says exactly what went wrong, e.g. which queue limit
+ * was hit. `message` is the English text, for logs and the REST API.
+ */
+export class UserFacingError extends GoonError {
+  constructor(
+    kind: GoonErrorKind,
+    readonly messageKey: MessageKey,
+    readonly vars?: MessageVars
+  ) {
+    super(kind, translate('en', messageKey, vars));
+  }
+}
+
+// Kinds every source would fail on the same way, e.g. a video only a signed-in account can watch
+const ACCOUNT_REQUIRED_KINDS: ReadonlySet<GoonErrorKind> = new Set(['ageRestricted', 'membersOnly', 'cookiesNoAccess']);
+
+// Kinds that often go away if the same thing is tried again a little later
+const TRANSIENT_KINDS: ReadonlySet<GoonErrorKind> = new Set(['extractionFailed', 'rateLimited', 'timeout']);
+
+const ERROR_MESSAGES: Record<Exclude<GoonErrorKind, 'queueLimit' | 'playlist'>, MessageKey> = {
+  notInVoice: 'common.notInVoice',
+  voiceConnectFailed: 'error.voiceConnectFailed',
+  extractionFailed: 'error.extractionFailed',
+  ageRestricted: 'error.ageRestricted',
+  membersOnly: 'error.membersOnly',
+  cookiesNoAccess: 'error.cookiesNoAccess',
+  geoBlocked: 'error.geoBlocked',
+  unavailable: 'error.unavailable',
+  offline: 'error.offline',
+  rateLimited: 'error.rateLimited',
+  timeout: 'error.timeout',
+  internal: 'guard.commandFailed',
+};
+
+// Expected refusals are worth a note; failures someone should look at are errors
+const LOG_LEVELS: Record<GoonErrorKind, LogLevel> = {
+  notInVoice: 'info',
+  voiceConnectFailed: 'error',
+  extractionFailed: 'error',
+  ageRestricted: 'info',
+  membersOnly: 'info',
+  cookiesNoAccess: 'warn',
+  geoBlocked: 'warn',
+  unavailable: 'info',
+  offline: 'info',
+  rateLimited: 'warn',
+  timeout: 'warn',
+  queueLimit: 'info',
+  playlist: 'info',
+  internal: 'error',
+};
+
+/** Any thrown value as a GoonError; ones the bot doesn't recognise become `internal`. */
+export function toGoonError(error: unknown): GoonError {
+  if (error instanceof GoonError) return error;
+  return new GoonError('internal', error instanceof Error ? error.message : String(error), error);
+}
+
+/** Whether `error` is a video only a signed-in account can play, which no fallback source gets around. */
+export function requiresAccount(error: unknown): boolean {
+  return error instanceof GoonError && ACCOUNT_REQUIRED_KINDS.has(error.kind);
+}
+
+/** Whether trying again might work, e.g. after YouTube throttled yt-dlp. */
+export function isTransientError(error: unknown): boolean {
+  return error instanceof GoonError && TRANSIENT_KINDS.has(error.kind);
+}
+
+/**
+ * The reply for a failed request. Errors the bot doesn't recognise get
+ * `fallback`, e.g. a hint about what the command expected.
+ */
+export function describeError(error: unknown, t: Translator, fallback: MessageKey = 'guard.commandFailed'): string {
+  const goonError = toGoonError(error);
+  if (goonError instanceof UserFacingError) {
+    return `❌ ${t(goonError.messageKey, goonError.vars)}`;
+  }
+  if (goonError.kind === 'internal') {
+    return t(fallback);
+  }
+  return t(ERROR_MESSAGES[goonError.kind as keyof typeof ERROR_MESSAGES]);
+}
+
+/** Logs `error` at its kind's level, without a stack trace for expected refusals. */
+export function logError(context: string, error: unknown): void {
+  const goonError = toGoonError(error);
+  const level = LOG_LEVELS[goonError.kind];
+  if (level === 'error') {
+    console.error(`${context}:`, goonError.kind === 'internal' ? goonError.cause : goonError);
+  } else {
+    console[level](`${context}: [${goonError.kind}] ${goonError.message}`);
+  }
+}
@@ -0,0 +1,123 @@
+const REQUEST_TIMEOUT_MS = 10_000;
+
+// Public instances come and go, so none is built in; point these at ones you trust
+const PIPED_API_URL = process.env.PIPED_API_URL?.replace(/\/+$/, '');
+const INVIDIOUS_URL = process.env.INVIDIOUS_URL?.replace(/\/+$/, '');
+
+export interface FrontendAudioStream {
+  url: string;
+  bitrateKbps?: number;
+}
+
+/** What an alternative YouTube frontend knows about a video. */
+export interface FrontendVideo {
+  title: string;
+  duration: number;
+  thumbnail?: string;
+  uploader?: string;
+  audioStreams: FrontendAudioStream[];
+}
+
+/** A Piped or Invidious instance, used when yt-dlp can't get through. */
+export interface YouTubeFrontend {
+  name: string;
+  fetchVideo(videoId: string): Promise<FrontendVideo>;
+}
+
+export function extractVideoId(url: string): string | undefined {
+  try {
+    const parsed = new URL(url);
+    if (parsed.hostname === 'youtu.be') return parsed.pathname.slice(1) || undefined;
+    const shortsOrLive = parsed.pathname.match(/^\/(?:shorts|live|embed)\/([\w-]{11})/);
+    return shortsOrLive?.[1] ?? parsed.searchParams.get('v') ?? undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+/**
+ * The instance answered, but doesn't have this video (removed, private, not
+ * found). That says nothing about the instance's health.
+ */
+export class VideoMissError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'VideoMissError';
+  }
+}
+
+async function getJson(url: string): Promise<any> {
+  const response = await fetch(url, { signal: AbortSignal.timeout(REQUEST_TIMEOUT_MS) });
+  if (!response.ok) {
+    const message = `${new URL(url).host} answered HTTP ${response.status}`;
+    // Server errors and rate limits are the instance's problem; other 4xx are about the video
+    throw response.status < 500 && response.status !== 429 ? new VideoMissError(message) : new Error(message);
+  }
+  return response.json();
+}
+
+function pipedFrontend(baseUrl: string): YouTubeFrontend {
+  return {
+    name: 'piped',
+    async fetchVideo(videoId: string): Promise<FrontendVideo> {
+      const video = await getJson(`${baseUrl}/streams/${encodeURIComponent(videoId)}`);
+      if (video.error) throw new VideoMissError(`Piped: ${video.error}`);
+
+      // Piped serves stream URLs through its own proxy, so they play from any IP
+      const audioStreams: FrontendAudioStream[] = (Array.isArray(video.audioStreams) ? video.audioStreams : [])
+        .filter((stream: any) => typeof stream?.url === 'string')
+        .map((stream: any) => ({
+          url: stream.url,
+          bitrateKbps: Number(stream.bitrate) > 0 ? Math.round(Number(stream.bitrate) / 1000) : undefined,
+        }));
+
+      return {
+        title: String(video.title || 'YouTube Track'),
+        duration: Number(video.duration) || 0,
+        thumbnail: typeof video.thumbnailUrl === 'string' ? video.thumbnailUrl : undefined,
+        uploader: typeof video.uploader === 'string' ? video.uploader : undefined,
+        audioStreams,
+      };
+    },
+  };
+}
+
+function invidiousFrontend(baseUrl: string): YouTubeFrontend {
+  return {
+    name: 'invidious',
+    async fetchVideo(videoId: string): Promise<FrontendVideo> {
+      const video = await getJson(`${baseUrl}/api/v1/videos/${encodeURIComponent(videoId)}`);
+      if (video.error) throw new VideoMissError(`Invidious: ${video.error}`);
+
+      // Format URLs are bound to the instance's IP; `local=true` has the instance proxy them
+      const audioStreams: FrontendAudioStream[] = (Array.isArray(video.adaptiveFormats) ? video.adaptiveFormats : [])
+        .filter((format: any) => String(format?.type ?? '').startsWith('audio/') && format.itag)
+        .map((format: any) => ({
+          url: `${baseUrl}/latest_version?id=${encodeURIComponent(videoId)}&itag=${encodeURIComponent(format.itag)}&local=true`,
+          bitrateKbps: Number(format.bitrate) > 0 ? Math.round(Number(format.bitrate) / 1000) : undefined,
+        }));
+
+      const thumbnails: any[] = Array.isArray(video.videoThumbnails) ? video.videoThumbnails : [];
+      return {
+        title: String(video.title || 'YouTube Track'),
+        duration: Number(video.lengthSeconds) || 0,
+        thumbnail: typeof thumbnails[0]?.url === 'string' ? thumbnails[0].url : undefined,
+        uploader: typeof video.author === 'string' ? video.author : undefined,
+        audioStreams,
+      };
+    },
+  };
+}
+
+/** The frontends configured through `PIPED_API_URL` and `INVIDIOUS_URL`. */
+export const YOUTUBE_FRONTENDS: YouTubeFrontend[] = [
+  ...(PIPED_API_URL ? [pipedFrontend(PIPED_API_URL)] : []),
+  ...(INVIDIOUS_URL ? [invidiousFrontend(INVIDIOUS_URL)] : []),
+];
+
+/** Highest bitrate, or with data saver the best one at 64 kbit/s or less (else the lowest). */
+export function pickAudioStream(streams: FrontendAudioStream[], dataSaver: boolean): FrontendAudioStream | undefined {
+  const sorted = [...streams].sort((a, b) => (b.bitrateKbps ?? 0) - (a.bitrateKbps ?? 0));
+  if (!dataSaver) return sorted[0];
+  return sorted.find((stream) => (stream.bitrateKbps ?? Infinity) <= 64) ?? sorted[sorted.length - 1];
+}
@@ -0,0 +1,63 @@
+/** Lowercase words without accents or punctuation, so `Beyoncé - Halo!` matches `beyonce halo`. */
+export function toSearchWords(text: string): string[] {
+  return text
+    .toLowerCase()
+    .normalize('NFKD')
+    .replace(/[\u0300-\u036f]/g, '')
+    .split(/[^\p{L}\p{N}]+/u)
+    .filter(Boolean);
+}
+
+/** Whether `a` becomes `b` with at most one inserted, removed or changed character. */
+function withinOneEdit(a: string, b: string): boolean {
+  if (Math.abs(a.length - b.length) > 1) return false;
+  let i = 0;
+  let j = 0;
+  let edits = 0;
+  while (i < a.length && j < b.length) {
+    if (a[i] === b[j]) {
+      i++;
+      j++;
+      continue;
+    }
+    if (++edits > 1) return false;
+    if (a.length > b.length) i++;
+    else if (b.length > a.length) j++;
+    else {
+      i++;
+      j++;
+    }
+  }
+  return edits + (a.length - i) + (b.length - j) <= 1;
+}
+
+function scoreWord(queryWord: string, words: string[]): number {
+  let best = 0;
+  for (const word of words) {
+    if (word === queryWord) return 3;
+    if (word.startsWith(queryWord)) best = Math.max(best, 2);
+    else if (word.includes(queryWord)) best = Math.max(best, 1);
+    // Short words are too easy to hit by accident with a typo allowed
+    else if (queryWord.length >= 4 && withinOneEdit(queryWord, word)) best = Math.max(best, 1);
+  }
+  return best;
+}
+
+/**
+ * How well `query` matches `text`; higher is better. Every query word has to
+ * match a word of the text exactly, as its start, inside it, or with one typo;
+ * otherwise the score is 0.
+ */
+export function fuzzyScore(query: string, text: string): number {
+  const queryWords = toSearchWords(query);
+  const words = toSearchWords(text);
+  if (queryWords.length === 0) return 0;
+
+  let total = 0;
+  for (const queryWord of queryWords) {
+    const score = scoreWord(queryWord, words);
+    if (score === 0) return 0;
+    total += score;
+  }
+  return total;
+}
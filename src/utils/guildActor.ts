@@ -0,0 +1,31 @@
+/**
+ * Runs each guild's playback changes one at a time, in the order they were
+ * requested, like a per-guild worker reading from a mailbox. Starting a song
+ * awaits stream setup, so without this a skip or stop arriving meanwhile
+ * could interleave with it: two songs started at once, or a song starting on
+ * a connection that was just torn down. Different guilds still run
+ * concurrently.
+ *
+ * A task must not await `run` for its own guild, since that waits on itself.
+ * Fire-and-forget is fine, e.g. a player event raised while stopping.
+ */
+export class GuildActors {
+  private readonly mailboxes: Map<string, Promise<unknown>> = new Map();
+
+  run<T>(guildId: string, task: () => Promise<T> | T): Promise<T> {
+    const previous = this.mailboxes.get(guildId) ?? Promise.resolve();
+    const result = previous.then(task);
+    // A failed task is reported to its caller and mustn't hold up the ones after it
+    const settled = result.catch(() => undefined);
+    this.mailboxes.set(guildId, settled);
+
+    void settled.then(() => {
+      if (this.mailboxes.get(guildId) === settled) {
+        this.mailboxes.delete(guildId);
+      }
+    });
+    return result;
+  }
+}
+
+export const playbackActors = new GuildActors();
@@ -0,0 +1,124 @@
+import { FeatureFlag, GuildSettings, VoiceInterruptionPolicy } from '../types/index';
+import { DocumentSchema, Storage } from './storage';
+import { DEFAULT_VOLUME } from './userPreferences';
+import { Translator, getTranslator } from './i18n';
+import { createDefaultTheme } from './embedTheme';
+
+export type GuildSettingsDocument = Record<string, GuildSettings>;
+
+export const FEATURE_FLAGS: Record<FeatureFlag, string> = {
+  filters: 'Audio filters such as speed and nightcore',
+  autoplay: 'Automatically queue related songs when the queue runs out',
+  dashboard: 'Web dashboard for queue management',
+};
+
+export const VOICE_INTERRUPTION_POLICIES: Record<VoiceInterruptionPolicy, string> = {
+  pause: 'Pause until unmuted and say why',
+  announce: 'Keep playing and post a notice',
+  disconnect: 'Leave voice and clear the queue',
+};
+
+export const GUILD_SETTINGS_SCHEMA: DocumentSchema<GuildSettingsDocument> = {
+  name: 'guild-settings',
+  version: 1,
+  migrations: {},
+  defaultValue: () => ({}),
+};
+
+export function createDefaultGuildSettings(): GuildSettings {
+  return {
+    aliases: {},
+    features: {},
+    normalizeLoudness: false,
+    dataSaver: false,
+    voiceInterruptionPolicy: 'pause',
+    renderer: 'classic',
+    language: 'en',
+    theme: createDefaultTheme(),
+    volume: DEFAULT_VOLUME,
+    idleTimeoutMinutes: 0,
+    announceNowPlaying: true,
+    maxSongsPerUser: 0,
+    maxQueueSize: 0,
+    maxSongMinutes: 0,
+    // A stream that never ends would otherwise hold up the queue for good
+    maxLiveMinutes: 60,
+    blacklist: [],
+    queueLocked: false,
+    autoDedupe: false,
+    allowedChannelIds: [],
+    disabledCommands: [],
+    stickyPlayer: false,
+    ttsAnnouncements: false,
+    joinIntros: false,
+  };
+}
+
+export function normalizeAliasName(name: string): string {
+  return name.trim().toLowerCase();
+}
+
+function withDefaults(stored: GuildSettingsDocument): GuildSettingsDocument {
+  const settings: GuildSettingsDocument = {};
+  for (const [guildId, guildSettings] of Object.entries(stored)) {
+    settings[guildId] = { ...createDefaultGuildSettings(), ...guildSettings };
+  }
+  return settings;
+}
+
+/** Per-guild configuration. Missing fields fall back to defaults, so new settings need no migration. */
+export class GuildSettingsManager {
+  private settings: GuildSettingsDocument = {};
+
+  constructor(private readonly storage: Storage) {
+    try {
+      this.settings = withDefaults(storage.load(GUILD_SETTINGS_SCHEMA));
+    } catch (error) {
+      console.error('[SETTINGS] Failed to load guild settings:', error);
+    }
+    // When processes share storage, another one may change settings, e.g. from the dashboard
+    storage.watch(GUILD_SETTINGS_SCHEMA, (stored) => (this.settings = withDefaults(stored)));
+  }
+
+  get(guildId: string): GuildSettings {
+    if (!this.settings[guildId]) {
+      this.settings[guildId] = createDefaultGuildSettings();
+    }
+    return this.settings[guildId];
+  }
+
+  /** The first guild whose settings match, e.g. the owner of an API key. Only guilds with settings are searched. */
+  findGuild(predicate: (settings: GuildSettings) => boolean): string | undefined {
+    return Object.keys(this.settings).find((guildId) => predicate(this.settings[guildId]));
+  }
+
+  update(guildId: string, changes: Partial<GuildSettings>): GuildSettings {
+    const updated = { ...this.get(guildId), ...changes };
+    this.settings[guildId] = updated;
+    this.save();
+    return updated;
+  }
+
+  /** Messages in the guild's language. */
+  translator(guildId: string): Translator {
+    return getTranslator(this.get(guildId).language);
+  }
+
+  isFeatureEnabled(guildId: string, feature: FeatureFlag): boolean {
+    return this.get(guildId).features[feature] === true;
+  }
+
+  setFeatureEnabled(guildId: string, feature: FeatureFlag, enabled: boolean): void {
+    this.update(guildId, { features: { ...this.get(guildId).features, [feature]: enabled } });
+  }
+
+  resolveAlias(guildId: string, name: string): string | undefined {
+    const { aliases } = this.get(guildId);
+    const key = normalizeAliasName(name);
+    return Object.prototype.hasOwnProperty.call(aliases, key) ? aliases[key] : undefined;
+  }
+
+  private save(): void {
+    this.storage.scheduleSave(GUILD_SETTINGS_SCHEMA, () => this.settings);
+  }
+}
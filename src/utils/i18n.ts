@@ -0,0 +1,32 @@
+import { Locale } from '../types/index';
+import { MessageKey, en } from '../locales/en';
+import { pt } from '../locales/pt';
+
+export type { MessageKey } from '../locales/en';
+
+/** Values for a message's `{name}` placeholders. */
+export type MessageVars = Record<string, string | number>;
+
+/** Looks up a message in one language and fills in its placeholders. */
+export type Translator = (key: MessageKey, vars?: MessageVars) => string;
+
+/** Each locale's name, in that language, for the settings command. */
+export const LOCALES: Record<Locale, string> = {
+  en: 'English',
+  pt: 'Português',
+};
+
+const catalogs: Record<Locale, Record<MessageKey, string>> = { en, pt };
+
+export function translate(locale: Locale, key: MessageKey, vars?: MessageVars): string {
+  // Unknown locales (e.g. one removed after a guild chose it) fall back to English
+  const message = catalogs[locale]?.[key] ?? en[key];
+  if (!vars) return message;
+  return message.replace(/\{(\w+)\}/g, (placeholder, name: string) =>
+    Object.prototype.hasOwnProperty.call(vars, name) ? String(vars[name]) : placeholder
+  );
+}
+
+export function getTranslator(locale: Locale): Translator {
+  return (key, vars) => translate(locale, key, vars);
+}
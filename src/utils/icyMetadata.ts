@@ -0,0 +1,87 @@
+import { Song } from '../types/index';
+import { resolveStationUrl } from './radioExtractor';
+import { getPublic } from './publicHttp';
+
+const REQUEST_TIMEOUT_MS = 10_000;
+// Stations change songs every few minutes; the player message refreshes more often than this anyway
+const POLL_INTERVAL_MS = 20_000;
+// A metadata block is at most 255 * 16 bytes, and stations space them a few KB apart
+const MAX_READ_BYTES = 256 * 1024;
+
+/** `Artist - Title` from a metadata block like `StreamTitle='Artist - Title';StreamUrl='';`. */
+export function parseStreamTitle(metadata: string): string | undefined {
+  // Titles can contain `';`, so the value runs up to the next key or the end
+  const match = /StreamTitle='(.*?)';(?=\w+=|$)/s.exec(metadata.replace(/\0+$/, ''));
+  return match?.[1].trim() || undefined;
+}
+
+/**
+ * What the station at `url` says is playing, from its ICY (Shoutcast/Icecast)
+ * metadata. Asks for metadata, reads audio up to the first metadata block,
+ * then hangs up. Undefined when the station sends none.
+ */
+export async function readIcyTitle(url: string): Promise<string | undefined> {
+  const { response } = await getPublic(url, { 'Icy-MetaData': '1' }, REQUEST_TIMEOUT_MS);
+  response.setTimeout(REQUEST_TIMEOUT_MS, () => response.destroy(new Error('Timed out waiting for ICY metadata')));
+  try {
+    const metaInt = Number(response.headers['icy-metaint']);
+    const status = response.statusCode ?? 0;
+    if (status < 200 || status >= 300 || !Number.isInteger(metaInt) || metaInt <= 0 || metaInt > MAX_READ_BYTES) {
+      return undefined;
+    }
+
+    let buffered = Buffer.alloc(0);
+    // `metaInt` bytes of audio, one length byte counting 16-byte units, then the metadata
+    for await (const chunk of response) {
+      buffered = Buffer.concat([buffered, chunk as Buffer]);
+      if (buffered.length > metaInt && buffered.length >= metaInt + 1 + buffered[metaInt] * 16) {
+        const length = buffered[metaInt] * 16;
+        return parseStreamTitle(buffered.subarray(metaInt + 1, metaInt + 1 + length).toString('utf8'));
+      }
+    }
+    return undefined;
+  } finally {
+    response.destroy();
+  }
+}
+
+/**
+ * Keeps radio songs' `streamTitle` up to date while they play, one station
+ * per guild. The player message picks the title up on its next refresh.
+ */
+export class IcyWatcher {
+  // Replaced whenever a guild's watch starts or stops, so a poll still in flight knows it's stale
+  private readonly watches: Map<string, { timer?: NodeJS.Timeout }> = new Map();
+
+  /** Polls `song`'s station until `isCurrent` says it has stopped playing. */
+  watch(guildId: string, song: Song, isCurrent: () => boolean): void {
+    this.stop(guildId);
+    const watch: { timer?: NodeJS.Timeout } = {};
+    this.watches.set(guildId, watch);
+
+    const poll = async (): Promise<void> => {
+      if (this.watches.get(guildId) !== watch) return;
+      if (!isCurrent()) {
+        this.watches.delete(guildId);
+        return;
+      }
+      try {
+        // Stations that stop sending titles keep showing the last one
+        song.streamTitle = (await readIcyTitle(await resolveStationUrl(song.url))) ?? song.streamTitle;
+      } catch (error) {
+        console.warn(`[ICY] Couldn't read what's on ${song.url}:`, error instanceof Error ? error.message : error);
+      }
+      if (this.watches.get(guildId) === watch) {
+        watch.timer = setTimeout(() => void poll(), POLL_INTERVAL_MS);
+      }
+    };
+    void poll();
+  }
+
+  stop(guildId: string): void {
+    clearTimeout(this.watches.get(guildId)?.timer);
+    this.watches.delete(guildId);
+  }
+}
+
+export const icyWatcher = new IcyWatcher();
@@ -0,0 +1,46 @@
+/** How often voice connections are checked for idleness. */
+export const IDLE_CHECK_INTERVAL_MS = 30_000;
+/** How long before leaving the bot warns that it's about to. */
+const IDLE_WARNING_MS = 60_000;
+
+export const MAX_IDLE_TIMEOUT_MINUTES = 180;
+
+export type IdleAction = 'warn' | 'leave';
+
+/**
+ * Tracks how long each guild's voice connection has sat with nothing playing
+ * (empty queue or paused). Fed on every periodic check, it says when to warn
+ * and when to leave; any playback in between starts the clock over.
+ */
+export class IdleTracker {
+  private readonly idleSince: Map<string, number> = new Map();
+  private readonly warned: Set<string> = new Set();
+
+  /** `timeoutMs` of 0 disables leaving for the guild. */
+  update(guildId: string, idle: boolean, timeoutMs: number, now = Date.now()): IdleAction | undefined {
+    if (!idle || timeoutMs <= 0) {
+      this.reset(guildId);
+      return undefined;
+    }
+
+    const since = this.idleSince.get(guildId) ?? now;
+    this.idleSince.set(guildId, since);
+    const idleFor = now - since;
+
+    if (idleFor >= timeoutMs) {
+      this.reset(guildId);
+      return 'leave';
+    }
+    // Short timeouts go straight to leaving rather than warning almost immediately
+    if (timeoutMs > IDLE_WARNING_MS * 2 && idleFor >= timeoutMs - IDLE_WARNING_MS && !this.warned.has(guildId)) {
+      this.warned.add(guildId);
+      return 'warn';
+    }
+    return undefined;
+  }
+
+  reset(guildId: string): void {
+    this.idleSince.delete(guildId);
+    this.warned.delete(guildId);
+  }
+}
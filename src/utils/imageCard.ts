@@ -0,0 +1,117 @@
+import { spawn } from 'child_process';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { Song } from '../types/index';
+import { YouTubeUtil } from './youtubeUtil';
+import { formatTrack } from './titleParser';
+
+const CARD_WIDTH = 800;
+const CARD_HEIGHT = 240;
+const TEXT_X = CARD_HEIGHT + 24;
+const BAR_WIDTH = CARD_WIDTH - TEXT_X - 36;
+const MAX_TITLE_LENGTH = 38;
+const RENDER_TIMEOUT_MS = 10_000;
+// The progress bar moves in steps, so regular player refreshes mostly hit the cache
+const PROGRESS_STEPS = 40;
+const MAX_CACHED_CARDS = 50;
+
+const cache: Map<string, Buffer> = new Map();
+
+function truncate(text: string, length: number): string {
+  return text.length > length ? `${text.slice(0, length - 1)}…` : text;
+}
+
+function runFfmpeg(args: string[]): Promise<Buffer> {
+  return new Promise((resolve, reject) => {
+    const ffmpeg = spawn('ffmpeg', args, { stdio: ['ignore', 'pipe', 'pipe'] });
+    const chunks: Buffer[] = [];
+    let stderr = '';
+    const timeout = setTimeout(() => ffmpeg.kill('SIGKILL'), RENDER_TIMEOUT_MS);
+
+    ffmpeg.stdout.on('data', (chunk: Buffer) => chunks.push(chunk));
+    ffmpeg.stderr.on('data', (chunk) => (stderr += chunk.toString()));
+    ffmpeg.on('error', (error) => {
+      clearTimeout(timeout);
+      reject(error);
+    });
+    ffmpeg.on('close', (code) => {
+      clearTimeout(timeout);
+      if (code === 0 && chunks.length > 0) {
+        resolve(Buffer.concat(chunks));
+      } else {
+        reject(new Error(`ffmpeg exited with code ${code} while drawing card: ${stderr.trim()}`));
+      }
+    });
+  });
+}
+
+/**
+ * Draws a "now playing" card (artwork, title, time and progress bar) as a PNG
+ * using ffmpeg's drawing filters, so no image library is needed.
+ */
+export async function renderNowPlayingCard(song: Song, elapsed: number): Promise<Buffer> {
+  // A live stream's bar stays full, with no times to show
+  const progress = song.isLive ? 1 : song.duration > 0 ? Math.min(1, elapsed / song.duration) : 0;
+  const step = Math.round(progress * PROGRESS_STEPS);
+  // Chapters of one upload share a URL
+  const cacheKey = `${song.url}:${song.clip?.start ?? 0}:${step}`;
+
+  const cached = cache.get(cacheKey);
+  if (cached) return cached;
+
+  // Text goes through files so titles need no escaping for the filter syntax
+  const tempDir = fs.mkdtempSync(path.join(os.tmpdir(), 'goonbot-card-'));
+  const titleFile = path.join(tempDir, 'title.txt');
+  const timeFile = path.join(tempDir, 'time.txt');
+  fs.writeFileSync(titleFile, truncate(formatTrack(song), MAX_TITLE_LENGTH));
+  fs.writeFileSync(
+    timeFile,
+    song.isLive
+      ? 'LIVE'
+      : `${YouTubeUtil.formatDuration(Math.floor(progress * song.duration))} / ${YouTubeUtil.formatDuration(song.duration)}`
+  );
+
+  const filledWidth = Math.round((BAR_WIDTH * step) / PROGRESS_STEPS);
+  const artwork = song.thumbnail
+    ? `[1:v]scale=${CARD_HEIGHT}:${CARD_HEIGHT}:force_original_aspect_ratio=increase,crop=${CARD_HEIGHT}:${CARD_HEIGHT}[art];[0:v][art]overlay=0:0,`
+    : '';
+  const filters = [
+    `drawtext=textfile='${titleFile}':expansion=none:fontcolor=white:fontsize=30:x=${TEXT_X}:y=56`,
+    `drawtext=textfile='${timeFile}':expansion=none:fontcolor=0xB3B3B3:fontsize=22:x=${TEXT_X}:y=110`,
+    `drawbox=x=${TEXT_X}:y=180:w=${BAR_WIDTH}:h=8:color=0x535353:t=fill`,
+    ...(filledWidth > 0
+      ? [`drawbox=x=${TEXT_X}:y=180:w=${filledWidth}:h=8:color=${song.isLive ? '0xE91429' : '0x1DB954'}:t=fill`]
+      : []),
+  ];
+
+  try {
+    const card = await runFfmpeg([
+      '-hide_banner',
+      '-loglevel',
+      'error',
+      '-f',
+      'lavfi',
+      '-i',
+      `color=c=0x191414:s=${CARD_WIDTH}x${CARD_HEIGHT}`,
+      ...(song.thumbnail ? ['-i', song.thumbnail] : []),
+      '-filter_complex',
+      (artwork || '[0:v]') + filters.join(','),
+      '-frames:v',
+      '1',
+      '-f',
+      'image2',
+      '-c:v',
+      'png',
+      'pipe:1',
+    ]);
+
+    cache.set(cacheKey, card);
+    if (cache.size > MAX_CACHED_CARDS) {
+      cache.delete(cache.keys().next().value as string);
+    }
+    return card;
+  } finally {
+    fs.rmSync(tempDir, { recursive: true, force: true });
+  }
+}
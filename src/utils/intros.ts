@@ -0,0 +1,117 @@
+import * as fs from 'fs';
+import * as path from 'path';
+import { spawn } from 'child_process';
+
+/** Longer clips are cut off when they're saved. */
+export const INTRO_MAX_SECONDS = 5;
+/** Largest upload accepted before converting it. */
+export const INTRO_MAX_UPLOAD_BYTES = 2 * 1024 * 1024;
+/** Clips are mixed in at this volume, so the music stays audible underneath. */
+export const INTRO_VOLUME = 0.5;
+/** Rejoining over and over only plays someone's intro once per this long. */
+export const INTRO_COOLDOWN_MS = 10 * 60_000;
+// Every intro restarts the song's pipeline, so a crowd joining at once only gets one
+const GUILD_COOLDOWN_MS = 30_000;
+const CONVERT_TIMEOUT_MS = 30_000;
+
+/**
+ * Short clips members play when they join the bot's voice channel. Clips
+ * follow the member across servers and are kept in the data directory as
+ * Opus, already trimmed to INTRO_MAX_SECONDS.
+ */
+export class IntroManager {
+  private readonly dir: string;
+  // When intros last played, by `guildId:userId` and by guild
+  private readonly lastPlayed: Map<string, number> = new Map();
+
+  constructor(dataDir: string) {
+    this.dir = path.join(dataDir, 'intros');
+  }
+
+  /** The member's clip on disk, if they have one. */
+  getClipPath(userId: string): string | undefined {
+    const clipPath = this.pathFor(userId);
+    return fs.existsSync(clipPath) ? clipPath : undefined;
+  }
+
+  /** Converts the audio at `sourceUrl` into the member's clip, replacing any they had. */
+  async save(userId: string, sourceUrl: string): Promise<void> {
+    fs.mkdirSync(this.dir, { recursive: true });
+    const clipPath = this.pathFor(userId);
+    const tempPath = `${clipPath}.tmp`;
+
+    try {
+      await convertClip(sourceUrl, tempPath);
+      fs.renameSync(tempPath, clipPath);
+    } finally {
+      fs.rmSync(tempPath, { force: true });
+    }
+  }
+
+  /** Deletes the member's clip. Returns false when they had none. */
+  remove(userId: string): boolean {
+    const clipPath = this.getClipPath(userId);
+    if (!clipPath) return false;
+    fs.rmSync(clipPath, { force: true });
+    return true;
+  }
+
+  /**
+   * Whether the member's intro may play now, recording it if so. False while
+   * they or anyone else in the guild played one too recently.
+   */
+  claim(guildId: string, userId: string, now = Date.now()): boolean {
+    const userKey = `${guildId}:${userId}`;
+    if (now - (this.lastPlayed.get(userKey) ?? 0) < INTRO_COOLDOWN_MS) return false;
+    if (now - (this.lastPlayed.get(guildId) ?? 0) < GUILD_COOLDOWN_MS) return false;
+
+    this.lastPlayed.set(userKey, now);
+    this.lastPlayed.set(guildId, now);
+    return true;
+  }
+
+  private pathFor(userId: string): string {
+    // Snowflakes are digits, but the ID ends up in a file path
+    return path.join(this.dir, `${userId.replace(/\D/g, '')}.ogg`);
+  }
+}
+
+/** Trims and re-encodes any audio or video ffmpeg can read into a short Ogg Opus clip. */
+function convertClip(sourceUrl: string, outputPath: string): Promise<void> {
+  return new Promise((resolve, reject) => {
+    const ffmpeg = spawn(
+      'ffmpeg',
+      [
+        '-hide_banner',
+        '-loglevel',
+        'error',
+        '-i',
+        sourceUrl,
+        '-t',
+        String(INTRO_MAX_SECONDS),
+        '-vn',
+        '-acodec',
+        'libopus',
+        '-b:a',
+        '64k',
+        '-f',
+        'ogg',
+        '-y',
+        outputPath,
+      ],
+      { stdio: ['ignore', 'ignore', 'pipe'] }
+    );
+    const timer = setTimeout(() => ffmpeg.kill('SIGKILL'), CONVERT_TIMEOUT_MS);
+    let stderr = '';
+    ffmpeg.stderr?.on('data', (chunk) => (stderr += chunk.toString()));
+    ffmpeg.on('error', (error) => {
+      clearTimeout(timer);
+      reject(error);
+    });
+    ffmpeg.on('close', (code) => {
+      clearTimeout(timer);
+      if (code === 0) resolve();
+      else reject(new Error(stderr.trim().split('\n').pop() || `ffmpeg exited with ${code}`));
+    });
+  });
+}
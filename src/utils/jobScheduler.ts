@@ -0,0 +1,127 @@
+type Job = () => Promise<void>;
+
+/**
+ * Extraction job classes. `interactive` is someone waiting on a reply or on the
+ * next song, `bulk` is playlist imports, and `prefetch` is speculative work.
+ */
+export type JobLane = 'interactive' | 'bulk' | 'prefetch';
+
+interface FairJobSchedulerOptions {
+  /** Checked before each job starts; jobs stay queued while it returns false. */
+  canStart?: () => boolean;
+  /** Called after every job settles. */
+  onSettled?: () => void;
+}
+
+/**
+ * Runs async jobs with bounded concurrency, taking turns between guilds.
+ * Each guild has its own FIFO queue and free slots are handed out round-robin,
+ * so a guild importing hundreds of songs can't starve another guild's single
+ * request.
+ */
+export class FairJobScheduler {
+  private readonly guildQueues: Map<string, Job[]> = new Map();
+  // Guilds with pending jobs, in the order they get their next turn
+  private readonly rotation: string[] = [];
+  private running = 0;
+
+  constructor(
+    private readonly maxConcurrency: number,
+    private readonly options: FairJobSchedulerOptions = {}
+  ) {}
+
+  schedule<T>(guildId: string, task: () => Promise<T>): Promise<T> {
+    return new Promise<T>((resolve, reject) => {
+      const job: Job = () => Promise.resolve().then(task).then(resolve, reject);
+
+      const queue = this.guildQueues.get(guildId);
+      if (queue) {
+        queue.push(job);
+      } else {
+        this.guildQueues.set(guildId, [job]);
+        this.rotation.push(guildId);
+      }
+
+      this.drain();
+    });
+  }
+
+  getPendingCount(guildId?: string): number {
+    if (guildId) {
+      return this.guildQueues.get(guildId)?.length ?? 0;
+    }
+    let total = 0;
+    for (const queue of this.guildQueues.values()) {
+      total += queue.length;
+    }
+    return total;
+  }
+
+  /** Starts queued jobs while there are free slots. */
+  drain(): void {
+    while (
+      this.running < this.maxConcurrency &&
+      this.rotation.length > 0 &&
+      (this.options.canStart?.() ?? true)
+    ) {
+      const guildId = this.rotation.shift()!;
+      const queue = this.guildQueues.get(guildId)!;
+      const job = queue.shift()!;
+
+      // Back of the line if this guild still has work waiting
+      if (queue.length > 0) {
+        this.rotation.push(guildId);
+      } else {
+        this.guildQueues.delete(guildId);
+      }
+
+      this.running++;
+      job().finally(() => {
+        this.running--;
+        this.drain();
+        this.options.onSettled?.();
+      });
+    }
+  }
+}
+
+/**
+ * Separate bounded pools per job lane. Bulk and prefetch jobs only start while
+ * no interactive job is waiting, and prefetch also yields to bulk imports, so a
+ * user's `/play` never queues behind background work.
+ */
+export class ExtractionScheduler {
+  private readonly lanes: Record<JobLane, FairJobScheduler>;
+
+  constructor(limits: Record<JobLane, number>) {
+    const interactive: FairJobScheduler = new FairJobScheduler(limits.interactive, {
+      onSettled: () => {
+        bulk.drain();
+        prefetch.drain();
+      },
+    });
+    const bulk: FairJobScheduler = new FairJobScheduler(limits.bulk, {
+      canStart: () => interactive.getPendingCount() === 0,
+      onSettled: () => prefetch.drain(),
+    });
+    const prefetch = new FairJobScheduler(limits.prefetch, {
+      canStart: () => interactive.getPendingCount() === 0 && bulk.getPendingCount() === 0,
+    });
+
+    this.lanes = { interactive, bulk, prefetch };
+  }
+
+  schedule<T>(guildId: string, task: () => Promise<T>, lane: JobLane = 'interactive'): Promise<T> {
+    return this.lanes[lane].schedule(guildId, task);
+  }
+
+  getPendingCount(lane: JobLane, guildId?: string): number {
+    return this.lanes[lane].getPendingCount(guildId);
+  }
+}
+
+export const extractionScheduler = new ExtractionScheduler({
+  interactive: Number(process.env.EXTRACTION_CONCURRENCY) || 3,
+  bulk: Number(process.env.BULK_EXTRACTION_CONCURRENCY) || 2,
+  prefetch: Number(process.env.PREFETCH_CONCURRENCY) || 1,
+});
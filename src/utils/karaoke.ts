@@ -0,0 +1,126 @@
+import { EmbedBuilder, Message } from 'discord.js';
+import { Song } from '../types/index';
+import { QueueManager } from './queueManager';
+import { playbackEvents } from './playbackEvents';
+import { LyricLine, fetchSyncedLyrics, findLineIndex } from './lyrics';
+import { formatTrack } from './titleParser';
+import { Translator } from './i18n';
+
+// Discord allows about five edits per five seconds per channel; this leaves room for the player
+const UPDATE_INTERVAL_MS = 1500;
+// Elapsed time is rounded down to the second, so lines are shown this much early to split the difference
+const LEAD_SECONDS = 0.5;
+const LINES_AFTER = 2;
+
+interface KaraokeSession {
+  message: Message;
+  queueManager: QueueManager;
+  t: Translator;
+  timer: NodeJS.Timeout;
+  /** The song the lyrics below are for. */
+  songUrl?: string;
+  /** Undefined while loading; empty when the song has none. */
+  lines?: LyricLine[];
+  /** The line index last rendered, so unchanged lines aren't sent again. */
+  shownIndex?: number;
+}
+
+function buildLyricsEmbed(song: Song, session: KaraokeSession, index: number): EmbedBuilder {
+  const embed = new EmbedBuilder()
+    .setColor('#1DB954')
+    .setTitle(`🎤 ${formatTrack(song)}`.slice(0, 256))
+    .setFooter({ text: session.t('karaoke.footer') });
+
+  if (!session.lines) return embed.setDescription(session.t('karaoke.searching'));
+  if (session.lines.length === 0) return embed.setDescription(session.t('karaoke.noLyrics'));
+
+  const display = (line: LyricLine) => line.text || '♪';
+  const parts: string[] = [];
+  if (index > 0) parts.push(`-# ${display(session.lines[index - 1])}`);
+  parts.push(index >= 0 ? `## ${display(session.lines[index])}` : '## ♪');
+  for (const line of session.lines.slice(index + 1, index + 1 + LINES_AFTER)) {
+    parts.push(display(line));
+  }
+  return embed.setDescription(parts.join('\n'));
+}
+
+/**
+ * Karaoke messages: one per guild, edited as the song plays to show the lyric
+ * line being sung, from lrclib's timestamped lyrics. A session follows the
+ * queue from song to song and ends when playback does.
+ */
+export class KaraokeSessions {
+  private readonly sessions: Map<string, KaraokeSession> = new Map();
+
+  constructor() {
+    playbackEvents.on('queueEmpty', ({ guildId }) => this.stop(guildId));
+    playbackEvents.on('voiceDisconnected', ({ guildId }) => this.stop(guildId));
+  }
+
+  /** Starts updating `message` with the lyrics, replacing the guild's previous session. */
+  start(guildId: string, message: Message, queueManager: QueueManager, t: Translator): void {
+    this.stop(guildId);
+    const session: KaraokeSession = {
+      message,
+      queueManager,
+      t,
+      timer: setInterval(() => void this.update(guildId, session), UPDATE_INTERVAL_MS),
+    };
+    this.sessions.set(guildId, session);
+    void this.update(guildId, session);
+  }
+
+  /** Ends the guild's session, leaving the message as it last was. Returns false when there was none. */
+  stop(guildId: string): boolean {
+    const session = this.sessions.get(guildId);
+    if (!session) return false;
+    clearInterval(session.timer);
+    this.sessions.delete(guildId);
+    return true;
+  }
+
+  private async update(guildId: string, session: KaraokeSession): Promise<void> {
+    const song = session.queueManager.getCurrentSong(guildId);
+    if (!song) return;
+
+    if (song.url !== session.songUrl) {
+      session.songUrl = song.url;
+      session.lines = undefined;
+      session.shownIndex = undefined;
+      void this.loadLyrics(session, song);
+    }
+
+    // Lyrics are timed against the whole video, while clips count from their start
+    const position = session.queueManager.getSongElapsedTime(guildId) + (song.clip?.start ?? 0) + LEAD_SECONDS;
+    const index = session.lines ? findLineIndex(session.lines, position) : -1;
+    // -2 stands for the loading message, which isn't a line index
+    const renderKey = session.lines ? index : -2;
+    if (renderKey === session.shownIndex) return;
+    session.shownIndex = renderKey;
+
+    try {
+      await session.message.edit({ content: '', embeds: [buildLyricsEmbed(song, session, index)] });
+    } catch (error) {
+      // Most likely the message was deleted, which ends karaoke too
+      console.error(`[KARAOKE] Failed to update lyrics in guild ${guildId}:`, error);
+      if (this.sessions.get(guildId) === session) this.stop(guildId);
+    }
+  }
+
+  private async loadLyrics(session: KaraokeSession, song: Song): Promise<void> {
+    let lines: LyricLine[];
+    try {
+      lines = (await fetchSyncedLyrics(song)) ?? [];
+    } catch (error) {
+      console.error(`[KARAOKE] Lyrics lookup failed for ${song.title}:`, error);
+      lines = [];
+    }
+    // The song may have changed while this was loading
+    if (session.songUrl === song.url) {
+      session.lines = lines;
+      session.shownIndex = undefined;
+    }
+  }
+}
+
+export const karaoke = new KaraokeSessions();
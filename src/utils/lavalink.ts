@@ -0,0 +1,258 @@
+import { EventEmitter } from 'events';
+import WebSocket from 'ws';
+import { VoiceConnectionStatus } from '@discordjs/voice';
+import { GatewayOpcodes, GatewayVoiceStateUpdate } from 'discord.js';
+
+const RECONNECT_DELAY_MS = 5000;
+
+/** Sends a payload over the Discord gateway shard that owns the guild. */
+export type GatewaySend = (payload: GatewayVoiceStateUpdate) => void;
+
+/** Fields of Lavalink v4's update-player request that the bot uses. */
+export interface LavalinkPlayerUpdate {
+  track?: { encoded?: null; identifier?: string };
+  /** Milliseconds. */
+  position?: number;
+  /** Milliseconds; null clears it. */
+  endTime?: number | null;
+  /** Percent, 0-1000. */
+  volume?: number;
+  paused?: boolean;
+  filters?: Record<string, unknown>;
+  voice?: { token: string; endpoint: string; sessionId: string };
+}
+
+export interface LavalinkPlayerListener {
+  /** `reason` is Lavalink's: finished, loadFailed, stopped, replaced or cleanup. */
+  onTrackEnd(reason: string): void;
+}
+
+/**
+ * Stands in for an @discordjs/voice `VoiceConnection` when Lavalink plays the
+ * audio. The bot only asks Discord to join over the gateway and forwards the
+ * voice credentials; Lavalink makes the actual voice connection. Exposes the
+ * same `joinConfig`, `state`, `rejoin` and `destroy` the rest of the bot uses,
+ * and emits status events so `entersState` works on it.
+ */
+export class LavalinkVoiceConnection extends EventEmitter {
+  readonly joinConfig: { guildId: string; channelId: string | null };
+  state: { status: VoiceConnectionStatus } = { status: VoiceConnectionStatus.Signalling };
+  private voiceSessionId?: string;
+  private voiceServer?: { token: string; endpoint: string };
+
+  constructor(
+    private readonly node: LavalinkNode,
+    guildId: string,
+    channelId: string,
+    private readonly send: GatewaySend
+  ) {
+    super();
+    this.joinConfig = { guildId, channelId };
+    this.sendVoiceState();
+  }
+
+  rejoin({ channelId }: { channelId: string }): boolean {
+    this.joinConfig.channelId = channelId;
+    this.setStatus(VoiceConnectionStatus.Signalling);
+    this.sendVoiceState();
+    return true;
+  }
+
+  destroy(): void {
+    if (this.state.status === VoiceConnectionStatus.Destroyed) return;
+    this.joinConfig.channelId = null;
+    this.sendVoiceState();
+    this.node.forget(this.joinConfig.guildId, this);
+    this.node.destroyPlayer(this.joinConfig.guildId).catch((error) =>
+      console.error(`[lavalink] Failed to destroy player for guild ${this.joinConfig.guildId}:`, error)
+    );
+    this.setStatus(VoiceConnectionStatus.Destroyed);
+  }
+
+  handleVoiceStateUpdate(data: { session_id: string; channel_id: string | null }): void {
+    this.voiceSessionId = data.session_id;
+    // Set when someone drags the bot elsewhere; null when it's disconnected
+    if (data.channel_id) {
+      this.joinConfig.channelId = data.channel_id;
+    } else if (this.state.status !== VoiceConnectionStatus.Destroyed) {
+      this.setStatus(VoiceConnectionStatus.Disconnected);
+    }
+  }
+
+  handleVoiceServerUpdate(data: { token: string; endpoint: string | null }): void {
+    // A null endpoint means the voice server is going away; another update follows
+    if (!data.endpoint) return;
+    this.voiceServer = { token: data.token, endpoint: data.endpoint };
+    this.setStatus(VoiceConnectionStatus.Connecting);
+    void this.sendVoiceToLavalink();
+  }
+
+  /** Hands the voice credentials to Lavalink again, e.g. after the node restarted. */
+  async sendVoiceToLavalink(): Promise<void> {
+    if (!this.voiceSessionId || !this.voiceServer || this.state.status === VoiceConnectionStatus.Destroyed) return;
+
+    try {
+      await this.node.updatePlayer(this.joinConfig.guildId, {
+        voice: { ...this.voiceServer, sessionId: this.voiceSessionId },
+      });
+      this.setStatus(VoiceConnectionStatus.Ready);
+    } catch (error) {
+      console.error(`[lavalink] Failed to send voice credentials for guild ${this.joinConfig.guildId}:`, error);
+    }
+  }
+
+  private sendVoiceState(): void {
+    this.send({
+      op: GatewayOpcodes.VoiceStateUpdate,
+      d: { guild_id: this.joinConfig.guildId, channel_id: this.joinConfig.channelId, self_mute: false, self_deaf: true },
+    });
+  }
+
+  private setStatus(status: VoiceConnectionStatus): void {
+    const oldState = this.state;
+    this.state = { status };
+    this.emit('stateChange', oldState, this.state);
+    this.emit(status, oldState, this.state);
+  }
+}
+
+/** A connection to one Lavalink v4 node: its WebSocket for events and its REST API for control. */
+export class LavalinkNode {
+  private socket?: WebSocket;
+  private sessionId?: string;
+  private userId?: string;
+  private readonly connections: Map<string, LavalinkVoiceConnection> = new Map();
+  private readonly listeners: Map<string, LavalinkPlayerListener> = new Map();
+
+  constructor(
+    private readonly baseUrl: string,
+    private readonly password: string
+  ) {}
+
+  /** Opens the WebSocket once the bot knows its own user ID; reconnects on its own afterwards. */
+  start(userId: string): void {
+    this.userId = userId;
+    this.openSocket();
+  }
+
+  /** Asks Discord to join a voice channel; Lavalink connects once the credentials arrive. */
+  connect(guildId: string, channelId: string, send: GatewaySend): LavalinkVoiceConnection {
+    this.connections.get(guildId)?.destroy();
+    const connection = new LavalinkVoiceConnection(this, guildId, channelId, send);
+    this.connections.set(guildId, connection);
+    return connection;
+  }
+
+  forget(guildId: string, connection: LavalinkVoiceConnection): void {
+    if (this.connections.get(guildId) === connection) {
+      this.connections.delete(guildId);
+    }
+  }
+
+  setPlayerListener(guildId: string, listener: LavalinkPlayerListener): void {
+    this.listeners.set(guildId, listener);
+  }
+
+  /** Feed every raw gateway packet here so voice credentials reach Lavalink. */
+  handleRawPacket(packet: { t?: string | null; d?: any }): void {
+    if (packet.t === 'VOICE_STATE_UPDATE' && packet.d?.user_id === this.userId) {
+      this.connections.get(packet.d.guild_id)?.handleVoiceStateUpdate(packet.d);
+    } else if (packet.t === 'VOICE_SERVER_UPDATE') {
+      this.connections.get(packet.d?.guild_id)?.handleVoiceServerUpdate(packet.d);
+    }
+  }
+
+  async updatePlayer(guildId: string, update: LavalinkPlayerUpdate): Promise<void> {
+    await this.request('PATCH', `/v4/sessions/${this.requireSession()}/players/${guildId}`, update);
+  }
+
+  async destroyPlayer(guildId: string): Promise<void> {
+    if (!this.sessionId) return;
+    await this.request('DELETE', `/v4/sessions/${this.sessionId}/players/${guildId}`);
+  }
+
+  private requireSession(): string {
+    if (!this.sessionId) {
+      throw new Error('Not connected to Lavalink yet');
+    }
+    return this.sessionId;
+  }
+
+  private async request(method: string, pathname: string, body?: unknown): Promise<unknown> {
+    const response = await fetch(new URL(pathname, this.baseUrl), {
+      method,
+      headers: { Authorization: this.password, 'Content-Type': 'application/json' },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    if (!response.ok) {
+      const error = (await response.json().catch(() => undefined)) as { message?: string } | undefined;
+      throw new Error(`Lavalink ${method} ${pathname} failed: ${error?.message ?? `HTTP ${response.status}`}`);
+    }
+    return response.status === 204 ? undefined : response.json();
+  }
+
+  private openSocket(): void {
+    const socketUrl = new URL('/v4/websocket', this.baseUrl);
+    socketUrl.protocol = socketUrl.protocol === 'https:' ? 'wss:' : 'ws:';
+
+    const socket = new WebSocket(socketUrl, {
+      headers: { Authorization: this.password, 'User-Id': this.userId!, 'Client-Name': 'GoonBot' },
+    });
+    this.socket = socket;
+
+    socket.on('message', (data) => {
+      try {
+        this.handleMessage(JSON.parse(data.toString()));
+      } catch (error) {
+        console.error('[lavalink] Bad message:', error);
+      }
+    });
+    socket.on('error', (error) => console.error('[lavalink] Socket error:', error.message));
+    socket.on('close', () => {
+      if (this.socket !== socket) return;
+      this.sessionId = undefined;
+      console.error(`[lavalink] Disconnected, retrying in ${RECONNECT_DELAY_MS / 1000}s`);
+      setTimeout(() => this.openSocket(), RECONNECT_DELAY_MS);
+    });
+  }
+
+  private handleMessage(message: any): void {
+    switch (message.op) {
+      case 'ready':
+        this.sessionId = message.sessionId;
+        console.log(`[lavalink] Connected (session ${this.sessionId})`);
+        // A fresh session has no players; give Lavalink the voice credentials again
+        for (const connection of this.connections.values()) {
+          void connection.sendVoiceToLavalink();
+        }
+        return;
+      case 'event':
+        this.handleEvent(message);
+        return;
+    }
+  }
+
+  private handleEvent(event: any): void {
+    const listener = this.listeners.get(event.guildId);
+    switch (event.type) {
+      case 'TrackEndEvent':
+        listener?.onTrackEnd(String(event.reason));
+        return;
+      case 'TrackExceptionEvent':
+        console.error(`[lavalink] Track failed in guild ${event.guildId}:`, event.exception?.message);
+        return;
+      case 'TrackStuckEvent':
+        console.error(`[lavalink] Track stuck in guild ${event.guildId} for ${event.thresholdMs}ms`);
+        return;
+      case 'WebSocketClosedEvent':
+        console.error(`[lavalink] Voice connection closed in guild ${event.guildId}: ${event.code} ${event.reason}`);
+        return;
+    }
+  }
+}
+
+/** The node playback goes through when `PLAYBACK_BACKEND=lavalink`; undefined otherwise. */
+export const lavalink: LavalinkNode | undefined =
+  process.env.PLAYBACK_BACKEND === 'lavalink'
+    ? new LavalinkNode(process.env.LAVALINK_URL || 'http://localhost:2333', process.env.LAVALINK_PASSWORD || 'youshallnotpass')
+    : undefined;
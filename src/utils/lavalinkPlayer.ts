@@ -0,0 +1,167 @@
+import { AudioFilters, Song } from '../types/index';
+import { BandwidthUsage } from './bandwidth';
+import { LavalinkNode, LavalinkPlayerUpdate } from './lavalink';
+import { DEFAULT_FILTERS, MusicPlayer, PlayerConnection } from './musicPlayer';
+import { DEFAULT_VOLUME } from './userPreferences';
+
+// Lavalink reports why a track ended. Stops are announced by stop() itself, like
+// the local player does, so a late event can't end a song started since.
+const IDLE_END_REASONS = new Set(['finished', 'loadFailed']);
+
+/**
+ * Plays through a Lavalink node. Speed, pitch, volume and seeking map onto
+ * Lavalink's own filters and player fields, so they change without a restart.
+ * Loudness normalization, data saver and bandwidth accounting belong to the
+ * local ffmpeg pipeline; here they're remembered but have no effect.
+ */
+export class LavalinkMusicPlayer implements MusicPlayer {
+  private filters: AudioFilters = { ...DEFAULT_FILTERS };
+  private normalizeLoudness = false;
+  private dataSaver = false;
+  private volume = DEFAULT_VOLUME;
+  private volumeResolver?: (song: Song) => number;
+  private currentSong?: Song;
+  private paused = false;
+  private songEndListeners: Array<() => void> = [];
+
+  constructor(
+    private readonly guildId: string,
+    private readonly node: LavalinkNode
+  ) {
+    node.setPlayerListener(guildId, {
+      onTrackEnd: (reason) => {
+        if (IDLE_END_REASONS.has(reason)) this.goIdle();
+      },
+    });
+  }
+
+  async playSong(_voiceConnection: PlayerConnection, song: Song, startAt = 0): Promise<void> {
+    this.currentSong = song;
+    this.volume = this.volumeFor(song);
+    this.paused = false;
+
+    const start = (song.clip?.start ?? 0) + startAt;
+    try {
+      await this.node.updatePlayer(this.guildId, {
+        track: { identifier: song.url },
+        position: Math.round(start * 1000),
+        endTime: song.clip ? Math.round(song.clip.end * 1000) : null,
+        volume: this.volume,
+        paused: false,
+        filters: this.lavalinkFilters(),
+      });
+    } catch (error) {
+      console.error(`Error playing song ${song.title}:`, error);
+      this.currentSong = undefined;
+      throw error;
+    }
+  }
+
+  onSongEnd(listener: () => void): void {
+    this.songEndListeners.push(listener);
+  }
+
+  /** Lavalink loads each track itself, so there's nothing to preload. */
+  setNextSongProvider(_provider: () => Song | undefined): void {}
+
+  /** Lavalink streams tracks directly, so there's no pipeline to put speech in front of. */
+  setAnnouncer(_announcer: (song: Song) => string | undefined): void {}
+
+  /** Lavalink streams tracks directly, so there's no local mix to add a clip to. */
+  async mixIn(_clipPath: string, _restartAt: number): Promise<void> {}
+
+  setVolumeResolver(resolver: (song: Song) => number): void {
+    this.volumeResolver = resolver;
+  }
+
+  getVolume(): number {
+    return this.volume;
+  }
+
+  async refreshVolume(_restartAt?: number): Promise<void> {
+    if (!this.currentSong) return;
+    this.volume = this.volumeFor(this.currentSong);
+    await this.node.updatePlayer(this.guildId, { volume: this.volume });
+  }
+
+  getFilters(): AudioFilters {
+    return { ...this.filters };
+  }
+
+  async setFilters(filters: AudioFilters, _restartAt?: number): Promise<void> {
+    this.filters = { ...filters };
+    if (!this.currentSong) return;
+    await this.node.updatePlayer(this.guildId, { filters: this.lavalinkFilters() });
+  }
+
+  isLoudnessNormalized(): boolean {
+    return this.normalizeLoudness;
+  }
+
+  async setLoudnessNormalization(enabled: boolean, _restartAt?: number): Promise<void> {
+    this.normalizeLoudness = enabled;
+  }
+
+  isDataSaverEnabled(): boolean {
+    return this.dataSaver;
+  }
+
+  setDataSaver(enabled: boolean): void {
+    this.dataSaver = enabled;
+  }
+
+  /** Lavalink streams the audio, so the bot itself uses no media bandwidth. */
+  getBandwidthUsage(): BandwidthUsage {
+    return { sentBytes: 0, downloadedBytes: 0 };
+  }
+
+  resetBandwidthUsage(): BandwidthUsage {
+    return this.getBandwidthUsage();
+  }
+
+  async seek(position: number): Promise<void> {
+    const start = (this.currentSong?.clip?.start ?? 0) + position;
+    await this.node.updatePlayer(this.guildId, { position: Math.round(start * 1000) });
+  }
+
+  stop(): void {
+    if (!this.currentSong) return;
+    this.sendUpdate({ track: { encoded: null } });
+    this.goIdle();
+  }
+
+  pause(): void {
+    this.paused = true;
+    this.sendUpdate({ paused: true });
+  }
+
+  unpause(): void {
+    this.paused = false;
+    this.sendUpdate({ paused: false });
+  }
+
+  isPlaying(): boolean {
+    return this.currentSong !== undefined && !this.paused;
+  }
+
+  private goIdle(): void {
+    this.currentSong = undefined;
+    this.paused = false;
+    for (const listener of this.songEndListeners) listener();
+  }
+
+  private volumeFor(song: Song): number {
+    return this.volumeResolver?.(song) ?? DEFAULT_VOLUME;
+  }
+
+  private lavalinkFilters(): Record<string, unknown> {
+    return { timescale: { speed: this.filters.speed, pitch: this.filters.pitch, rate: 1 } };
+  }
+
+  /** For the synchronous controls; failures are logged rather than thrown. */
+  private sendUpdate(update: LavalinkPlayerUpdate): void {
+    this.node.updatePlayer(this.guildId, update).catch((error) => {
+      console.error(`[lavalink] Player update failed for guild ${this.guildId}:`, error);
+    });
+  }
+}
@@ -0,0 +1,32 @@
+import { Song } from '../types/index';
+import { YouTubeUtil } from './youtubeUtil';
+
+/** How many upcoming songs get their metadata fetched ahead of time. */
+export const METADATA_LOOKAHEAD = 3;
+
+const inFlight = new WeakSet<Song>();
+
+/**
+ * Fills in full metadata for songs queued from a flat playlist listing. Songs
+ * are updated in place so the queue and embeds pick up the new details.
+ */
+export async function resolvePendingMetadata(guildId: string, songs: Song[]): Promise<void> {
+  const pending = songs.filter((song) => song.needsMetadata && !inFlight.has(song));
+
+  await Promise.all(
+    pending.map(async (song) => {
+      inFlight.add(song);
+      try {
+        const info = await YouTubeUtil.fetchSongInfo(song.url, song.addedBy, guildId, 'prefetch');
+        // fetchSongInfo falls back to placeholder metadata; the flat listing is better than that
+        if (info.id !== 'unknown') {
+          // An album listing knows the album even when the video's own details don't
+          Object.assign(song, { ...info, album: info.album ?? song.album, addedBy: song.addedBy });
+        }
+      } finally {
+        song.needsMetadata = undefined;
+        inFlight.delete(song);
+      }
+    })
+  );
+}
@@ -0,0 +1,198 @@
+import { Song } from '../types/index';
+import { DocumentSchema, Storage } from './storage';
+import { fuzzyScore } from './fuzzySearch';
+
+export interface PlayTotals {
+  /** Songs started. For a member, songs they requested. */
+  plays: number;
+  /** Seconds of music played. For a member, seconds they were in the channel for. */
+  listenedSeconds: number;
+}
+
+export interface SongStats {
+  title: string;
+  url: string;
+  plays: number;
+  /** The uploading channel, when known. */
+  channel?: string;
+  /** When it last started, in epoch milliseconds; unset for songs last played before this was recorded. */
+  lastPlayedAt?: number;
+}
+
+export interface UserListeningStats extends PlayTotals {
+  /** Songs the member requested that were played, by URL. */
+  songs: Record<string, SongStats>;
+}
+
+export interface PlayRecord {
+  /** When the song started, in epoch milliseconds. */
+  at: number;
+  url: string;
+  title: string;
+  /** Who requested it. */
+  userId: string;
+}
+
+export interface GuildListeningStats extends PlayTotals {
+  songs: Record<string, SongStats>;
+  users: Record<string, UserListeningStats>;
+  /** Every play within RECENT_PLAYS_MAX_AGE_MS, oldest first, for per-period rankings. */
+  recentPlays: PlayRecord[];
+}
+
+/** A period that plays can be ranked over. */
+export type StatsPeriod = 'week' | 'month' | 'all';
+
+export const STATS_PERIOD_MS: Record<Exclude<StatsPeriod, 'all'>, number> = {
+  week: 7 * 24 * 60 * 60 * 1000,
+  month: 30 * 24 * 60 * 60 * 1000,
+};
+
+// Plays are only kept individually for as long as the longest period needs them
+const RECENT_PLAYS_MAX_AGE_MS = STATS_PERIOD_MS.month;
+
+export type ListeningStatsDocument = Record<string, GuildListeningStats>;
+
+export const LISTENING_STATS_SCHEMA: DocumentSchema<ListeningStatsDocument> = {
+  name: 'listening-stats',
+  version: 2,
+  migrations: {
+    // Individual plays weren't kept before, so per-period rankings start empty
+    1: (data: any) => {
+      for (const guild of Object.values<any>(data ?? {})) {
+        guild.recentPlays = [];
+      }
+      return data;
+    },
+  },
+  defaultValue: () => ({}),
+};
+
+function createGuildStats(): GuildListeningStats {
+  return { plays: 0, listenedSeconds: 0, songs: {}, users: {}, recentPlays: [] };
+}
+
+function createUserStats(): UserListeningStats {
+  return { plays: 0, listenedSeconds: 0, songs: {} };
+}
+
+function countPlay(songs: Record<string, SongStats>, song: Song): void {
+  const stats = (songs[song.url] ??= { title: song.title, url: song.url, plays: 0 });
+  // Titles get cleaned up over time, so the latest one wins
+  stats.title = song.title;
+  stats.channel = song.channel ?? stats.channel;
+  stats.lastPlayedAt = Date.now();
+  stats.plays++;
+}
+
+/** The most played songs first; ties go to the title that sorts first. */
+export function rankSongs(songs: Record<string, SongStats>, limit: number): SongStats[] {
+  return Object.values(songs)
+    .sort((a, b) => b.plays - a.plays || a.title.localeCompare(b.title))
+    .slice(0, limit);
+}
+
+export interface PeriodRanking {
+  songs: SongStats[];
+  /** Requesters by songs played, most first. */
+  requesters: { userId: string; plays: number }[];
+}
+
+/** Play counts and listening time per guild and per member, kept across restarts. */
+export class ListeningStatsManager {
+  private stats: ListeningStatsDocument = {};
+
+  constructor(private readonly storage: Storage) {
+    try {
+      this.stats = storage.load(LISTENING_STATS_SCHEMA);
+    } catch (error) {
+      console.error('[STATS] Failed to load listening stats:', error);
+    }
+  }
+
+  getGuildStats(guildId: string): GuildListeningStats {
+    return this.stats[guildId] ?? createGuildStats();
+  }
+
+  getUserStats(guildId: string, userId: string): UserListeningStats {
+    return this.stats[guildId]?.users[userId] ?? createUserStats();
+  }
+
+  /** Counts a song starting, for the guild and for whoever requested it. */
+  recordPlay(guildId: string, song: Song): void {
+    const guild = this.getOrCreateGuildStats(guildId);
+    const requester = (guild.users[song.addedBy] ??= createUserStats());
+    guild.plays++;
+    requester.plays++;
+    countPlay(guild.songs, song);
+    countPlay(requester.songs, song);
+
+    const now = Date.now();
+    guild.recentPlays.push({ at: now, url: song.url, title: song.title, userId: song.addedBy });
+    const firstKept = guild.recentPlays.findIndex((play) => play.at > now - RECENT_PLAYS_MAX_AGE_MS);
+    guild.recentPlays.splice(0, firstKept);
+    this.save();
+  }
+
+  /** The most played songs and most active requesters over `period`, up to `limit` of each. */
+  rank(guildId: string, period: StatsPeriod, limit: number): PeriodRanking {
+    const guild = this.getGuildStats(guildId);
+    const requesterPlays = new Map<string, number>();
+    let songs: Record<string, SongStats>;
+
+    if (period === 'all') {
+      songs = guild.songs;
+      for (const [userId, user] of Object.entries(guild.users)) {
+        if (user.plays > 0) requesterPlays.set(userId, user.plays);
+      }
+    } else {
+      songs = {};
+      const since = Date.now() - STATS_PERIOD_MS[period];
+      for (const play of guild.recentPlays) {
+        if (play.at <= since) continue;
+        const stats = (songs[play.url] ??= { title: play.title, url: play.url, plays: 0 });
+        stats.title = play.title;
+        stats.plays++;
+        requesterPlays.set(play.userId, (requesterPlays.get(play.userId) ?? 0) + 1);
+      }
+    }
+
+    const requesters = [...requesterPlays]
+      .map(([userId, plays]) => ({ userId, plays }))
+      .sort((a, b) => b.plays - a.plays)
+      .slice(0, limit);
+    return { songs: rankSongs(songs, limit), requesters };
+  }
+
+  /**
+   * Songs the guild has ever played whose title or channel fuzzily matches
+   * `query`, best match first and then most recently played.
+   */
+  searchPlayed(guildId: string, query: string, limit: number): SongStats[] {
+    return Object.values(this.getGuildStats(guildId).songs)
+      .map((song) => ({ song, score: fuzzyScore(query, `${song.title} ${song.channel ?? ''}`) }))
+      .filter(({ score }) => score > 0)
+      .sort((a, b) => b.score - a.score || (b.song.lastPlayedAt ?? 0) - (a.song.lastPlayedAt ?? 0))
+      .slice(0, limit)
+      .map(({ song }) => song);
+  }
+
+  /** Adds time a song played to the guild and to everyone who was listening. */
+  recordListening(guildId: string, seconds: number, listenerIds: string[]): void {
+    if (seconds <= 0) return;
+    const guild = this.getOrCreateGuildStats(guildId);
+    guild.listenedSeconds += seconds;
+    for (const userId of listenerIds) {
+      (guild.users[userId] ??= createUserStats()).listenedSeconds += seconds;
+    }
+    this.save();
+  }
+
+  private getOrCreateGuildStats(guildId: string): GuildListeningStats {
+    return (this.stats[guildId] ??= createGuildStats());
+  }
+
+  private save(): void {
+    this.storage.scheduleSave(LISTENING_STATS_SCHEMA, () => this.stats);
+  }
+}
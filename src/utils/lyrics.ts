@@ -0,0 +1,86 @@
+import { Song } from '../types/index';
+
+const LRCLIB_URL = (process.env.LRCLIB_URL || 'https://lrclib.net').replace(/\/+$/, '');
+const REQUEST_TIMEOUT_MS = 10_000;
+// Search results this far off the song's length are probably a different version
+const DURATION_TOLERANCE_SECONDS = 5;
+const CACHE_SIZE = 100;
+
+/** One line of timestamped lyrics. */
+export interface LyricLine {
+  /** When the line starts, in seconds into the song. */
+  time: number;
+  text: string;
+}
+
+/** Parses LRC (`[mm:ss.xx] text`) into lines sorted by time. Lines with several stamps appear once per stamp. */
+export function parseSyncedLyrics(lrc: string): LyricLine[] {
+  const lines: LyricLine[] = [];
+  for (const raw of lrc.split(/\r?\n/)) {
+    const stamps = [...raw.matchAll(/\[(\d+):(\d+(?:\.\d+)?)\]/g)];
+    if (stamps.length === 0) continue;
+    const text = raw.replace(/\[[^\]]*\]/g, '').trim();
+    for (const [, minutes, seconds] of stamps) {
+      lines.push({ time: Number(minutes) * 60 + Number(seconds), text });
+    }
+  }
+  return lines.sort((a, b) => a.time - b.time);
+}
+
+/** The index of the line being sung at `position` seconds, or -1 before the first one. */
+export function findLineIndex(lines: LyricLine[], position: number): number {
+  let index = -1;
+  for (let i = 0; i < lines.length && lines[i].time <= position; i++) index = i;
+  return index;
+}
+
+async function getJson(url: URL): Promise<any> {
+  // lrclib asks clients to identify themselves
+  const response = await fetch(url, {
+    headers: { 'User-Agent': 'GoonBot (discord-music-bot)' },
+    signal: AbortSignal.timeout(REQUEST_TIMEOUT_MS),
+  });
+  if (response.status === 404) return undefined;
+  if (!response.ok) throw new Error(`${url.host} answered HTTP ${response.status}`);
+  return response.json();
+}
+
+function closeInLength(record: any, song: Song): boolean {
+  return song.duration <= 0 || Math.abs(Number(record.duration) - song.duration) <= DURATION_TOLERANCE_SECONDS;
+}
+
+/** Asks lrclib for synced lyrics: an exact lookup when artist and track are known, else a search by title. */
+async function fetchFromLrclib(song: Song): Promise<string | undefined> {
+  if (song.artist && song.track) {
+    const url = new URL(`${LRCLIB_URL}/api/get`);
+    url.searchParams.set('artist_name', song.artist);
+    url.searchParams.set('track_name', song.track);
+    if (song.duration > 0) url.searchParams.set('duration', String(Math.round(song.duration)));
+    const record = await getJson(url);
+    if (typeof record?.syncedLyrics === 'string') return record.syncedLyrics;
+  }
+
+  const url = new URL(`${LRCLIB_URL}/api/search`);
+  url.searchParams.set('q', song.artist && song.track ? `${song.artist} ${song.track}` : (song.track ?? song.title));
+  const results = await getJson(url);
+  const match = (Array.isArray(results) ? results : []).find(
+    (record: any) => typeof record?.syncedLyrics === 'string' && closeInLength(record, song)
+  );
+  return match?.syncedLyrics;
+}
+
+// Lookups by song URL, including misses, so replays and loops don't ask again
+const cache = new Map<string, LyricLine[] | undefined>();
+
+/** Timestamped lyrics for the song, or undefined when lrclib has none. Throws when lrclib can't be reached. */
+export async function fetchSyncedLyrics(song: Song): Promise<LyricLine[] | undefined> {
+  if (cache.has(song.url)) return cache.get(song.url);
+
+  const lrc = await fetchFromLrclib(song);
+  const lines = lrc ? parseSyncedLyrics(lrc) : [];
+  const result = lines.length > 0 ? lines : undefined;
+
+  if (cache.size >= CACHE_SIZE) cache.delete(cache.keys().next().value!);
+  cache.set(song.url, result);
+  return result;
+}
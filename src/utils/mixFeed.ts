@@ -0,0 +1,108 @@
+import { BlacklistEntry, Song } from '../types/index';
+import { QueueManager } from './queueManager';
+import { YouTubeUtil } from './youtubeUtil';
+import { extractVideoId } from './frontendApis';
+import { filterBlacklisted } from './blacklist';
+
+// Songs queued per lookup; a mix lists about 25-50 at a time
+const MIX_BATCH_SIZE = 10;
+// Upcoming songs left when the next batch is fetched, so it's queued before the queue runs dry
+const REFILL_BELOW = 3;
+
+interface MixFeed {
+  userId: string;
+  /** The next batch comes from this video's mix, so the feed drifts along with what's playing. */
+  lastVideoId: string;
+  /** Every video queued from the feed, so a mix circling back isn't queued twice. */
+  seen: Set<string>;
+  /** The queue generation the feed started in; clearing the queue ends it. */
+  generation: number;
+  refilling: boolean;
+}
+
+export function mixUrl(videoId: string): string {
+  return `https://www.youtube.com/watch?v=${videoId}&list=RD${videoId}`;
+}
+
+/** The video a YouTube Mix link (`watch?v=ID&list=RDID`) starts from; undefined for other links. */
+export function getMixSeed(url: string): string | undefined {
+  try {
+    const list = new URL(url).searchParams.get('list');
+    if (!list?.startsWith('RD')) return undefined;
+    const fromList = list.slice(2);
+    return extractVideoId(url) ?? (/^[\w-]{11}$/.test(fromList) ? fromList : undefined);
+  } catch {
+    return undefined;
+  }
+}
+
+/**
+ * Keeps guilds' queues topped up from YouTube Mixes. A mix only lists a few
+ * dozen songs, so each batch comes from the mix of the last song queued,
+ * fetched once the queue runs low. One mix per guild; it ends when the
+ * queue is cleared, the bot leaves voice, or YouTube runs out of new songs.
+ */
+export class MixFeeds {
+  private readonly feeds: Map<string, MixFeed> = new Map();
+
+  /** Starts a mix from `seedVideoId`, replacing any running in the guild, and returns its first batch. */
+  async start(guildId: string, seedVideoId: string, userId: string, queueManager: QueueManager): Promise<Song[]> {
+    const feed: MixFeed = {
+      userId,
+      lastVideoId: seedVideoId,
+      seen: new Set([seedVideoId]),
+      generation: queueManager.getGeneration(guildId),
+      refilling: false,
+    };
+    this.feeds.set(guildId, feed);
+    return this.takeBatch(guildId, feed);
+  }
+
+  /** Returns whether a mix was running. */
+  stop(guildId: string): boolean {
+    return this.feeds.delete(guildId);
+  }
+
+  isActive(guildId: string, queueManager: QueueManager): boolean {
+    return Boolean(this.getFeed(guildId, queueManager));
+  }
+
+  /** Queues the next batch if a mix is running and fewer than a few songs are left. Returns how many were added. */
+  async refill(guildId: string, queueManager: QueueManager, blacklist: BlacklistEntry[]): Promise<number> {
+    const feed = this.getFeed(guildId, queueManager);
+    if (!feed || feed.refilling || queueManager.getQueueSize(guildId) >= REFILL_BELOW) return 0;
+
+    feed.refilling = true;
+    try {
+      const batch = await this.takeBatch(guildId, feed);
+      if (batch.length === 0) {
+        console.log(`[MIX] Mix for ${guildId} ran out of new songs`);
+        this.feeds.delete(guildId);
+        return 0;
+      }
+      return queueManager.addSongs(guildId, filterBlacklisted(batch, blacklist).allowed, feed.generation);
+    } finally {
+      feed.refilling = false;
+    }
+  }
+
+  // A feed from before the queue was last cleared is over
+  private getFeed(guildId: string, queueManager: QueueManager): MixFeed | undefined {
+    const feed = this.feeds.get(guildId);
+    if (feed && feed.generation !== queueManager.getGeneration(guildId)) {
+      this.feeds.delete(guildId);
+      return undefined;
+    }
+    return feed;
+  }
+
+  private async takeBatch(guildId: string, feed: MixFeed): Promise<Song[]> {
+    const entries = await YouTubeUtil.fetchPlaylistEntries(mixUrl(feed.lastVideoId), feed.userId, guildId);
+    const batch = entries.filter((song) => !feed.seen.has(song.id)).slice(0, MIX_BATCH_SIZE);
+    for (const song of batch) feed.seen.add(song.id);
+    if (batch.length > 0) feed.lastVideoId = batch[batch.length - 1].id;
+    return batch;
+  }
+}
+
+export const mixFeeds = new MixFeeds();
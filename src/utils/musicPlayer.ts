@@ -1,111 +1,478 @@
 import { AudioPlayer, AudioPlayerStatus, StreamType, VoiceConnection, createAudioPlayer, createAudioResource } from '@discordjs/voice';
-import { Song } from '../types/index';
-import { ChildProcess, execFile, spawn } from 'child_process';
+import { AudioFilters, Song } from '../types/index';
+import { ChildProcess, spawn } from 'child_process';
 import { PassThrough } from 'stream';
-import { promisify } from 'util';
+import { AudioInput } from './audioInput';
+import { extractorForSong } from './sourceExtractor';
+import { BandwidthUsage } from './bandwidth';
+import { DEFAULT_VOLUME } from './userPreferences';
+import { synthesizeSpeech } from './tts';
+import { INTRO_VOLUME } from './intros';
+import type { LavalinkVoiceConnection } from './lavalink';
 
-const execFileAsync = promisify(execFile);
+const OUTPUT_SAMPLE_RATE = 48000;
+// EBU R128 targets: integrated loudness, true peak and loudness range
+const LOUDNORM_FILTER = 'loudnorm=I=-16:TP=-1.5:LRA=11';
+// Fixed Opus bitrates, which also let downloaded bytes be estimated from bytes sent
+const OUTPUT_BITRATE_KBPS = 96;
+const DATA_SAVER_OUTPUT_BITRATE_KBPS = 48;
+// Typical bestaudio bitrate, for sources that don't report one
+const ASSUMED_SOURCE_BITRATE_KBPS = 128;
+// The next song's pipeline is started this long before the current one ends
+const PRELOAD_BEFORE_END_SECONDS = 30;
 
-export class MusicPlayer {
+export const DEFAULT_FILTERS: AudioFilters = { speed: 1, pitch: 1 };
+
+/** Builds the ffmpeg `-af` chain for the given filters, or undefined when none apply. */
+function buildFilterChain({ speed, pitch }: AudioFilters, normalizeLoudness: boolean, volume: number): string | undefined {
+  const chain: string[] = [];
+
+  // loudnorm resamples to 192kHz internally, so bring it back down afterwards
+  if (normalizeLoudness) {
+    chain.push(LOUDNORM_FILTER, `aresample=${OUTPUT_SAMPLE_RATE}`);
+  }
+
+  // asetrate shifts pitch and speed together; atempo then corrects the speed
+  if (pitch !== 1) {
+    chain.push(
+      `aresample=${OUTPUT_SAMPLE_RATE}`,
+      `asetrate=${Math.round(OUTPUT_SAMPLE_RATE * pitch)}`,
+      `aresample=${OUTPUT_SAMPLE_RATE}`
+    );
+  }
+
+  // atempo only accepts factors between 0.5 and 2, so larger changes are chained
+  let tempo = speed / pitch;
+  while (tempo > 2) {
+    chain.push('atempo=2');
+    tempo /= 2;
+  }
+  while (tempo < 0.5) {
+    chain.push('atempo=0.5');
+    tempo /= 0.5;
+  }
+  if (Math.abs(tempo - 1) > 0.001) {
+    chain.push(`atempo=${tempo.toFixed(4)}`);
+  }
+
+  // Last, so normalization doesn't undo it
+  if (volume !== DEFAULT_VOLUME) {
+    chain.push(`volume=${(volume / 100).toFixed(2)}`);
+  }
+
+  return chain.length > 0 ? chain.join(',') : undefined;
+}
+
+interface Pipeline {
+  process: ChildProcess;
+  stream: PassThrough;
+  input: AudioInput;
+  /** Volume in percent the pipeline was built with. */
+  volume: number;
+}
+
+function killPipeline(pipeline: Pipeline): void {
+  if (!pipeline.process.killed) {
+    pipeline.process.kill('SIGKILL');
+  }
+  pipeline.input.release();
+}
+
+/**
+ * An ffmpeg filter graph that plays the spoken announcement (input 1) and
+ * then the song (input 0) with its usual filters. Both are brought to the
+ * same format first, since concat needs matching streams.
+ */
+function buildAnnouncementGraph(songFilterChain: string | undefined): string {
+  const format = `aresample=${OUTPUT_SAMPLE_RATE},aformat=sample_fmts=fltp:channel_layouts=stereo`;
+  return [
+    `[0:a]${songFilterChain ? `${songFilterChain},` : ''}${format}[music]`,
+    `[1:a]${format}[speech]`,
+    '[speech][music]concat=n=2:v=0:a=1[out]',
+  ].join(';');
+}
+
+/**
+ * An ffmpeg filter graph that plays the song (input 0) with its usual filters
+ * and a join intro (input 1) mixed in quietly on top. amix would otherwise
+ * lower the music while the intro plays, hence `normalize=0`.
+ */
+function buildIntroGraph(songFilterChain: string | undefined): string {
+  const format = `aresample=${OUTPUT_SAMPLE_RATE},aformat=sample_fmts=fltp:channel_layouts=stereo`;
+  return [
+    `[0:a]${songFilterChain ? `${songFilterChain},` : ''}${format}[music]`,
+    `[1:a]${format},volume=${INTRO_VOLUME}[intro]`,
+    '[music][intro]amix=inputs=2:duration=first:dropout_transition=0:normalize=0[out]',
+  ].join(';');
+}
+
+/** A guild's voice connection: a real one, or Lavalink's stand-in when Lavalink plays the audio. */
+export type PlayerConnection = VoiceConnection | LavalinkVoiceConnection;
+
+/**
+ * Plays one guild's songs. Commands only see this interface, so playback can
+ * run locally through yt-dlp and ffmpeg or on a Lavalink node.
+ */
+export interface MusicPlayer {
+  playSong(voiceConnection: PlayerConnection, song: Song, startAt?: number): Promise<void>;
+  /** Called when the player goes idle: the song ended, failed to load or was stopped. */
+  onSongEnd(listener: () => void): void;
+  setNextSongProvider(provider: () => Song | undefined): void;
+  setVolumeResolver(resolver: (song: Song) => number): void;
+  /** Picks what to say in voice before each song that starts from the beginning; undefined says nothing. */
+  setAnnouncer(announcer: (song: Song) => string | undefined): void;
+  /** Restarts the current song from `restartAt` with the audio file at `clipPath` mixed in over its start. */
+  mixIn(clipPath: string, restartAt: number): Promise<void>;
+  getVolume(): number;
+  refreshVolume(restartAt?: number): Promise<void>;
+  getFilters(): AudioFilters;
+  setFilters(filters: AudioFilters, restartAt?: number): Promise<void>;
+  isLoudnessNormalized(): boolean;
+  setLoudnessNormalization(enabled: boolean, restartAt?: number): Promise<void>;
+  isDataSaverEnabled(): boolean;
+  setDataSaver(enabled: boolean): void;
+  getBandwidthUsage(): BandwidthUsage;
+  resetBandwidthUsage(): BandwidthUsage;
+  seek(position: number): Promise<void>;
+  stop(): void;
+  pause(): void;
+  unpause(): void;
+  isPlaying(): boolean;
+}
+
+/** Streams through yt-dlp and ffmpeg on this host. */
+export class LocalMusicPlayer implements MusicPlayer {
   private audioPlayer: AudioPlayer;
-  private ffmpegProcess?: ChildProcess;
+  private pipeline?: Pipeline;
+  private filters: AudioFilters = { ...DEFAULT_FILTERS };
+  private normalizeLoudness = false;
+  private dataSaver = false;
+  private bandwidth: BandwidthUsage = { sentBytes: 0, downloadedBytes: 0 };
+  // Volume in percent for the current song, and how to pick it for each song
+  private volume = DEFAULT_VOLUME;
+  private volumeResolver?: (song: Song) => number;
+  private announcer?: (song: Song) => string | undefined;
+  // Remembered so the pipeline can be rebuilt when filters change mid-song
+  private currentSong?: Song;
+  private voiceConnection?: VoiceConnection;
+  // Gapless playback: the next song's pipeline, started near the end of the current one
+  private nextSongProvider?: () => Song | undefined;
+  private preloaded?: { song: Song; pipeline: Promise<Pipeline> };
+  private preloadTimer?: NodeJS.Timeout;
 
-  constructor() {
+  constructor(private readonly guildId: string) {
     this.audioPlayer = createAudioPlayer();
     this.audioPlayer.on('error', (error) => {
       console.error('[audio-player] Error:', error.message);
     });
   }
 
-  async playSong(voiceConnection: VoiceConnection, song: Song): Promise<void> {
+  async playSong(voiceConnection: VoiceConnection, song: Song, startAt = 0): Promise<void> {
+    await this.play(voiceConnection, song, startAt, startAt === 0);
+  }
+
+  private async play(
+    voiceConnection: VoiceConnection,
+    song: Song,
+    startAt: number,
+    announce: boolean,
+    overlayPath?: string
+  ): Promise<void> {
     try {
       this.cleanupProcesses();
-      const streamData = await this.getAudioStream(song);
-      const resource = createAudioResource(streamData, {
+      this.currentSong = song;
+      this.voiceConnection = voiceConnection;
+      this.volume = this.volumeFor(song);
+      const pipeline =
+        (await this.takePreloaded(song, startAt)) ??
+        (await this.startPipeline(song, startAt, this.volume, announce, overlayPath));
+      this.pipeline = pipeline;
+      const resource = createAudioResource(pipeline.stream, {
         inputType: StreamType.OggOpus,
       });
 
       this.audioPlayer.play(resource);
       voiceConnection.subscribe(this.audioPlayer);
+      this.schedulePreload(song, startAt);
     } catch (error) {
       console.error(`Error playing song ${song.title}:`, error);
       throw error;
     }
   }
 
-  private async resolveStreamUrl(url: string): Promise<string> {
-    const { stdout } = await execFileAsync('yt-dlp', [
-      '-f',
-      'bestaudio',
-      '-g',
-      '--no-playlist',
-      url,
-    ]);
+  /** Tells the player which song comes next so it can be preloaded for gapless playback. */
+  setNextSongProvider(provider: () => Song | undefined): void {
+    this.nextSongProvider = provider;
+  }
+
+  /** Decides each song's volume, e.g. from the requester's preference, when it starts. */
+  setVolumeResolver(resolver: (song: Song) => number): void {
+    this.volumeResolver = resolver;
+  }
+
+  setAnnouncer(announcer: (song: Song) => string | undefined): void {
+    this.announcer = announcer;
+  }
+
+  async mixIn(clipPath: string, restartAt: number): Promise<void> {
+    if (!this.currentSong || !this.voiceConnection) return;
+    await this.play(this.voiceConnection, this.currentSong, restartAt, false, clipPath);
+  }
+
+  getVolume(): number {
+    return this.volume;
+  }
+
+  /**
+   * Asks the resolver again after a volume setting changed. The current song
+   * restarts from `restartAt` when its volume changes; the next one is rebuilt.
+   */
+  async refreshVolume(restartAt?: number): Promise<void> {
+    this.discardPreloaded();
+    if (!this.currentSong || this.volumeFor(this.currentSong) === this.volume) return;
+    await this.restartCurrentSong(restartAt);
+  }
+
+  getFilters(): AudioFilters {
+    return { ...this.filters };
+  }
+
+  /**
+   * Changes the audio filters. With `restartAt` (in song seconds) the current
+   * song's pipeline is rebuilt from there so the change is heard immediately;
+   * otherwise the filters apply from the next song.
+   */
+  async setFilters(filters: AudioFilters, restartAt?: number): Promise<void> {
+    this.filters = { ...filters };
+    this.discardPreloaded();
+    await this.restartCurrentSong(restartAt);
+  }
+
+  isLoudnessNormalized(): boolean {
+    return this.normalizeLoudness;
+  }
+
+  /** Turns loudness normalization on or off; `restartAt` works as in setFilters. */
+  async setLoudnessNormalization(enabled: boolean, restartAt?: number): Promise<void> {
+    this.normalizeLoudness = enabled;
+    this.discardPreloaded();
+    await this.restartCurrentSong(restartAt);
+  }
+
+  isDataSaverEnabled(): boolean {
+    return this.dataSaver;
+  }
+
+  /** Switches between full and low-bitrate formats, starting with the next song. */
+  setDataSaver(enabled: boolean): void {
+    this.dataSaver = enabled;
+    this.discardPreloaded();
+  }
+
+  getBandwidthUsage(): BandwidthUsage {
+    return { ...this.bandwidth };
+  }
+
+  /** Starts counting a new session, returning the usage of the one that ended. */
+  resetBandwidthUsage(): BandwidthUsage {
+    const usage = this.getBandwidthUsage();
+    this.bandwidth = { sentBytes: 0, downloadedBytes: 0 };
+    return usage;
+  }
+
+  /** Restarts the current song from `position` seconds. */
+  async seek(position: number): Promise<void> {
+    await this.restartCurrentSong(position);
+  }
+
+  private async restartCurrentSong(restartAt?: number): Promise<void> {
+    if (restartAt !== undefined && this.currentSong && this.voiceConnection) {
+      // Restarts pick up mid-song, so the announcement isn't repeated
+      await this.play(this.voiceConnection, this.currentSong, restartAt, false);
+    }
+  }
+
+  private schedulePreload(song: Song, startAt: number): void {
+    this.clearPreloadTimer();
+    // Songs still waiting on metadata have no known length
+    if (!this.nextSongProvider || song.duration <= 0) return;
+
+    const remainingSeconds = (song.duration - startAt) / this.filters.speed;
+    const delayMs = Math.max(0, (remainingSeconds - PRELOAD_BEFORE_END_SECONDS) * 1000);
+    this.preloadTimer = setTimeout(() => this.preloadNext(), delayMs);
+  }
+
+  private preloadNext(): void {
+    this.preloadTimer = undefined;
+    const nextSong = this.nextSongProvider?.();
+    if (!nextSong || this.preloaded?.song === nextSong) return;
+
+    this.discardPreloaded();
+    // ffmpeg stalls once the buffer is full, so only the first few seconds are fetched early
+    const pipeline = this.startPipeline(nextSong, 0, this.volumeFor(nextSong), true);
+    pipeline.catch((error) => {
+      console.error(`Error preloading ${nextSong.title}:`, error);
+      if (this.preloaded?.pipeline === pipeline) {
+        this.preloaded = undefined;
+      }
+    });
+    this.preloaded = { song: nextSong, pipeline };
+  }
 
-    const streamUrl = stdout
-      .split('\n')
-      .map((line) => line.trim())
-      .find((line) => line.length > 0);
+  /** Returns the preloaded pipeline if it's for this song from the start; otherwise discards it. */
+  private async takePreloaded(song: Song, startAt: number): Promise<Pipeline | undefined> {
+    const preloaded = this.preloaded;
+    this.preloaded = undefined;
+    if (!preloaded) return undefined;
 
-    if (!streamUrl) {
-      throw new Error('yt-dlp did not return a direct stream URL');
+    if (preloaded.song !== song || startAt !== 0) {
+      preloaded.pipeline.then(killPipeline, () => undefined);
+      return undefined;
     }
 
-    return streamUrl;
+    try {
+      const pipeline = await preloaded.pipeline;
+      // Who is listening may have changed since it was preloaded
+      if (pipeline.volume !== this.volume) {
+        killPipeline(pipeline);
+        return undefined;
+      }
+      return pipeline.process.exitCode === null ? pipeline : undefined;
+    } catch {
+      return undefined;
+    }
+  }
+
+  private discardPreloaded(): void {
+    this.preloaded?.pipeline.then(killPipeline, () => undefined);
+    this.preloaded = undefined;
   }
 
-  private async getAudioStream(song: Song) {
-    const streamUrl = await this.resolveStreamUrl(song.url);
+  private clearPreloadTimer(): void {
+    if (this.preloadTimer) {
+      clearTimeout(this.preloadTimer);
+      this.preloadTimer = undefined;
+    }
+  }
 
-    this.ffmpegProcess = spawn(
+  private volumeFor(song: Song): number {
+    return this.volumeResolver?.(song) ?? DEFAULT_VOLUME;
+  }
+
+  private async startPipeline(
+    song: Song,
+    startAt: number,
+    volume: number,
+    announce: boolean,
+    overlayPath?: string
+  ): Promise<Pipeline> {
+    let measuredDownload = false;
+    const input = await extractorForSong(song).stream(song, this.guildId, {
+      dataSaver: this.dataSaver,
+      onDownloaded: (bytes) => {
+        measuredDownload = true;
+        this.bandwidth.downloadedBytes += bytes;
+      },
+    });
+    // After opening the input, so an input that fails to open doesn't leave the speech file behind
+    const announcement = announce ? this.announcer?.(song) : undefined;
+    const speech = announcement ? await synthesizeSpeech(announcement) : undefined;
+    const filterChain = buildFilterChain(this.filters, this.normalizeLoudness, volume);
+    const outputBitrateKbps = this.dataSaver ? DATA_SAVER_OUTPUT_BITRATE_KBPS : OUTPUT_BITRATE_KBPS;
+    const sourceBitrateKbps = input.sourceBitrateKbps ?? ASSUMED_SOURCE_BITRATE_KBPS;
+
+    // Clips play part of the video; positions within them are relative to the clip start.
+    // Lives always rejoin the broadcast where it is now
+    const seekPosition = song.isLive ? 0 : (song.clip?.start ?? 0) + startAt;
+    // A dropped connection would otherwise end a live stream as if the broadcast had
+    const reconnect = song.isLive && /^https?:/.test(input.path);
+
+    const ffmpegProcess = spawn(
       'ffmpeg',
       [
         '-hide_banner',
         '-loglevel',
         'error',
+        ...(seekPosition > 0 ? ['-ss', String(seekPosition)] : []),
+        ...(input.httpProxy ? ['-http_proxy', input.httpProxy] : []),
+        ...(reconnect ? ['-reconnect', '1', '-reconnect_streamed', '1', '-reconnect_delay_max', '5'] : []),
+        ...(song.clip ? ['-t', String(Math.max(0, song.clip.end - seekPosition))] : []),
         '-i',
-        streamUrl,
+        input.path,
+        ...(speech ? ['-i', speech.path, '-filter_complex', buildAnnouncementGraph(filterChain), '-map', '[out]'] : []),
+        ...(!speech && overlayPath
+          ? ['-i', overlayPath, '-filter_complex', buildIntroGraph(filterChain), '-map', '[out]']
+          : []),
+        ...(!speech && !overlayPath && filterChain ? ['-af', filterChain] : []),
         '-vn',
         '-acodec',
         'libopus',
+        '-b:a',
+        `${outputBitrateKbps}k`,
         '-f',
         'ogg',
         'pipe:1',
       ],
-      { stdio: ['ignore', 'pipe', 'pipe'] }
+      { stdio: [input.source || input.stream ? 'pipe' : 'ignore', 'pipe', 'pipe'] }
     );
 
-    if (!this.ffmpegProcess.stdout) {
+    if (!ffmpegProcess.stdout) {
+      input.release();
+      speech?.release();
       throw new Error('Failed to initialize ffmpeg output stream');
     }
 
-    if (this.ffmpegProcess.stderr) {
-      this.ffmpegProcess.stderr.on('data', (chunk) => {
+    const stdinSource = input.source?.stdout ?? input.stream;
+    if (stdinSource && ffmpegProcess.stdin) {
+      // ffmpeg closes stdin early when seeking or killed; that's not an error
+      ffmpegProcess.stdin.on('error', () => undefined);
+      stdinSource.on('error', () => ffmpegProcess.stdin?.end());
+      stdinSource.pipe(ffmpegProcess.stdin);
+    }
+    ffmpegProcess.on('close', () => {
+      input.release();
+      speech?.release();
+    });
+
+    if (ffmpegProcess.stderr) {
+      ffmpegProcess.stderr.on('data', (chunk) => {
         const msg = chunk.toString().trim();
         if (msg) console.error('[ffmpeg]', msg);
       });
     }
 
-    this.ffmpegProcess.on('error', (err) => {
+    ffmpegProcess.on('error', (err) => {
       console.error('Error starting ffmpeg:', err);
     });
 
+    // Counted on the way into the buffer below, so output a skip throws away is counted too.
+    // Past the buffer, output is consumed in real time, so the source is read at the same pace
+    ffmpegProcess.stdout.on('data', (chunk: Buffer) => {
+      this.bandwidth.sentBytes += chunk.length;
+      if (!measuredDownload) {
+        this.bandwidth.downloadedBytes += (chunk.length * sourceBitrateKbps) / outputBitrateKbps;
+      }
+    });
+
     const buffer = new PassThrough({ highWaterMark: 1024 * 1024 });
-    this.ffmpegProcess.stdout.pipe(buffer);
+    ffmpegProcess.stdout.pipe(buffer);
 
-    return buffer;
+    return { process: ffmpegProcess, stream: buffer, input, volume };
   }
 
   private cleanupProcesses(): void {
-    if (this.ffmpegProcess && !this.ffmpegProcess.killed) {
-      this.ffmpegProcess.kill('SIGKILL');
+    if (this.pipeline) {
+      killPipeline(this.pipeline);
     }
-    this.ffmpegProcess = undefined;
+    this.pipeline = undefined;
   }
 
   stop(): void {
     this.audioPlayer.stop();
     this.cleanupProcesses();
+    this.clearPreloadTimer();
+    this.discardPreloaded();
+    this.currentSong = undefined;
   }
 
   pause(): void {
@@ -116,8 +483,8 @@ export class MusicPlayer {
     this.audioPlayer.unpause();
   }
 
-  getPlayer(): AudioPlayer {
-    return this.audioPlayer;
+  onSongEnd(listener: () => void): void {
+    this.audioPlayer.on(AudioPlayerStatus.Idle, listener);
   }
 
   isPlaying(): boolean {
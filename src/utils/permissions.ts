@@ -0,0 +1,57 @@
+import { BaseInteraction, GuildMember, PermissionFlagsBits } from 'discord.js';
+import { GuildSettings } from '../types/index';
+import type { QueueLimits } from './queueManager';
+
+/** Who is asking: an interaction, or the member behind a message. */
+export type PermissionSubject = Pick<BaseInteraction, 'member' | 'memberPermissions'>;
+
+/** Server admins are members who can manage the guild. */
+export function isAdmin(interaction: PermissionSubject): boolean {
+  return Boolean(interaction.memberPermissions?.has(PermissionFlagsBits.ManageGuild));
+}
+
+/**
+ * DJs control playback for everyone. Without a DJ role configured anyone is a
+ * DJ; with one, only members with that role and admins are.
+ */
+export function isDj(interaction: PermissionSubject, settings: GuildSettings): boolean {
+  return !settings.djRoleId || hasDjRole(interaction, settings);
+}
+
+/** Admins and members with the configured DJ role; unlike isDj, nobody when no role is set. */
+export function hasDjRole(interaction: PermissionSubject, settings: GuildSettings): boolean {
+  if (isAdmin(interaction)) return true;
+  const member = interaction.member;
+  if (!settings.djRoleId || !member) return false;
+  // Uncached members arrive as raw API data with role IDs only
+  return member instanceof GuildMember ? member.roles.cache.has(settings.djRoleId) : member.roles.includes(settings.djRoleId);
+}
+
+/** The queue limits that apply to the user; DJs and admins have none. */
+export function getQueueLimits(interaction: PermissionSubject, settings: GuildSettings): QueueLimits | undefined {
+  if (hasDjRole(interaction, settings)) return undefined;
+  return {
+    maxSongsPerUser: settings.maxSongsPerUser,
+    maxQueueSize: settings.maxQueueSize,
+    maxSongMinutes: settings.maxSongMinutes,
+    locked: settings.queueLocked,
+  };
+}
+
+/** Whether the user is in the voice channel the bot is playing in. */
+export function isInBotVoiceChannel(interaction: BaseInteraction): boolean {
+  const botChannelId = interaction.guild?.members.me?.voice.channelId;
+  const member = interaction.member;
+  if (!botChannelId || !(member instanceof GuildMember)) return false;
+  return member.voice.channelId === botChannelId;
+}
+
+const OWNER_IDS = (process.env.OWNER_IDS || '')
+  .split(',')
+  .map((id) => id.trim())
+  .filter((id) => id.length > 0);
+
+/** Bot owners are listed in `OWNER_IDS` and can manage every guild's feature flags. */
+export function isBotOwner(userId: string): boolean {
+  return OWNER_IDS.includes(userId);
+}
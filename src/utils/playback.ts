@@ -0,0 +1,298 @@
+import { BaseInteraction, VoiceBasedChannel } from 'discord.js';
+import { VoiceConnectionStatus, entersState, joinVoiceChannel } from '@discordjs/voice';
+import { LocalMusicPlayer, MusicPlayer, PlayerConnection } from './musicPlayer';
+import { lavalink } from './lavalink';
+import { LavalinkMusicPlayer } from './lavalinkPlayer';
+import { QueueManager } from './queueManager';
+import { playbackActors } from './guildActor';
+import { playbackEvents } from './playbackEvents';
+import { GoonError } from './errors';
+import { AudioFilters, Song } from '../types/index';
+
+/** A player for the configured backend: a Lavalink node when `PLAYBACK_BACKEND=lavalink`, else local ffmpeg. */
+export function createMusicPlayer(guildId: string): MusicPlayer {
+  return lavalink ? new LavalinkMusicPlayer(guildId, lavalink) : new LocalMusicPlayer(guildId);
+}
+
+/**
+ * Connects to the invoking member's voice channel, or moves there if already
+ * connected elsewhere in the guild. Returns undefined when the member isn't in
+ * a voice channel.
+ */
+export async function connectToVoice(
+  interaction: BaseInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer
+): Promise<PlayerConnection | undefined> {
+  const member = interaction.member as any;
+  const channel: VoiceBasedChannel | undefined = member?.voice?.channel ?? undefined;
+
+  if (!channel) {
+    return undefined;
+  }
+
+  return connectToChannel(channel, queueManager, musicPlayer);
+}
+
+async function waitUntilReady(connection: PlayerConnection): Promise<void> {
+  try {
+    await entersState(connection, VoiceConnectionStatus.Ready, 20_000);
+  } catch (error) {
+    throw new GoonError('voiceConnectFailed', "Voice connection didn't become ready within 20s", error);
+  }
+}
+
+/**
+ * Connects to `channel`, or moves there if already connected elsewhere in the
+ * guild. Every way into voice goes through here so connection settings apply
+ * consistently.
+ */
+export async function connectToChannel(
+  channel: VoiceBasedChannel,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer
+): Promise<PlayerConnection> {
+  const guild = channel.guild;
+  const queue = queueManager.getQueue(guild.id);
+  let connection = queue.voiceConnection;
+
+  if (connection && connection.state.status !== VoiceConnectionStatus.Destroyed) {
+    if (connection.joinConfig.channelId !== channel.id) {
+      queue.selfMoveChannelId = channel.id;
+      connection.rejoin({ channelId: channel.id, selfDeaf: true, selfMute: false });
+      await waitUntilReady(connection);
+    }
+    return connection;
+  }
+
+  const onDestroyed = () => playbackEvents.emit('voiceDisconnected', { guildId: guild.id });
+  if (lavalink) {
+    const lavalinkConnection = lavalink.connect(guild.id, channel.id, (payload) => guild.shard.send(payload));
+    lavalinkConnection.once(VoiceConnectionStatus.Destroyed, onDestroyed);
+    connection = lavalinkConnection;
+  } else {
+    const voiceConnection = joinVoiceChannel({
+      channelId: channel.id,
+      guildId: guild.id,
+      adapterCreator: (guild as any).voiceAdapterCreator,
+      // The bot never listens, so there's no reason to receive everyone's audio
+      selfDeaf: true,
+    });
+    voiceConnection.once(VoiceConnectionStatus.Destroyed, onDestroyed);
+    connection = voiceConnection;
+  }
+  await waitUntilReady(connection);
+  queue.voiceConnection = connection;
+  // A new voice session starts counting bandwidth from zero
+  musicPlayer.resetBandwidthUsage();
+  return connection;
+}
+
+/**
+ * Joins the invoking member's voice channel if needed and starts the next
+ * queued song. Callers check `queueManager.isPlaying` first; it's checked
+ * again in turn, so two commands racing to start playback start one song.
+ */
+export async function startPlayback(
+  interaction: BaseInteraction,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer
+): Promise<void> {
+  await startPlaybackWith(interaction.guildId!, queueManager, musicPlayer, () =>
+    connectToVoice(interaction, queueManager, musicPlayer)
+  );
+}
+
+/** Like startPlayback, joining `channel` rather than the invoking member's channel. */
+export async function startPlaybackIn(
+  channel: VoiceBasedChannel,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer
+): Promise<void> {
+  await startPlaybackWith(channel.guild.id, queueManager, musicPlayer, () => connectToChannel(channel, queueManager, musicPlayer));
+}
+
+/**
+ * Like startPlayback, for requests that don't come from a member in voice:
+ * starts the next queued song only if the bot is already connected.
+ */
+export async function startPlaybackIfConnected(
+  guildId: string,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer
+): Promise<void> {
+  await startPlaybackWith(guildId, queueManager, musicPlayer, async () => {
+    const connection: PlayerConnection | undefined = queueManager.getQueue(guildId).voiceConnection;
+    return connection && connection.state.status !== VoiceConnectionStatus.Destroyed ? connection : undefined;
+  });
+}
+
+async function startPlaybackWith(
+  guildId: string,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  connect: () => Promise<PlayerConnection | undefined>
+): Promise<void> {
+  await playbackActors.run(guildId, async () => {
+    if (queueManager.isPlaying(guildId)) return;
+
+    try {
+      const connection = await connect();
+      if (!connection) {
+        return;
+      }
+
+      const nextSong = queueManager.dequeueNextSong(guildId);
+      if (nextSong) {
+        queueManager.setCurrentSong(guildId, nextSong);
+        queueManager.setPlaying(guildId, true);
+        queueManager.setSongStartTime(guildId, Date.now());
+        await musicPlayer.playSong(connection, nextSong);
+        playbackEvents.emit('trackStarted', { guildId, song: nextSong, automatic: false });
+      }
+    } catch (error) {
+      console.error('Error starting playback:', error);
+      queueManager.setCurrentSong(guildId, undefined);
+      queueManager.setPlaying(guildId, false);
+    }
+  });
+}
+
+/**
+ * Skips `skipped`, plus `count - 1` queued songs after it, and starts the
+ * next queued song, if any. Does nothing when
+ * `skipped` has stopped playing meanwhile, e.g. it ended while the caller was
+ * replying. Returns the song that started.
+ */
+export async function skipSong(
+  guildId: string,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  skipped: Song,
+  count = 1
+): Promise<Song | undefined> {
+  return playbackActors.run(guildId, async () => {
+    if (queueManager.getCurrentSong(guildId) !== skipped) return undefined;
+    const elapsed = queueManager.getSongElapsedTime(guildId);
+    playbackEvents.emit('trackEnded', { guildId, song: skipped, reason: 'skipped', elapsed });
+    // Songs skipped past in the queue never started, so they aren't announced as ended
+    for (let i = 1; i < count; i++) {
+      if (!queueManager.dequeueNextSong(guildId)) break;
+    }
+    const nextSong = queueManager.dequeueNextSong(guildId);
+    queueManager.setCurrentSong(guildId, undefined);
+
+    if (!nextSong) {
+      musicPlayer.stop();
+      queueManager.setPlaying(guildId, false);
+      return undefined;
+    }
+
+    const connection = queueManager.getQueue(guildId).voiceConnection;
+    if (!connection) return undefined;
+    queueManager.setCurrentSong(guildId, nextSong);
+    queueManager.setPlaying(guildId, true);
+    queueManager.setSongStartTime(guildId, Date.now());
+    await musicPlayer.playSong(connection, nextSong);
+    playbackEvents.emit('trackStarted', { guildId, song: nextSong, automatic: false });
+    return nextSong;
+  });
+}
+
+/** Applies new audio filters, restarting the current song from where it was. */
+export async function applyFilters(
+  guildId: string,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  filters: AudioFilters
+): Promise<void> {
+  const position = queueManager.getSongElapsedTime(guildId);
+  queueManager.setPlaybackRate(guildId, filters.speed);
+
+  if (!queueManager.getCurrentSong(guildId)) {
+    await musicPlayer.setFilters(filters);
+    return;
+  }
+
+  await musicPlayer.setFilters(filters, position);
+  // Restarting takes a moment, so the clock restarts once audio is flowing again
+  queueManager.setSongPosition(guildId, position);
+}
+
+/** Jumps to `position` seconds in the current song. */
+export async function seekTo(
+  guildId: string,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  position: number
+): Promise<void> {
+  await musicPlayer.seek(position);
+  queueManager.setSongPosition(guildId, position);
+}
+
+/**
+ * Moves `seconds` forward, or back when negative, from where the current song
+ * is, staying within the song. Returns where it landed, or undefined when
+ * nothing is playing or the song can't be seeked, like a live stream.
+ */
+export async function seekBy(
+  guildId: string,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  seconds: number
+): Promise<number | undefined> {
+  const currentSong = queueManager.getCurrentSong(guildId);
+  if (!currentSong || currentSong.duration <= 0) return undefined;
+
+  // Stopping just short of the end leaves the song to finish on its own
+  const elapsed = queueManager.getSongElapsedTime(guildId);
+  const position = Math.min(Math.max(elapsed + seconds, 0), Math.max(currentSong.duration - 1, 0));
+  await seekTo(guildId, queueManager, musicPlayer, position);
+  return position;
+}
+
+/** Turns loudness normalization on or off, restarting the current song from where it was. */
+export async function applyLoudnessNormalization(
+  guildId: string,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  enabled: boolean
+): Promise<void> {
+  if (!queueManager.getCurrentSong(guildId)) {
+    await musicPlayer.setLoudnessNormalization(enabled);
+    return;
+  }
+
+  const position = queueManager.getSongElapsedTime(guildId);
+  await musicPlayer.setLoudnessNormalization(enabled, position);
+  queueManager.setSongPosition(guildId, position);
+}
+
+/** Picks the volume again after a volume setting changed, restarting the current song from where it was if it differs. */
+export async function applyVolume(guildId: string, queueManager: QueueManager, musicPlayer: MusicPlayer): Promise<void> {
+  if (!queueManager.getCurrentSong(guildId)) {
+    await musicPlayer.refreshVolume();
+    return;
+  }
+
+  const position = queueManager.getSongElapsedTime(guildId);
+  const previousVolume = musicPlayer.getVolume();
+  await musicPlayer.refreshVolume(position);
+  if (musicPlayer.getVolume() !== previousVolume) {
+    queueManager.setSongPosition(guildId, position);
+  }
+}
+
+/** Mixes a short clip in over the current song from where it is; does nothing unless a song is playing. */
+export async function mixInClip(
+  guildId: string,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  clipPath: string
+): Promise<void> {
+  if (!queueManager.getCurrentSong(guildId) || !musicPlayer.isPlaying()) return;
+
+  const position = queueManager.getSongElapsedTime(guildId);
+  await musicPlayer.mixIn(clipPath, position);
+  queueManager.setSongPosition(guildId, position);
+}
@@ -0,0 +1,52 @@
+import { Song } from '../types/index';
+
+export interface PlaybackEventMap {
+  /** A song began playing. `automatic` is set when it followed the previous song on its own rather than by command. */
+  trackStarted: { guildId: string; song: Song; automatic: boolean };
+  /**
+   * A song stopped playing because it ran out or was skipped, after `elapsed`
+   * seconds. Stopping playback emits `voiceDisconnected` instead.
+   */
+  trackEnded: { guildId: string; song: Song; reason: 'finished' | 'skipped'; elapsed: number };
+  /** Songs were added to, removed from or reordered in the upcoming queue, or it was cleared. */
+  queueChanged: { guildId: string };
+  /** The last queued song finished with nothing after it. */
+  queueEmpty: { guildId: string };
+  /** The bot's voice connection was destroyed, by a command, the idle timeout or being left alone. */
+  voiceDisconnected: { guildId: string };
+}
+
+export type PlaybackEvent = keyof PlaybackEventMap;
+type Listener<E extends PlaybackEvent> = (payload: PlaybackEventMap[E]) => void | Promise<void>;
+
+/**
+ * Typed publish/subscribe for playback, so announcements, stats and other
+ * side effects subscribe here rather than living in the playback code.
+ * Listeners run after the change has happened; one that throws or rejects is
+ * logged without affecting playback or the other listeners.
+ */
+export class PlaybackEventBus {
+  private readonly listeners: { [E in PlaybackEvent]?: Listener<E>[] } = {};
+
+  /** Returns a function that unsubscribes. */
+  on<E extends PlaybackEvent>(event: E, listener: Listener<E>): () => void {
+    const listeners = (this.listeners[event] ??= []) as Listener<E>[];
+    listeners.push(listener);
+    return () => {
+      const index = listeners.indexOf(listener);
+      if (index >= 0) listeners.splice(index, 1);
+    };
+  }
+
+  emit<E extends PlaybackEvent>(event: E, payload: PlaybackEventMap[E]): void {
+    for (const listener of [...((this.listeners[event] ?? []) as Listener<E>[])]) {
+      try {
+        Promise.resolve(listener(payload)).catch((error) => console.error(`[EVENTS] ${event} listener failed:`, error));
+      } catch (error) {
+        console.error(`[EVENTS] ${event} listener failed:`, error);
+      }
+    }
+  }
+}
+
+export const playbackEvents = new PlaybackEventBus();
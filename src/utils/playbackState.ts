@@ -0,0 +1,65 @@
+import { Song } from '../types/index';
+import { DocumentSchema, Storage, reviveSong, reviveSongs } from './storage';
+
+/** A guild's playback when the bot shut down, enough to pick up where it left off. */
+export interface SavedPlayback {
+  voiceChannelId: string;
+  /** Where to post the player and announcements after resuming. */
+  textChannelId?: string;
+  currentSong?: Song;
+  /** Seconds into `currentSong`. */
+  position: number;
+  /** Upcoming songs, including any that were spilled to disk. */
+  songs: Song[];
+  savedAt: number;
+}
+
+export type PlaybackStateDocument = Record<string, SavedPlayback>;
+
+export const PLAYBACK_STATE_SCHEMA: DocumentSchema<PlaybackStateDocument> = {
+  name: 'playback-state',
+  version: 1,
+  migrations: {},
+  defaultValue: () => ({}),
+};
+
+/**
+ * Adds `state` to the saved state, writing it immediately since the process
+ * is about to exit. Guilds other clustered processes saved are kept.
+ */
+export function savePlaybackState(storage: Storage, state: PlaybackStateDocument): void {
+  storage.save(PLAYBACK_STATE_SCHEMA, { ...storage.load(PLAYBACK_STATE_SCHEMA), ...state });
+}
+
+/**
+ * Reads the state saved at the last shutdown for the guilds `ownsGuild`
+ * accepts and clears it, so it's only resumed once. Other guilds are left
+ * for the clustered process that serves them.
+ */
+export function takePlaybackState(storage: Storage, ownsGuild: (guildId: string) => boolean): PlaybackStateDocument {
+  let stored: PlaybackStateDocument;
+  try {
+    stored = storage.load(PLAYBACK_STATE_SCHEMA);
+    storage.save(
+      PLAYBACK_STATE_SCHEMA,
+      Object.fromEntries(Object.entries(stored).filter(([guildId]) => !ownsGuild(guildId)))
+    );
+  } catch (error) {
+    console.error('[PLAYBACK-STATE] Failed to load saved playback:', error);
+    return {};
+  }
+
+  const state: PlaybackStateDocument = {};
+  for (const [guildId, saved] of Object.entries(stored)) {
+    if (!ownsGuild(guildId) || typeof saved?.voiceChannelId !== 'string') continue;
+    state[guildId] = {
+      voiceChannelId: saved.voiceChannelId,
+      textChannelId: typeof saved.textChannelId === 'string' ? saved.textChannelId : undefined,
+      currentSong: reviveSong(saved.currentSong) ?? undefined,
+      position: Number(saved.position) || 0,
+      songs: reviveSongs(saved.songs),
+      savedAt: Number(saved.savedAt) || 0,
+    };
+  }
+  return state;
+}
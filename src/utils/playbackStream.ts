@@ -0,0 +1,74 @@
+import WebSocket from 'ws';
+import { PlaybackEvent, playbackEvents } from './playbackEvents';
+import { ApiContext, describeNowPlaying, findGuildByApiKey, toApiSong } from './restApi';
+import { WebServer, getBearerToken } from './webServer';
+
+// How often listeners get the position of the playing song
+const POSITION_INTERVAL_MS = 2000;
+// Changes this close together, like a skip (track ended, track started), go out as one update
+const STATE_DEBOUNCE_MS = 250;
+
+const STATE_EVENTS: PlaybackEvent[] = ['trackStarted', 'trackEnded', 'queueChanged', 'queueEmpty', 'voiceDisconnected'];
+
+/**
+ * Adds a `/api/stream` WebSocket that pushes one server's playback state as
+ * it changes, for live overlays. Messages are JSON: `state` (the current song
+ * and queue) on connect and after every change, and `position` every couple
+ * of seconds while a song plays.
+ */
+export function registerPlaybackStream(server: WebServer, context: ApiContext): void {
+  const listeners = new Map<string, Set<WebSocket>>();
+  const pendingStates = new Map<string, NodeJS.Timeout>();
+
+  const describeState = (guildId: string) => ({
+    type: 'state',
+    nowPlaying: describeNowPlaying(context, guildId),
+    queue: context.queueManager.getFullQueue(guildId).map(toApiSong),
+  });
+
+  function broadcast(guildId: string, message: object): void {
+    const payload = JSON.stringify(message);
+    for (const socket of listeners.get(guildId) ?? []) {
+      if (socket.readyState === WebSocket.OPEN) socket.send(payload);
+    }
+  }
+
+  function scheduleState(guildId: string): void {
+    if (!listeners.has(guildId) || pendingStates.has(guildId)) return;
+    pendingStates.set(
+      guildId,
+      setTimeout(() => {
+        pendingStates.delete(guildId);
+        broadcast(guildId, describeState(guildId));
+      }, STATE_DEBOUNCE_MS)
+    );
+  }
+
+  for (const event of STATE_EVENTS) {
+    playbackEvents.on(event, ({ guildId }) => scheduleState(guildId));
+  }
+
+  setInterval(() => {
+    for (const guildId of listeners.keys()) {
+      const nowPlaying = describeNowPlaying(context, guildId);
+      if (nowPlaying) {
+        broadcast(guildId, { type: 'position', elapsed: nowPlaying.elapsed, paused: nowPlaying.paused });
+      }
+    }
+  }, POSITION_INTERVAL_MS).unref();
+
+  // Browser sources can't set headers, so the key may also come as `?key=`
+  server.websocket('/api/stream', (request) => {
+    const guildId = findGuildByApiKey(context.guildSettings, getBearerToken(request) ?? request.query.get('key'));
+    return (socket) => {
+      const sockets = listeners.get(guildId) ?? new Set<WebSocket>();
+      listeners.set(guildId, sockets.add(socket));
+      socket.send(JSON.stringify(describeState(guildId)));
+
+      socket.on('close', () => {
+        sockets.delete(socket);
+        if (sockets.size === 0 && listeners.get(guildId) === sockets) listeners.delete(guildId);
+      });
+    };
+  });
+}
@@ -0,0 +1,70 @@
+import { ActionRowBuilder, ButtonBuilder, ButtonStyle } from 'discord.js';
+import { LoopMode } from '../types/index';
+import { MusicPlayer } from './musicPlayer';
+import { QueueManager } from './queueManager';
+import { MessageKey, Translator } from './i18n';
+
+export const PLAYER_BUTTON_IDS = {
+  TOGGLE: 'music:toggle',
+  SKIP: 'music:skip',
+  STOP: 'music:stop',
+  SHUFFLE: 'music:shuffle',
+  LOOP: 'music:loop',
+  REFRESH: 'music:refresh',
+} as const;
+
+/** Buttons that change playback for everyone, so they're limited to DJs when the guild has a DJ role. */
+export const DJ_ONLY_BUTTON_IDS: string[] = [PLAYER_BUTTON_IDS.STOP, PLAYER_BUTTON_IDS.SHUFFLE, PLAYER_BUTTON_IDS.LOOP];
+
+/** The loop button steps through the modes in this order. */
+export const NEXT_LOOP_MODE: Record<LoopMode, LoopMode> = { off: 'track', track: 'queue', queue: 'off' };
+
+export const LOOP_MODE_LABELS: Record<LoopMode, MessageKey> = {
+  off: 'player.loopOff',
+  track: 'player.loopTrack',
+  queue: 'player.loopQueue',
+};
+
+/** The control panel shown under the player and queue messages. */
+export function buildPlayerControls(queueManager: QueueManager, guildId: string, musicPlayer: MusicPlayer, t: Translator) {
+  const hasCurrentSong = Boolean(queueManager.getCurrentSong(guildId));
+  const loopMode = queueManager.getLoopMode(guildId);
+
+  const controlRow = new ActionRowBuilder<ButtonBuilder>().addComponents(
+    new ButtonBuilder()
+      .setCustomId(PLAYER_BUTTON_IDS.TOGGLE)
+      .setEmoji(musicPlayer.isPlaying() ? '⏸️' : '▶️')
+      .setStyle(ButtonStyle.Success)
+      .setDisabled(!hasCurrentSong),
+    new ButtonBuilder()
+      .setCustomId(PLAYER_BUTTON_IDS.SKIP)
+      .setEmoji('⏭️')
+      .setStyle(ButtonStyle.Secondary)
+      .setDisabled(!hasCurrentSong),
+    new ButtonBuilder()
+      .setCustomId(PLAYER_BUTTON_IDS.STOP)
+      .setEmoji('⏹️')
+      .setStyle(ButtonStyle.Danger)
+      .setDisabled(!hasCurrentSong),
+    new ButtonBuilder()
+      .setCustomId(PLAYER_BUTTON_IDS.SHUFFLE)
+      .setEmoji('🔀')
+      .setStyle(ButtonStyle.Secondary)
+      .setDisabled(queueManager.getQueueSize(guildId) < 2),
+    new ButtonBuilder()
+      .setCustomId(PLAYER_BUTTON_IDS.LOOP)
+      .setEmoji(loopMode === 'track' ? '🔂' : '🔁')
+      .setStyle(loopMode === 'off' ? ButtonStyle.Secondary : ButtonStyle.Primary)
+      .setDisabled(!hasCurrentSong)
+  );
+
+  const infoRow = new ActionRowBuilder<ButtonBuilder>().addComponents(
+    new ButtonBuilder()
+      .setCustomId(PLAYER_BUTTON_IDS.REFRESH)
+      .setLabel(t('player.queueButton'))
+      .setEmoji('📋')
+      .setStyle(ButtonStyle.Secondary)
+  );
+
+  return [controlRow, infoRow];
+}
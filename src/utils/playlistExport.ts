@@ -0,0 +1,36 @@
+import { Song } from '../types/index';
+
+export type ExportFormat = 'json' | 'm3u';
+
+/** Renders songs in a format `/import` reads back. */
+export function exportSongs(name: string, songs: Song[], format: ExportFormat): string {
+  if (format === 'm3u') {
+    const lines = ['#EXTM3U', `#PLAYLIST:${name}`];
+    for (const song of songs) {
+      // M3U uses -1 for unknown lengths
+      lines.push(`#EXTINF:${song.duration > 0 ? song.duration : -1},${song.title}`);
+      lines.push(song.url);
+    }
+    return lines.join('\n') + '\n';
+  }
+
+  return JSON.stringify(
+    {
+      name,
+      exportedAt: new Date().toISOString(),
+      songs: songs.map((song) => ({
+        title: song.title,
+        url: song.url,
+        duration: song.duration,
+        thumbnail: song.thumbnail,
+      })),
+    },
+    null,
+    2
+  );
+}
+
+export function toExportFileName(name: string, format: ExportFormat): string {
+  const safeName = name.replace(/[^a-z0-9-_]+/gi, '_').replace(/^_+|_+$/g, '') || 'playlist';
+  return `${safeName}.${format}`;
+}
@@ -0,0 +1,212 @@
+import * as path from 'path';
+import { Song } from '../types/index';
+import { resolveSong } from './sourceExtractor';
+import { Translator } from './i18n';
+
+export const MAX_IMPORT_ENTRIES = 200;
+
+export interface ImportEntry {
+  /** 1-based line (or array index) in the source, used for error reporting. */
+  line: number;
+  input: string;
+}
+
+export interface ImportFailure extends ImportEntry {
+  reason: string;
+}
+
+export interface ImportResult {
+  songs: Song[];
+  failures: ImportFailure[];
+}
+
+export interface ImportProgress {
+  /** Entries looked up so far, including failed ones. */
+  done: number;
+  failed: number;
+  total: number;
+}
+
+type EntryResolution = { entry: ImportEntry; song: Song } | { entry: ImportEntry; reason: string };
+
+export type ImportFormat = 'm3u' | 'json' | 'csv' | 'text';
+
+export function detectImportFormat(fileName: string): ImportFormat {
+  switch (path.extname(fileName).toLowerCase()) {
+    case '.m3u':
+    case '.m3u8':
+      return 'm3u';
+    case '.json':
+      return 'json';
+    case '.csv':
+      return 'csv';
+    default:
+      return 'text';
+  }
+}
+
+/** Splits an uploaded playlist file into one entry per URL or search query. */
+export function parseImportFile(content: string, format: ImportFormat): ImportEntry[] {
+  switch (format) {
+    case 'json':
+      return parseJson(content);
+    case 'csv':
+      return parseCsv(content);
+    case 'm3u':
+    case 'text':
+      // M3U directives and comments both start with '#'
+      return parseLines(content, (line) => !line.startsWith('#'));
+  }
+}
+
+/** Parses a pasted list of URLs separated by whitespace or commas. */
+export function parsePastedList(text: string): ImportEntry[] {
+  return text
+    .split(/[\s,]+/)
+    .map((input) => input.trim())
+    .filter((input) => input.length > 0)
+    .map((input, index) => ({ line: index + 1, input }));
+}
+
+/**
+ * Resolves every entry, collecting the ones that fail instead of aborting the
+ * import. `onProgress` hears about each entry as it finishes.
+ */
+export async function resolveImportEntries(
+  entries: ImportEntry[],
+  userId: string,
+  guildId: string,
+  onProgress?: (progress: ImportProgress) => void
+): Promise<ImportResult> {
+  const progress: ImportProgress = { done: 0, failed: 0, total: entries.length };
+  const resolveEntry = async (entry: ImportEntry): Promise<EntryResolution> => {
+    try {
+      const song = await resolveSong(entry.input, userId, guildId, 'bulk');
+      if (!song) return { entry, reason: 'unsupported link or no search results' };
+      // A link no source could look up comes back as a placeholder so /play can still try it; an import reports it
+      if (song.id === 'unknown') return { entry, reason: 'video is private, removed or otherwise unavailable' };
+      return { entry, song };
+    } catch (error) {
+      return { entry, reason: error instanceof Error ? error.message : String(error) };
+    }
+  };
+
+  const results = await Promise.all(
+    entries.map(async (entry) => {
+      const result = await resolveEntry(entry);
+      progress.done++;
+      if ('reason' in result) progress.failed++;
+      onProgress?.({ ...progress });
+      return result;
+    })
+  );
+
+  const songs: Song[] = [];
+  const failures: ImportFailure[] = [];
+  for (const result of results) {
+    if ('song' in result) {
+      songs.push(result.song);
+    } else {
+      failures.push({ ...result.entry, reason: result.reason });
+    }
+  }
+
+  return { songs, failures };
+}
+
+/** `⏳ Resolved 37/120, 3 failed…` */
+export function formatImportProgress({ done, failed, total }: ImportProgress, t: Translator): string {
+  return failed > 0 ? t('import.progressFailed', { done, total, failed }) : t('import.progress', { done, total });
+}
+
+/** `Line 3: \`input\` — reason`, one per failure, with a note for any past `max`. */
+export function formatImportFailures(failures: ImportFailure[], max: number, t: Translator): string {
+  const lines = failures.slice(0, max).map((failure) =>
+    t('import.failureLine', { line: failure.line, input: failure.input.slice(0, 60), reason: failure.reason.slice(0, 150) })
+  );
+  if (failures.length > max) {
+    lines.push(t('import.moreFailures', { count: failures.length - max }));
+  }
+  return lines.join('\n');
+}
+
+function parseLines(content: string, keep: (line: string) => boolean): ImportEntry[] {
+  const entries: ImportEntry[] = [];
+  content.split(/\r?\n/).forEach((rawLine, index) => {
+    const line = rawLine.trim();
+    if (line && keep(line)) {
+      entries.push({ line: index + 1, input: line });
+    }
+  });
+  return entries;
+}
+
+function parseJson(content: string): ImportEntry[] {
+  let parsed: any;
+  try {
+    parsed = JSON.parse(content);
+  } catch (error) {
+    throw new Error(`Invalid JSON: ${error instanceof Error ? error.message : String(error)}`);
+  }
+
+  // Accept a bare array, or an object wrapping one under `songs`
+  const items: unknown[] = Array.isArray(parsed) ? parsed : Array.isArray(parsed?.songs) ? parsed.songs : [];
+  const entries: ImportEntry[] = [];
+
+  items.forEach((item: any, index) => {
+    const input = typeof item === 'string' ? item : item?.url ?? item?.title;
+    if (typeof input === 'string' && input.trim()) {
+      entries.push({ line: index + 1, input: input.trim() });
+    }
+  });
+
+  return entries;
+}
+
+function parseCsv(content: string): ImportEntry[] {
+  const lines = content.split(/\r?\n/);
+  const header = splitCsvRow(lines[0] ?? '').map((cell) => cell.toLowerCase());
+
+  // Use the `url` column when there's a header, otherwise the first column
+  let column = header.indexOf('url');
+  const hasHeader = column >= 0 || header.includes('title');
+  if (column < 0) {
+    column = hasHeader ? header.indexOf('title') : 0;
+  }
+
+  const entries: ImportEntry[] = [];
+  lines.forEach((line, index) => {
+    if (hasHeader && index === 0) return;
+    const input = splitCsvRow(line)[column]?.trim();
+    if (input) {
+      entries.push({ line: index + 1, input });
+    }
+  });
+  return entries;
+}
+
+function splitCsvRow(row: string): string[] {
+  const cells: string[] = [];
+  let current = '';
+  let inQuotes = false;
+
+  for (let i = 0; i < row.length; i++) {
+    const char = row[i];
+    if (char === '"') {
+      if (inQuotes && row[i + 1] === '"') {
+        current += '"';
+        i++;
+      } else {
+        inQuotes = !inQuotes;
+      }
+    } else if (char === ',' && !inQuotes) {
+      cells.push(current);
+      current = '';
+    } else {
+      current += char;
+    }
+  }
+  cells.push(current);
+
+  return cells.map((cell) => cell.trim());
+}
@@ -0,0 +1,135 @@
+import { Playlist, Song } from '../types/index';
+import { DocumentSchema, Storage, reviveSongs } from './storage';
+import { UserFacingError } from './errors';
+import { MessageKey, MessageVars } from './i18n';
+
+export const MAX_PLAYLISTS_PER_USER = 25;
+export const MAX_PLAYLIST_SIZE = 200;
+
+// guildId -> userId -> playlists
+export type PlaylistDocument = Record<string, Record<string, Playlist[]>>;
+
+export const PLAYLIST_SCHEMA: DocumentSchema<PlaylistDocument> = {
+  name: 'playlists',
+  version: 1,
+  migrations: {},
+  defaultValue: () => ({}),
+};
+
+export class PlaylistError extends UserFacingError {
+  constructor(messageKey: MessageKey, vars?: MessageVars) {
+    super('playlist', messageKey, vars);
+  }
+}
+
+/** Saved playlists, owned by a user within a single guild. */
+export class PlaylistManager {
+  private playlists: PlaylistDocument;
+
+  constructor(private readonly storage: Storage) {
+    this.playlists = {};
+    try {
+      this.playlists = this.revive(storage.load(PLAYLIST_SCHEMA));
+    } catch (error) {
+      console.error('[PLAYLIST] Failed to load playlists:', error);
+    }
+  }
+
+  getPlaylists(guildId: string, userId: string): Playlist[] {
+    return this.playlists[guildId]?.[userId] ?? [];
+  }
+
+  getPlaylist(guildId: string, userId: string, name: string): Playlist | undefined {
+    const key = name.trim().toLowerCase();
+    return this.getPlaylists(guildId, userId).find((playlist) => playlist.name.toLowerCase() === key);
+  }
+
+  createPlaylist(guildId: string, userId: string, name: string): Playlist {
+    const trimmedName = name.trim();
+    if (!trimmedName) {
+      throw new PlaylistError('playlist.emptyName');
+    }
+    if (this.getPlaylist(guildId, userId, trimmedName)) {
+      throw new PlaylistError('playlist.exists', { name: trimmedName });
+    }
+
+    const userPlaylists = this.getUserPlaylists(guildId, userId);
+    if (userPlaylists.length >= MAX_PLAYLISTS_PER_USER) {
+      throw new PlaylistError('playlist.tooMany', { max: MAX_PLAYLISTS_PER_USER });
+    }
+
+    const playlist: Playlist = {
+      name: trimmedName,
+      ownerId: userId,
+      songs: [],
+      createdAt: Date.now(),
+    };
+    userPlaylists.push(playlist);
+    this.save();
+    return playlist;
+  }
+
+  deletePlaylist(guildId: string, userId: string, name: string): Playlist {
+    const playlist = this.requirePlaylist(guildId, userId, name);
+    const userPlaylists = this.getUserPlaylists(guildId, userId);
+    userPlaylists.splice(userPlaylists.indexOf(playlist), 1);
+    this.save();
+    return playlist;
+  }
+
+  addSongs(guildId: string, userId: string, name: string, songs: Song[]): Playlist {
+    const playlist = this.requirePlaylist(guildId, userId, name);
+    if (playlist.songs.length + songs.length > MAX_PLAYLIST_SIZE) {
+      throw new PlaylistError('playlist.full', { max: MAX_PLAYLIST_SIZE });
+    }
+
+    playlist.songs.push(...songs);
+    this.save();
+    return playlist;
+  }
+
+  /** Removes the song at a 1-based position. */
+  removeSong(guildId: string, userId: string, name: string, position: number): Song {
+    const playlist = this.requirePlaylist(guildId, userId, name);
+    if (position < 1 || position > playlist.songs.length) {
+      throw new PlaylistError('playlist.badPosition', { size: playlist.songs.length });
+    }
+
+    const [removed] = playlist.songs.splice(position - 1, 1);
+    this.save();
+    return removed;
+  }
+
+  private requirePlaylist(guildId: string, userId: string, name: string): Playlist {
+    const playlist = this.getPlaylist(guildId, userId, name);
+    if (!playlist) {
+      throw new PlaylistError('playlist.notFound', { name });
+    }
+    return playlist;
+  }
+
+  private getUserPlaylists(guildId: string, userId: string): Playlist[] {
+    const guildPlaylists = (this.playlists[guildId] ??= {});
+    return (guildPlaylists[userId] ??= []);
+  }
+
+  private revive(document: PlaylistDocument): PlaylistDocument {
+    const revived: PlaylistDocument = {};
+    for (const [guildId, users] of Object.entries(document)) {
+      revived[guildId] = {};
+      for (const [userId, playlists] of Object.entries(users)) {
+        revived[guildId][userId] = playlists.map((playlist) => ({
+          name: String(playlist.name),
+          ownerId: userId,
+          songs: reviveSongs(playlist.songs),
+          createdAt: Number(playlist.createdAt) || 0,
+        }));
+      }
+    }
+    return revived;
+  }
+
+  private save(): void {
+    this.storage.scheduleSave(PLAYLIST_SCHEMA, () => this.playlists);
+  }
+}
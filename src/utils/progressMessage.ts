@@ -0,0 +1,51 @@
+// Discord allows about five edits per five seconds to a message
+const EDIT_INTERVAL_MS = 2000;
+
+/**
+ * Keeps a reply up to date while something long runs, e.g. an import. Updates
+ * arriving faster than Discord allows edits are merged; only the latest is sent.
+ */
+export class ProgressMessage {
+  private pending?: string;
+  private timer?: NodeJS.Timeout;
+  private lastEditAt = 0;
+  private inFlight: Promise<void> = Promise.resolve();
+  private stopped = false;
+
+  constructor(
+    private readonly edit: (content: string) => Promise<unknown>,
+    private readonly intervalMs = EDIT_INTERVAL_MS
+  ) {}
+
+  update(content: string): void {
+    if (this.stopped) return;
+    this.pending = content;
+    if (this.timer) return;
+
+    const wait = Math.max(0, this.lastEditAt + this.intervalMs - Date.now());
+    this.timer = setTimeout(() => this.send(), wait);
+  }
+
+  /** Drops updates not sent yet and waits for one being sent, so it can't overwrite the final message. */
+  async stop(): Promise<void> {
+    this.stopped = true;
+    clearTimeout(this.timer);
+    this.timer = undefined;
+    await this.inFlight;
+  }
+
+  private send(): void {
+    this.timer = undefined;
+    const content = this.pending;
+    this.pending = undefined;
+    if (content === undefined || this.stopped) return;
+
+    this.lastEditAt = Date.now();
+    this.inFlight = this.inFlight
+      .then(() => this.edit(content))
+      .then(
+        () => undefined,
+        (error) => console.error('Error updating progress message:', error)
+      );
+  }
+}
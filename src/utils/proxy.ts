@@ -0,0 +1,38 @@
+// Schemes yt-dlp's --proxy understands
+export const PROXY_PROTOCOLS = ['http:', 'https:', 'socks4:', 'socks4a:', 'socks5:', 'socks5h:'];
+
+/** Reads `guildId=url` pairs separated by commas, skipping malformed entries. */
+export function parseGuildProxies(raw: string | undefined): Map<string, string> {
+  const proxies = new Map<string, string>();
+  for (const entry of (raw ?? '').split(',')) {
+    const separator = entry.indexOf('=');
+    if (separator <= 0) continue;
+    const guildId = entry.slice(0, separator).trim();
+    const url = entry.slice(separator + 1).trim();
+    if (guildId && url) proxies.set(guildId, url);
+  }
+  return proxies;
+}
+
+/** Returns a problem description when `url` can't be used as a proxy. */
+export function validateProxyUrl(url: string): string | undefined {
+  try {
+    const { protocol } = new URL(url);
+    return PROXY_PROTOCOLS.includes(protocol) ? undefined : `unsupported scheme ${protocol}`;
+  } catch {
+    return 'not a valid URL';
+  }
+}
+
+const DEFAULT_PROXY = process.env.PROXY_URL || undefined;
+const GUILD_PROXIES = parseGuildProxies(process.env.GUILD_PROXY_URLS);
+
+/** The proxy YouTube traffic for this guild goes through, if any; per-guild entries win. */
+export function getProxyUrl(guildId?: string): string | undefined {
+  return (guildId && GUILD_PROXIES.get(guildId)) || DEFAULT_PROXY;
+}
+
+/** ffmpeg's own HTTP client only speaks to plain HTTP proxies. */
+export function isHttpProxy(url: string): boolean {
+  return url.startsWith('http://');
+}
@@ -0,0 +1,135 @@
+import * as dns from 'dns';
+import * as http from 'http';
+import * as https from 'https';
+import * as net from 'net';
+import { GoonError } from './errors';
+
+const MAX_REDIRECTS = 5;
+const REDIRECT_STATUSES = new Set([301, 302, 303, 307, 308]);
+
+// Loopback, private, CGNAT, link-local (cloud metadata services live there), multicast and reserved ranges
+const PRIVATE_ADDRESSES = new net.BlockList();
+for (const [address, prefix] of [
+  ['0.0.0.0', 8],
+  ['10.0.0.0', 8],
+  ['100.64.0.0', 10],
+  ['127.0.0.0', 8],
+  ['169.254.0.0', 16],
+  ['172.16.0.0', 12],
+  ['192.168.0.0', 16],
+  ['224.0.0.0', 3],
+] as const) {
+  PRIVATE_ADDRESSES.addSubnet(address, prefix, 'ipv4');
+}
+for (const [address, prefix] of [
+  ['::', 127],
+  ['fc00::', 7],
+  ['fe80::', 10],
+  ['ff00::', 8],
+] as const) {
+  PRIVATE_ADDRESSES.addSubnet(address, prefix, 'ipv6');
+}
+
+/** Whether an IP address is somewhere the bot shouldn't be sent, like its own host or network. */
+export function isPrivateAddress(address: string): boolean {
+  const mapped = /^::ffff:(\d+\.\d+\.\d+\.\d+)$/i.exec(address);
+  if (mapped) return isPrivateAddress(mapped[1]);
+  if (net.isIPv4(address)) return PRIVATE_ADDRESSES.check(address, 'ipv4');
+  if (net.isIPv6(address)) return PRIVATE_ADDRESSES.check(address, 'ipv6');
+  return true;
+}
+
+/**
+ * Whether a link is http(s) and doesn't name a private address outright.
+ * Hostnames are checked again when they're resolved; see getPublic.
+ */
+export function isPublicHttpUrl(url: string): boolean {
+  try {
+    const parsed = new URL(url);
+    if (parsed.protocol !== 'http:' && parsed.protocol !== 'https:') return false;
+    const host = parsed.hostname.replace(/^\[|\]$/g, '').toLowerCase();
+    if (host === 'localhost' || host.endsWith('.localhost')) return false;
+    return !net.isIP(host) || !isPrivateAddress(host);
+  } catch {
+    return false;
+  }
+}
+
+// Resolves like the default lookup, but fails for hostnames pointing at private addresses
+function publicLookup(hostname: string, options: dns.LookupOptions, callback: (...args: any[]) => void): void {
+  dns.lookup(hostname, { ...options, all: true }, (error, addresses) => {
+    if (error) {
+      callback(error);
+      return;
+    }
+    const blocked = addresses.find(({ address }) => isPrivateAddress(address));
+    if (blocked || addresses.length === 0) {
+      callback(new GoonError('unavailable', `${hostname} resolves to a private address (${blocked?.address ?? 'none'})`));
+    } else if (options.all) {
+      callback(null, addresses);
+    } else {
+      callback(null, addresses[0].address, addresses[0].family);
+    }
+  });
+}
+
+function get(url: string, headers: Record<string, string>, timeoutMs: number): Promise<http.IncomingMessage> {
+  return new Promise((resolve, reject) => {
+    const client = new URL(url).protocol === 'https:' ? https : http;
+    const request = client.get(url, { headers, lookup: publicLookup as net.LookupFunction }, (response) => {
+      // Only until the headers arrive; a station's stream sits idle while playback is paused
+      request.setTimeout(0);
+      resolve(response);
+    });
+    request.setTimeout(timeoutMs, () => request.destroy(new Error(`${url} timed out`)));
+    request.on('error', reject);
+  });
+}
+
+/**
+ * GETs a link on the public internet. Redirects are followed by hand, and
+ * every hop is refused if it names or resolves to a private address, so
+ * neither a link nor a redirect can point the bot at its own network.
+ * The caller reads or destroys the returned response.
+ */
+export async function getPublic(
+  url: string,
+  headers: Record<string, string> = {},
+  timeoutMs = 10_000
+): Promise<{ response: http.IncomingMessage; url: string }> {
+  for (let hop = 0; hop <= MAX_REDIRECTS; hop++) {
+    if (!isPublicHttpUrl(url)) {
+      throw new GoonError('unavailable', `Refusing to fetch ${url}: not a public http(s) address`);
+    }
+    const response = await get(url, headers, timeoutMs);
+    const location = response.headers.location;
+    if (!REDIRECT_STATUSES.has(response.statusCode ?? 0) || !location) {
+      return { response, url };
+    }
+    response.destroy();
+    url = new URL(location, url).href;
+  }
+  throw new GoonError('unavailable', `Too many redirects fetching ${url}`);
+}
+
+/** A response header's value, the first when it was sent more than once. */
+export function headerValue(response: http.IncomingMessage, name: string): string | undefined {
+  const value = response.headers[name.toLowerCase()];
+  return (Array.isArray(value) ? value[0] : value)?.trim() || undefined;
+}
+
+/** Reads a whole response body as text, giving up past `maxBytes` or after `timeoutMs` without data. */
+export async function readText(response: http.IncomingMessage, maxBytes: number, timeoutMs = 10_000): Promise<string> {
+  response.setTimeout(timeoutMs, () => response.destroy(new Error('Response timed out')));
+  const chunks: Buffer[] = [];
+  let length = 0;
+  for await (const chunk of response) {
+    length += (chunk as Buffer).length;
+    if (length > maxBytes) {
+      response.destroy();
+      throw new GoonError('unavailable', `Response is larger than ${maxBytes} bytes`);
+    }
+    chunks.push(chunk as Buffer);
+  }
+  return Buffer.concat(chunks).toString('utf8');
+}
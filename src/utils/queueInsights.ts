@@ -0,0 +1,74 @@
+import { Song } from '../types/index';
+
+export interface RequesterShare {
+  userId: string;
+  songs: number;
+  /** Seconds of queued audio. */
+  duration: number;
+}
+
+export interface QueueInsights {
+  songCount: number;
+  /** Seconds, counting only songs whose duration is known. */
+  totalDuration: number;
+  /** Songs whose details haven't been fetched yet, so their duration isn't known. */
+  pendingMetadata: number;
+  /** Most queued time first. */
+  requesters: RequesterShare[];
+  longest?: Song;
+  shortest?: Song;
+  /** Songs that repeat an earlier entry (same video and clip). */
+  duplicates: number;
+  /** Song counts per source, most first. */
+  sources: Array<{ source: string; songs: number }>;
+}
+
+/** Where a song comes from, by its URL's host. */
+export function describeSource(url: string): string {
+  let host: string;
+  try {
+    host = new URL(url).hostname.toLowerCase().replace(/^www\./, '');
+  } catch {
+    return 'Unknown';
+  }
+  if (host === 'music.youtube.com') return 'YouTube Music';
+  if (host === 'youtube.com' || host === 'youtu.be' || host.endsWith('.youtube.com')) return 'YouTube';
+  return host;
+}
+
+function countBy<T>(items: T[], key: (item: T) => string): Map<string, number> {
+  const counts = new Map<string, number>();
+  for (const item of items) {
+    counts.set(key(item), (counts.get(key(item)) ?? 0) + 1);
+  }
+  return counts;
+}
+
+export function computeQueueInsights(songs: Song[]): QueueInsights {
+  const hasDuration = (song: Song) => !song.needsMetadata && song.duration > 0;
+  const known = songs.filter(hasDuration);
+
+  const requesters = new Map<string, RequesterShare>();
+  for (const song of songs) {
+    const share = requesters.get(song.addedBy) ?? { userId: song.addedBy, songs: 0, duration: 0 };
+    share.songs++;
+    share.duration += hasDuration(song) ? song.duration : 0;
+    requesters.set(song.addedBy, share);
+  }
+
+  const byLength = [...known].sort((a, b) => b.duration - a.duration);
+  const entryKeys = countBy(songs, (song) => `${song.url}#${song.clip?.start ?? ''}`);
+
+  return {
+    songCount: songs.length,
+    totalDuration: known.reduce((total, song) => total + song.duration, 0),
+    pendingMetadata: songs.length - known.length,
+    requesters: [...requesters.values()].sort((a, b) => b.duration - a.duration || b.songs - a.songs),
+    longest: byLength[0],
+    shortest: byLength[byLength.length - 1],
+    duplicates: [...entryKeys.values()].reduce((total, count) => total + count - 1, 0),
+    sources: [...countBy(songs, (song) => describeSource(song.url))]
+      .map(([source, count]) => ({ source, songs: count }))
+      .sort((a, b) => b.songs - a.songs),
+  };
+}
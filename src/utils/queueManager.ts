@@ -1,41 +1,436 @@
-import { Song, GuildQueue } from '../types/index';
+import { Song, GuildQueue, LoopMode } from '../types/index';
+import { DocumentSchema, Storage, reviveSongs } from './storage';
+import { QueueSpill, SPILL_PAGE_SIZE } from './queueSpill';
+import { METADATA_LOOKAHEAD, resolvePendingMetadata } from './lazyMetadata';
+import { shuffleInPlace } from './shuffle';
+import { fromShortId, isShortId, toShortId } from './shortId';
+import { playbackEvents } from './playbackEvents';
+import { UserFacingError } from './errors';
+import { MessageKey, MessageVars } from './i18n';
 
+const MAX_HISTORY_SIZE = 50;
+// Songs past this many are spilled to disk; see QueueSpill
+const MAX_IN_MEMORY_SONGS = Math.max(Number(process.env.QUEUE_MEMORY_LIMIT) || 500, SPILL_PAGE_SIZE * 2);
+
+export type HistoryDocument = Record<string, Song[]>;
+
+export const HISTORY_SCHEMA: DocumentSchema<HistoryDocument> = {
+  name: 'history',
+  version: 1,
+  migrations: {
+    0: (data: any) => {
+      const migrated: HistoryDocument = {};
+      for (const [guildId, songs] of Object.entries(data ?? {})) {
+        migrated[guildId] = reviveSongs(songs);
+      }
+      return migrated;
+    },
+  },
+  defaultValue: () => ({}),
+};
+
+/** Caps on how much can be queued; 0 means no limit. */
+export interface QueueLimits {
+  maxSongsPerUser: number;
+  maxQueueSize: number;
+  maxSongMinutes: number;
+  /** Nothing can be added at all. */
+  locked: boolean;
+}
+
+/** Thrown when adding songs would go over the guild's queue limits. */
+export class QueueLimitError extends UserFacingError {
+  constructor(messageKey: MessageKey, vars?: MessageVars) {
+    super('queueLimit', messageKey, vars);
+  }
+}
+
+function checkUnlocked(limits: QueueLimits): void {
+  if (limits.locked) {
+    throw new QueueLimitError('queueLimit.locked');
+  }
+}
+
+/** What makes two queue entries the same song: the video, and for a clip which part of it. */
+export function songKey(song: Song): string {
+  const video = song.id !== 'unknown' ? song.id : song.url;
+  return song.clip ? `${video}@${song.clip.start}` : video;
+}
+
+/** Songs whose length isn't known yet (pending playlist metadata, live streams) are let through. */
+function isTooLong(song: Song, limits: QueueLimits): boolean {
+  return limits.maxSongMinutes > 0 && song.duration > limits.maxSongMinutes * 60;
+}
+
+/**
+ * Owns every guild's queue. All queue edits go through these methods, each of
+ * which runs to completion synchronously, so commands and long-running imports
+ * never observe a half-applied change. Ordering rules while an import is
+ * still resolving songs:
+ * - songs it adds later are appended after whatever the queue holds by then,
+ *   so they are not part of an earlier shuffle and don't shift positions used
+ *   by an earlier remove or move;
+ * - clearing the queue cancels it: songs it adds afterwards are dropped (see
+ *   getGeneration).
+ */
 export class QueueManager {
   private queues: Map<string, GuildQueue> = new Map();
+  private storage?: Storage;
+  private persistedHistory: HistoryDocument = {};
+  private spillStorage?: Storage;
+  private spills: Map<string, QueueSpill> = new Map();
+  private generations: Map<string, number> = new Map();
+  private queueIdCounters: Map<string, number> = new Map();
+  private autoDedupe: (guildId: string) => boolean = () => false;
+
+  constructor(storage?: Storage) {
+    this.storage = storage;
+    if (storage) {
+      // Spilled pages are only read back by this process, so they stay in files whatever the backend
+      const spillStorage = storage.scratch();
+      this.spillStorage = spillStorage;
+      try {
+        this.persistedHistory = storage.load(HISTORY_SCHEMA);
+      } catch (error) {
+        console.error('[QUEUE] Failed to load play history:', error);
+      }
+      // Spilled pages from a previous run belong to queues that no longer exist
+      QueueSpill.clearAll(spillStorage);
+    }
+  }
+
+  /** Where each guild's auto-dedupe setting comes from; with it on, songs already queued aren't added again. */
+  setAutoDedupe(isEnabled: (guildId: string) => boolean): void {
+    this.autoDedupe = isEnabled;
+  }
+
+  /**
+   * `songs` without the ones already upcoming or repeated among them, when the
+   * guild has auto-dedupe on; otherwise `songs` unchanged. Callers that report
+   * what was skipped can filter first; addSongs filters again anyway.
+   */
+  withoutQueued(guildId: string, songs: Song[]): Song[] {
+    if (!this.autoDedupe(guildId)) return songs;
+
+    const seen = new Set(this.getFullQueue(guildId).map(songKey));
+    return songs.filter((song) => {
+      const key = songKey(song);
+      if (seen.has(key)) return false;
+      seen.add(key);
+      return true;
+    });
+  }
+
+  /** Removes every upcoming song that's already queued earlier, including any spilled to disk. Returns how many. */
+  removeDuplicates(guildId: string): number {
+    let removed = 0;
+    this.rewriteQueue(guildId, (songs) => {
+      const seen = new Set<string>();
+      const unique = songs.filter((song) => {
+        const key = songKey(song);
+        if (seen.has(key)) return false;
+        seen.add(key);
+        return true;
+      });
+      removed = songs.length - unique.length;
+      songs.splice(0, songs.length, ...unique);
+    });
+    if (removed > 0) playbackEvents.emit('queueChanged', { guildId });
+    return removed;
+  }
 
   getQueue(guildId: string): GuildQueue {
     if (!this.queues.has(guildId)) {
       this.queues.set(guildId, {
         songs: [],
+        history: reviveSongs(this.persistedHistory[guildId]),
         playing: false,
       });
     }
     return this.queues.get(guildId)!;
   }
 
-  addSong(guildId: string, song: Song): void {
-    const queue = this.getQueue(guildId);
-    queue.songs.push(song);
+  addSong(guildId: string, song: Song, limits?: QueueLimits): void {
+    if (limits) checkUnlocked(limits);
+    if (limits && isTooLong(song, limits)) {
+      throw new QueueLimitError('queueLimit.songTooLong', { track: song.title, minutes: limits.maxSongMinutes });
+    }
+    if (this.withoutQueued(guildId, [song]).length === 0) {
+      throw new QueueLimitError('queueLimit.alreadyQueued', { track: song.title });
+    }
+    this.checkLimits(guildId, song.addedBy, 1, limits);
+    song.queueId = this.nextQueueId(guildId);
+    this.appendSong(guildId, song);
+    playbackEvents.emit('queueChanged', { guildId });
     console.log(`[QUEUE] Added song to ${guildId}: ${song.title} (url: ${song.url ? 'present' : 'missing'})`);
   }
 
+  /**
+   * Appends songs in order. When `generation` is given and the queue has been
+   * cleared since it was read, nothing is added. Returns how many songs were added.
+   * With `limits`, songs over the length limit are left out and the rest are
+   * added all or nothing. With auto-dedupe on, songs already queued are left out.
+   */
+  addSongs(guildId: string, songs: Song[], generation?: number, limits?: QueueLimits): number {
+    if (generation !== undefined && generation !== this.getGeneration(guildId)) {
+      console.log(`[QUEUE] Dropped ${songs.length} songs for ${guildId}: the queue was cleared while they were loading`);
+      return 0;
+    }
+    songs = this.withoutQueued(guildId, songs);
+    if (limits) {
+      checkUnlocked(limits);
+      const allowed = songs.filter((song) => !isTooLong(song, limits));
+      if (songs.length > 0 && allowed.length === 0) {
+        throw new QueueLimitError('queueLimit.allTooLong', { count: songs.length, minutes: limits.maxSongMinutes });
+      }
+      if (allowed.length > 0) {
+        this.checkLimits(guildId, allowed[0].addedBy, allowed.length, limits);
+      }
+      songs = allowed;
+    }
+
+    for (const song of songs) {
+      song.queueId = this.nextQueueId(guildId);
+      this.appendSong(guildId, song);
+    }
+    if (songs.length > 0) playbackEvents.emit('queueChanged', { guildId });
+    console.log(`[QUEUE] Added ${songs.length} songs to ${guildId}`);
+    return songs.length;
+  }
+
+  /** Throws QueueLimitError when `count` more songs from `userId` would go over `limits`. */
+  checkLimits(guildId: string, userId: string, count: number, limits?: QueueLimits): void {
+    if (!limits) return;
+    checkUnlocked(limits);
+
+    const size = this.getQueueSize(guildId);
+    if (limits.maxQueueSize > 0 && size + count > limits.maxQueueSize) {
+      const room = Math.max(limits.maxQueueSize - size, 0);
+      throw room === 0
+        ? new QueueLimitError('queueLimit.full', { max: limits.maxQueueSize })
+        : new QueueLimitError('queueLimit.noRoom', { room, max: limits.maxQueueSize, count });
+    }
+
+    if (limits.maxSongsPerUser > 0) {
+      const queued = this.getFullQueue(guildId).filter((song) => song.addedBy === userId).length;
+      if (queued + count > limits.maxSongsPerUser) {
+        throw new QueueLimitError('queueLimit.perUser', { max: limits.maxSongsPerUser, queued });
+      }
+    }
+  }
+
+  /** Changes every time the queue is cleared; capture it before slow work that will add songs. */
+  getGeneration(guildId: string): number {
+    return this.generations.get(guildId) ?? 0;
+  }
+
+  addSongToFront(guildId: string, song: Song): void {
+    const queue = this.getQueue(guildId);
+    // A song coming back to the queue keeps the ID people may have seen for it
+    this.claimQueueId(guildId, song.queueId);
+    song.queueId ??= this.nextQueueId(guildId);
+    queue.songs.unshift(song);
+    playbackEvents.emit('queueChanged', { guildId });
+  }
+
+  /**
+   * Finds a song by its 1-based position or its short ID. Returns the zero-based
+   * index, or -1 when nothing matches.
+   */
+  findSongIndex(guildId: string, reference: string): number {
+    const trimmed = reference.trim().toLowerCase();
+    if (/^\d+$/.test(trimmed)) {
+      const index = Number(trimmed) - 1;
+      return index >= 0 && index < this.getQueueSize(guildId) ? index : -1;
+    }
+    if (!isShortId(trimmed)) {
+      return -1;
+    }
+
+    const inMemory = this.getQueue(guildId).songs.findIndex((song) => song.queueId === trimmed);
+    if (inMemory !== -1) {
+      return inMemory;
+    }
+    return this.getFullQueue(guildId).findIndex((song) => song.queueId === trimmed);
+  }
+
   removeSong(guildId: string, index: number): Song | null {
     const queue = this.getQueue(guildId);
     if (index >= 0 && index < queue.songs.length) {
       const removed = queue.songs.splice(index, 1);
+      this.refillFromSpill(guildId);
+      playbackEvents.emit('queueChanged', { guildId });
       return removed[0];
     }
+    if (index >= queue.songs.length && index < this.getQueueSize(guildId)) {
+      let removed: Song | null = null;
+      this.rewriteQueue(guildId, (songs) => {
+        removed = songs.splice(index, 1)[0];
+      });
+      playbackEvents.emit('queueChanged', { guildId });
+      return removed;
+    }
     return null;
   }
 
+  /** Moves the song at `from` so it ends up at `to` (both zero-based). Returns the moved song. */
+  moveSong(guildId: string, from: number, to: number): Song | null {
+    const queue = this.getQueue(guildId);
+    const size = this.getQueueSize(guildId);
+    if (from < 0 || from >= size || to < 0 || to >= size) {
+      return null;
+    }
+
+    if (from < queue.songs.length && to < queue.songs.length) {
+      const [moved] = queue.songs.splice(from, 1);
+      queue.songs.splice(to, 0, moved);
+      playbackEvents.emit('queueChanged', { guildId });
+      return moved;
+    }
+
+    let moved: Song | null = null;
+    this.rewriteQueue(guildId, (songs) => {
+      [moved] = songs.splice(from, 1);
+      songs.splice(to, 0, moved);
+    });
+    playbackEvents.emit('queueChanged', { guildId });
+    return moved;
+  }
+
+  /** Shuffles every upcoming song, including any spilled to disk. */
+  shuffleQueue(guildId: string): number {
+    this.rewriteQueue(guildId, (songs) => shuffleInPlace(songs));
+    playbackEvents.emit('queueChanged', { guildId });
+    return this.getQueueSize(guildId);
+  }
+
   getCurrentSong(guildId: string): Song | undefined {
     const queue = this.getQueue(guildId);
     return queue.currentSong;
   }
 
-  setCurrentSong(guildId: string, song: Song | undefined): void {
+  /** Whether `userId` asked for the current song and every upcoming one, so skipping affects nobody else. */
+  isSoleRequester(guildId: string, userId: string): boolean {
+    const currentSong = this.getCurrentSong(guildId);
+    return currentSong?.addedBy === userId && this.getFullQueue(guildId).every((song) => song.addedBy === userId);
+  }
+
+  setCurrentSong(guildId: string, song: Song | undefined, recordHistory = true): void {
     const queue = this.getQueue(guildId);
+    // The outgoing song becomes the most recent history entry, so `/back` can return to it.
+    if (recordHistory && queue.currentSong && queue.currentSong !== song) {
+      queue.history.push(queue.currentSong);
+      if (queue.history.length > MAX_HISTORY_SIZE) {
+        queue.history.shift();
+      }
+      this.saveHistory(guildId);
+    }
     queue.currentSong = song;
+    // A song from history or from before a restart keeps its old ID
+    this.claimQueueId(guildId, song?.queueId);
+
+    // Songs from big playlists are queued bare; fetch details for the ones about to play
+    if (song) {
+      resolvePendingMetadata(guildId, [song, ...queue.songs.slice(0, METADATA_LOOKAHEAD)]).catch((error) =>
+        console.error(`[QUEUE] Failed to resolve upcoming metadata for ${guildId}:`, error)
+      );
+    }
+  }
+
+  getHistory(guildId: string): Song[] {
+    const queue = this.getQueue(guildId);
+    return queue.history;
+  }
+
+  popPreviousSong(guildId: string): Song | undefined {
+    const queue = this.getQueue(guildId);
+    const previousSong = queue.history.pop();
+    if (previousSong) {
+      this.saveHistory(guildId);
+    }
+    return previousSong;
+  }
+
+  private getSpill(guildId: string): QueueSpill | undefined {
+    const spillStorage = this.spillStorage;
+    if (!spillStorage) return undefined;
+
+    if (!this.spills.has(guildId)) {
+      this.spills.set(guildId, new QueueSpill(spillStorage, guildId));
+    }
+    return this.spills.get(guildId)!;
+  }
+
+  private appendSong(guildId: string, song: Song): void {
+    const queue = this.getQueue(guildId);
+    const spill = this.getSpill(guildId);
+
+    // Once anything has spilled, new songs must follow it to keep the queue in order
+    if (spill && (spill.size > 0 || queue.songs.length >= MAX_IN_MEMORY_SONGS)) {
+      spill.push(song);
+    } else {
+      queue.songs.push(song);
+    }
+  }
+
+  private nextQueueId(guildId: string): string {
+    const sequence = this.queueIdCounters.get(guildId) ?? this.firstUnusedSequence(guildId);
+    this.queueIdCounters.set(guildId, sequence + 1);
+    return toShortId(sequence);
+  }
+
+  /** Moves the guild's ID counter past `queueId`, so new songs never get the same ID as this one. */
+  claimQueueId(guildId: string, queueId: string | undefined): void {
+    const sequence = queueId === undefined ? undefined : fromShortId(queueId);
+    if (sequence === undefined) return;
+    if (sequence >= (this.queueIdCounters.get(guildId) ?? this.firstUnusedSequence(guildId))) {
+      this.queueIdCounters.set(guildId, sequence + 1);
+    }
+  }
+
+  /** Where a guild's counter starts: after every ID its persisted history was given in earlier runs. */
+  private firstUnusedSequence(guildId: string): number {
+    let next = 0;
+    for (const song of this.getQueue(guildId).history) {
+      const sequence = song.queueId === undefined ? undefined : fromShortId(song.queueId);
+      if (sequence !== undefined && sequence >= next) next = sequence + 1;
+    }
+    return next;
+  }
+
+  /** Applies an edit to the whole queue at once, pulling in and re-spilling songs on disk as needed. */
+  private rewriteQueue(guildId: string, edit: (songs: Song[]) => void): void {
+    const queue = this.getQueue(guildId);
+    const songs = this.getFullQueue(guildId);
+    edit(songs);
+
+    queue.songs = [];
+    this.spills.get(guildId)?.clear();
+    for (const song of songs) {
+      this.appendSong(guildId, song);
+    }
+  }
+
+  private refillFromSpill(guildId: string): void {
+    const spill = this.spills.get(guildId);
+    if (!spill) return;
+
+    const queue = this.getQueue(guildId);
+    while (spill.size > 0 && queue.songs.length <= MAX_IN_MEMORY_SONGS - SPILL_PAGE_SIZE) {
+      try {
+        queue.songs.push(...spill.takePage());
+      } catch (error) {
+        console.error(`[QUEUE] Failed to load spilled songs for ${guildId}:`, error);
+        return;
+      }
+    }
+  }
+
+  private saveHistory(guildId: string): void {
+    if (!this.storage) return;
+
+    this.persistedHistory[guildId] = this.getQueue(guildId).history;
+    this.storage.scheduleSave(HISTORY_SCHEMA, () => this.persistedHistory);
   }
 
   getNextSong(guildId: string): Song | undefined {
@@ -46,25 +441,85 @@ export class QueueManager {
   dequeueNextSong(guildId: string): Song | undefined {
     const queue = this.getQueue(guildId);
     const nextSong = queue.songs.shift();
+    this.refillFromSpill(guildId);
     console.log(`[QUEUE] Dequeued song from ${guildId}: ${nextSong?.title} (url: ${nextSong?.url ? 'present' : 'missing'})`);
     return nextSong;
   }
 
+  /**
+   * Dequeues what plays after `finished` ran to the end, following the loop
+   * mode: `track` plays it again, `queue` sends it to the back of the queue.
+   * A counted track loop turns itself off as its last replay starts.
+   */
+  takeSongAfter(guildId: string, finished: Song | undefined): Song | undefined {
+    const queue = this.getQueue(guildId);
+    const loopMode = this.getLoopMode(guildId);
+    if (finished && loopMode === 'track') {
+      if (queue.loopsLeft !== undefined && --queue.loopsLeft <= 0) {
+        this.setLoopMode(guildId, 'off');
+      }
+      return finished;
+    }
+    if (finished && loopMode === 'queue') {
+      this.appendSong(guildId, finished);
+    }
+    return this.dequeueNextSong(guildId);
+  }
+
+  getLoopMode(guildId: string): LoopMode {
+    return this.getQueue(guildId).loopMode ?? 'off';
+  }
+
+  /** `times` limits a `track` loop to that many replays. */
+  setLoopMode(guildId: string, loopMode: LoopMode, times?: number): void {
+    const queue = this.getQueue(guildId);
+    queue.loopMode = loopMode;
+    queue.loopsLeft = loopMode === 'track' ? times : undefined;
+  }
+
   skipSong(guildId: string): Song | undefined {
     return this.dequeueNextSong(guildId);
   }
 
+  /** Songs held in memory, i.e. the front of the queue; see getQueueSize for the full length. */
   getAllSongs(guildId: string): Song[] {
     const queue = this.getQueue(guildId);
     return queue.songs;
   }
 
+  /** Every upcoming song, including any spilled to disk. Reads from disk, so avoid calling it in hot paths. */
+  getFullQueue(guildId: string): Song[] {
+    const queue = this.getQueue(guildId);
+    const spill = this.spills.get(guildId);
+    return spill && spill.size > 0 ? [...queue.songs, ...spill.peekAll()] : [...queue.songs];
+  }
+
+  /**
+   * Empties the upcoming songs, including any spilled to disk, and cancels
+   * imports still running, like clearQueue but leaving the current song
+   * playing. Returns how many songs were removed.
+   */
+  clearUpcoming(guildId: string): number {
+    const queue = this.getQueue(guildId);
+    const removed = this.getQueueSize(guildId);
+    queue.songs = [];
+    this.spills.get(guildId)?.clear();
+    this.generations.set(guildId, this.getGeneration(guildId) + 1);
+    playbackEvents.emit('queueChanged', { guildId });
+    return removed;
+  }
+
   clearQueue(guildId: string): void {
     const queue = this.getQueue(guildId);
     queue.songs = [];
+    this.spills.get(guildId)?.clear();
+    this.generations.set(guildId, this.getGeneration(guildId) + 1);
     queue.currentSong = undefined;
     queue.playing = false;
     queue.voiceConnection = undefined;
+    queue.loopMode = undefined;
+    queue.loopsLeft = undefined;
+    playbackEvents.emit('queueChanged', { guildId });
   }
 
   setPlaying(guildId: string, playing: boolean): void {
@@ -79,7 +534,7 @@ export class QueueManager {
 
   getQueueSize(guildId: string): number {
     const queue = this.getQueue(guildId);
-    return queue.songs.length;
+    return queue.songs.length + (this.spills.get(guildId)?.size ?? 0);
   }
 
   setPlayerMessage(guildId: string, messageId: string, channelId: string): void {
@@ -96,14 +551,46 @@ export class QueueManager {
     };
   }
 
+  setLastCommandChannel(guildId: string, channelId: string): void {
+    this.getQueue(guildId).lastCommandChannelId = channelId;
+  }
+
+  /** Where notices the bot posts on its own go: the player message's channel, else where the last command was used. */
+  getAnnouncementChannelId(guildId: string): string | undefined {
+    const queue = this.getQueue(guildId);
+    return queue.playerChannelId ?? queue.lastCommandChannelId;
+  }
+
   setSongStartTime(guildId: string, startTime: number): void {
     const queue = this.getQueue(guildId);
     queue.songStartTime = startTime;
+    queue.songStartOffset = 0;
   }
 
   getSongElapsedTime(guildId: string): number {
+    return Math.floor(this.getSongPosition(guildId));
+  }
+
+  /** Re-anchors the song clock, e.g. after restarting playback from a given position. */
+  setSongPosition(guildId: string, position: number): void {
+    const queue = this.getQueue(guildId);
+    queue.songStartTime = Date.now();
+    queue.songStartOffset = position;
+  }
+
+  setPlaybackRate(guildId: string, rate: number): void {
+    const queue = this.getQueue(guildId);
+    // Anchor at the current position so elapsed time stays continuous across the change
+    if (queue.songStartTime) {
+      this.setSongPosition(guildId, this.getSongPosition(guildId));
+    }
+    queue.playbackRate = rate;
+  }
+
+  private getSongPosition(guildId: string): number {
     const queue = this.getQueue(guildId);
     if (!queue.songStartTime) return 0;
-    return Math.floor((Date.now() - queue.songStartTime) / 1000);
+    const realSeconds = (Date.now() - queue.songStartTime) / 1000;
+    return (queue.songStartOffset ?? 0) + realSeconds * (queue.playbackRate ?? 1);
   }
 }
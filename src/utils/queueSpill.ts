@@ -0,0 +1,104 @@
+import * as fs from 'fs';
+import * as path from 'path';
+import { Song } from '../types/index';
+import { DocumentSchema, Storage, reviveSongs } from './storage';
+
+// Clustered processes (told their shards as e.g. `SHARDS=[0]`) each spill into their own
+// directory, so clearing leftovers at startup spares the others' live queues
+const SHARD_IDS = process.env.SHARDS?.match(/\d+/g)?.join('-');
+const SPILL_DIR = SHARD_IDS ? `queue-spill/shard-${SHARD_IDS}` : 'queue-spill';
+export const SPILL_PAGE_SIZE = 100;
+
+/**
+ * Overflow storage for the tail of a very large guild queue. Songs beyond the
+ * in-memory limit are written to disk in fixed-size pages and read back, oldest
+ * page first, as the in-memory part of the queue drains. A page that can't be
+ * written (disk full, no permission) stays in memory in its place instead.
+ */
+export class QueueSpill {
+  // Page IDs on disk, or the songs themselves for pages that failed to write
+  private pages: (number | Song[])[] = [];
+  private nextPageId = 0;
+  // Songs waiting to fill the next page; they sit after every page on disk
+  private tail: Song[] = [];
+  private spilledCount = 0;
+
+  constructor(private readonly storage: Storage, private readonly guildId: string) {}
+
+  static clearAll(storage: Storage): void {
+    fs.rmSync(path.join(storage.getDataDir(), SPILL_DIR), { recursive: true, force: true });
+  }
+
+  get size(): number {
+    return this.spilledCount;
+  }
+
+  push(song: Song): void {
+    this.tail.push(song);
+    this.spilledCount++;
+
+    if (this.tail.length >= SPILL_PAGE_SIZE) {
+      const pageId = this.nextPageId++;
+      try {
+        this.storage.save(this.getPageSchema(pageId), this.tail);
+        this.pages.push(pageId);
+      } catch (error) {
+        console.error(`[QUEUE] Failed to spill songs for ${this.guildId} to disk, keeping them in memory:`, error);
+        this.pages.push(this.tail);
+      }
+      this.tail = [];
+    }
+  }
+
+  /** Removes and returns the oldest spilled songs, at most one page at a time. */
+  takePage(): Song[] {
+    const page = this.pages.shift();
+
+    if (page === undefined) {
+      const songs = this.tail;
+      this.tail = [];
+      this.spilledCount -= songs.length;
+      return songs;
+    }
+
+    if (Array.isArray(page)) {
+      this.spilledCount -= page.length;
+      return page;
+    }
+
+    // Pages are always full, so the count stays right even if a page file went missing
+    const schema = this.getPageSchema(page);
+    const songs = reviveSongs(this.storage.load(schema));
+    this.storage.remove(schema);
+    this.spilledCount -= SPILL_PAGE_SIZE;
+    return songs;
+  }
+
+  /** Reads every spilled song in order without removing anything. */
+  peekAll(): Song[] {
+    const songs: Song[] = [];
+    for (const page of this.pages) {
+      songs.push(...(Array.isArray(page) ? page : reviveSongs(this.storage.load(this.getPageSchema(page)))));
+    }
+    songs.push(...this.tail);
+    return songs;
+  }
+
+  clear(): void {
+    for (const page of this.pages) {
+      if (!Array.isArray(page)) this.storage.remove(this.getPageSchema(page));
+    }
+    this.pages = [];
+    this.tail = [];
+    this.spilledCount = 0;
+  }
+
+  private getPageSchema(pageId: number): DocumentSchema<Song[]> {
+    return {
+      name: `${SPILL_DIR}/${this.guildId}-${pageId}`,
+      version: 1,
+      migrations: {},
+      defaultValue: () => [],
+    };
+  }
+}
@@ -0,0 +1,81 @@
+import type { SourceExtractor } from './sourceExtractor';
+import { GoonError } from './errors';
+import { getPublic, headerValue, isPublicHttpUrl, readText } from './publicHttp';
+
+// Station playlists are a few lines long
+const MAX_PLAYLIST_BYTES = 64 * 1024;
+
+// Stream and playlist paths internet radio servers use, e.g. `/stream`, `/radio.mp3` or Shoutcast's `/;`
+const STREAM_PATH = /(?:\.(?:mp3|aac|aacp|ogg|opus|m3u|pls)|\/(?:stream|listen|live)|\/;)\/?$/i;
+const PLAYLIST_PATH = /\.(?:m3u|pls)$/i;
+
+// Stations are fetched by the bot itself, so links into the host's own network are refused,
+// whether given directly, redirected to or resolved to; see publicHttp
+function isStreamUrl(url: string): boolean {
+  return isPublicHttpUrl(url) && STREAM_PATH.test(new URL(url).pathname);
+}
+
+/** The first stream an `.m3u` or `.pls` playlist lists; other URLs are returned as they are. */
+export async function resolveStationUrl(url: string): Promise<string> {
+  if (!PLAYLIST_PATH.test(new URL(url).pathname)) return url;
+
+  const { response } = await getPublic(url);
+  if (response.statusCode !== 200) {
+    response.destroy();
+    throw new GoonError('unavailable', `Station playlist answered HTTP ${response.statusCode}`);
+  }
+  // .pls lines look like `File1=http://...`; .m3u lists URLs between `#` comments
+  const stream = (await readText(response, MAX_PLAYLIST_BYTES))
+    .split(/\r?\n/)
+    .map((line) => line.trim().replace(/^File\d+=/i, ''))
+    .find(isPublicHttpUrl);
+  if (!stream) throw new GoonError('unavailable', `No streams in station playlist ${url}`);
+  return stream;
+}
+
+/** Internet radio: Icecast and Shoutcast streams, or the .m3u/.pls playlists pointing at them, played by ffmpeg. */
+export const radioExtractor: SourceExtractor = {
+  name: 'radio',
+  sources: ['radio'],
+
+  matchesUrl: isStreamUrl,
+
+  async extract(url, userId) {
+    const streamUrl = await resolveStationUrl(url);
+    const { response } = await getPublic(streamUrl, { 'Icy-MetaData': '1' });
+    // Only the headers are needed; the body is the never-ending stream
+    response.destroy();
+
+    const status = response.statusCode ?? 0;
+    const contentType = headerValue(response, 'content-type') ?? '';
+    const name = headerValue(response, 'icy-name');
+    if (status < 200 || status >= 300 || (!contentType.startsWith('audio/') && !contentType.includes('ogg') && !name)) {
+      throw new GoonError('unavailable', `${streamUrl} isn't an audio stream (HTTP ${status}, ${contentType || 'no type'})`);
+    }
+
+    return {
+      id: streamUrl,
+      title: name || new URL(streamUrl).hostname,
+      url,
+      duration: 0,
+      addedBy: userId,
+      channel: headerValue(response, 'icy-genre'),
+      isLive: true,
+      source: { kind: 'radio' },
+    };
+  },
+
+  // Stations are played by link; there's nothing to search
+  search: async () => null,
+
+  // Fetched here and piped to ffmpeg, which would otherwise follow redirects without these checks
+  async stream(song) {
+    const { response } = await getPublic(await resolveStationUrl(song.url));
+    const status = response.statusCode ?? 0;
+    if (status < 200 || status >= 300) {
+      response.destroy();
+      throw new GoonError('unavailable', `Station answered HTTP ${status}`);
+    }
+    return { path: 'pipe:0', stream: response, release: () => response.destroy() };
+  },
+};
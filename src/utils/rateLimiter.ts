@@ -0,0 +1,87 @@
+import { RepliableInteraction } from 'discord.js';
+import { BotServices } from '../types/index';
+
+/** Requests allowed per minute, which is also how many can be made in a burst. */
+const USER_LIMIT_PER_MINUTE = Math.max(Number(process.env.USER_EXTRACTION_LIMIT) || 10, 1);
+const GUILD_LIMIT_PER_MINUTE = Math.max(Number(process.env.GUILD_EXTRACTION_LIMIT) || 30, 1);
+
+interface Bucket {
+  tokens: number;
+  updatedAt: number;
+}
+
+export type RateLimitResult = { allowed: true } | { allowed: false; scope: 'user' | 'guild'; retryAfterMs: number };
+
+/**
+ * Token buckets for commands that run yt-dlp, one per user in each guild and
+ * one per guild, so one person spamming searches can't use up the extraction
+ * slots playback needs. A request takes a token from both buckets; tokens
+ * come back steadily up to the per-minute limit.
+ */
+export class ExtractionRateLimiter {
+  private readonly buckets: Map<string, Bucket> = new Map();
+
+  constructor(
+    private readonly userLimit = USER_LIMIT_PER_MINUTE,
+    private readonly guildLimit = GUILD_LIMIT_PER_MINUTE
+  ) {}
+
+  /** Takes a token for `userId` in `guildId` if both buckets have one; otherwise says how long to wait. */
+  tryConsume(guildId: string, userId: string, now = Date.now()): RateLimitResult {
+    this.prune(now);
+
+    const user = this.refill(`${guildId}:${userId}`, this.userLimit, now);
+    const guild = this.refill(guildId, this.guildLimit, now);
+    if (user.tokens < 1) {
+      return { allowed: false, scope: 'user', retryAfterMs: this.waitFor(user, this.userLimit) };
+    }
+    if (guild.tokens < 1) {
+      return { allowed: false, scope: 'guild', retryAfterMs: this.waitFor(guild, this.guildLimit) };
+    }
+
+    user.tokens -= 1;
+    guild.tokens -= 1;
+    return { allowed: true };
+  }
+
+  private refill(key: string, limit: number, now: number): Bucket {
+    let bucket = this.buckets.get(key);
+    if (!bucket) {
+      bucket = { tokens: limit, updatedAt: now };
+      this.buckets.set(key, bucket);
+    }
+    bucket.tokens = Math.min(limit, bucket.tokens + ((now - bucket.updatedAt) / 60_000) * limit);
+    bucket.updatedAt = now;
+    return bucket;
+  }
+
+  private waitFor(bucket: Bucket, limit: number): number {
+    return Math.ceil(((1 - bucket.tokens) / limit) * 60_000);
+  }
+
+  // A bucket that would be full again is the same as no bucket
+  private prune(now: number): void {
+    for (const [key, bucket] of this.buckets) {
+      const limit = key.includes(':') ? this.userLimit : this.guildLimit;
+      if (bucket.tokens + ((now - bucket.updatedAt) / 60_000) * limit >= limit) {
+        this.buckets.delete(key);
+      }
+    }
+  }
+}
+
+/**
+ * Takes a token for the interaction's user before a command runs yt-dlp.
+ * When they or their server are over the limit, says how long to wait and returns false.
+ */
+export async function checkExtractionLimit(interaction: RepliableInteraction, services: BotServices): Promise<boolean> {
+  const result = services.rateLimiter.tryConsume(interaction.guildId!, interaction.user.id);
+  if (result.allowed) return true;
+
+  const t = services.guildSettings.translator(interaction.guildId!);
+  const content = t(result.scope === 'user' ? 'guard.userRateLimited' : 'guard.guildRateLimited', {
+    seconds: Math.ceil(result.retryAfterMs / 1000),
+  });
+  await (interaction.deferred ? interaction.editReply(content) : interaction.reply({ content, ephemeral: true }));
+  return false;
+}
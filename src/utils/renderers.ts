@@ -0,0 +1,384 @@
+import { AttachmentBuilder, EmbedBuilder } from 'discord.js';
+import { AudioFilters, EmbedTheme, RendererStyle, Song } from '../types/index';
+import { QueueManager } from './queueManager';
+import { YouTubeUtil } from './youtubeUtil';
+import { getDisplayThumbnail } from './thumbnailProxy';
+import { renderNowPlayingCard } from './imageCard';
+import { formatChapter, getChapterAt } from './chapters';
+import { formatTrack } from './titleParser';
+import { Translator } from './i18n';
+import { createThemedEmbed, getThemeEmoji, withEmoji } from './embedTheme';
+
+const PLACEHOLDER_THUMBNAIL = 'https://via.placeholder.com/160x160?text=No+Song';
+const CARD_FILE_NAME = 'now-playing.png';
+
+export const RENDERER_STYLES: Record<RendererStyle, string> = {
+  classic: 'Embeds with artwork and a progress bar',
+  compact: 'Plain text, one line per song',
+  card: 'A generated now-playing image',
+};
+
+/** Everything a renderer needs to draw the player or the queue. */
+export interface PlayerView {
+  currentSong?: Song;
+  elapsed: number;
+  /** The first few upcoming songs; how many depends on what is being rendered. */
+  upcoming: Song[];
+  queueSize: number;
+  /** Seconds of audio in the whole upcoming queue, counting only songs whose duration is known. */
+  queueDuration: number;
+  /** Speed and pitch the player is applying, if known. */
+  filters?: AudioFilters;
+  /** Messages in the guild's language. */
+  t: Translator;
+  theme: EmbedTheme;
+}
+
+export interface RenderedMessage {
+  content: string;
+  embeds: EmbedBuilder[];
+  files: AttachmentBuilder[];
+  /** Source of the thumbnail in `files`, so an edit can keep the one already attached when it's the same. */
+  thumbnailSource?: string;
+}
+
+/** Turns player state into a message. One per display style, chosen per guild. */
+export interface Renderer {
+  renderNowPlaying(view: PlayerView): Promise<RenderedMessage>;
+  renderQueue(view: PlayerView): Promise<RenderedMessage>;
+}
+
+export function buildPlayerView(
+  queueManager: QueueManager,
+  guildId: string,
+  previewSize: number,
+  t: Translator,
+  theme: EmbedTheme,
+  filters?: AudioFilters
+): PlayerView {
+  return {
+    currentSong: queueManager.getCurrentSong(guildId),
+    elapsed: queueManager.getSongElapsedTime(guildId),
+    upcoming: queueManager.getAllSongs(guildId).slice(0, previewSize),
+    queueSize: queueManager.getQueueSize(guildId),
+    queueDuration: queueManager.getFullQueue(guildId).reduce((total, song) => total + song.duration, 0),
+    filters,
+    t,
+    theme,
+  };
+}
+
+export function buildProgressBar(elapsed: number, total: number): string {
+  const barLength = 20;
+  const filledLength = Math.round((elapsed / total) * barLength);
+  const emptyLength = barLength - filledLength;
+  const bar =
+    '█'.repeat(Math.max(0, filledLength - 1)) +
+    '🔘' +
+    '░'.repeat(Math.max(0, emptyLength - 1));
+
+  const elapsedStr = YouTubeUtil.formatDuration(elapsed);
+  const totalStr = YouTubeUtil.formatDuration(total);
+
+  return `\`${elapsedStr}\` ${bar} \`${totalStr}\``;
+}
+
+/** What a radio station says it's playing, once its metadata has been read. */
+function onAirText({ currentSong, t, theme }: PlayerView): string | undefined {
+  if (!currentSong?.streamTitle) return undefined;
+  return withEmoji(getThemeEmoji(theme).onAir, t('player.onAir', { title: currentSong.streamTitle }));
+}
+
+function currentChapterText({ currentSong, elapsed }: PlayerView): string | undefined {
+  if (!currentSong) return undefined;
+  const current = getChapterAt(currentSong, elapsed);
+  return current ? formatChapter(currentSong, current.index) : undefined;
+}
+
+/** E.g. `1.25x speed, 1.25x pitch`; undefined when playing as recorded. */
+function filtersText({ filters, t }: PlayerView): string | undefined {
+  if (!filters) return undefined;
+  const parts = [
+    filters.speed !== 1 && t('player.speed', { value: filters.speed }),
+    filters.pitch !== 1 && t('player.pitch', { value: filters.pitch }),
+  ].filter(Boolean);
+  return parts.length > 0 ? parts.join(', ') : undefined;
+}
+
+// Songs restored from an old save may not know who asked for them. Only used
+// in embeds, where mentions don't ping anyone.
+function requesterMention(song: Song): string | undefined {
+  return /^\d+$/.test(song.addedBy) ? `<@${song.addedBy}>` : undefined;
+}
+
+/** The song's length as code, or the live badge for a stream. */
+function lengthText(song: Song, t: Translator): string {
+  return song.isLive ? t('player.live') : `\`${YouTubeUtil.formatDuration(song.duration)}\``;
+}
+
+/** e.g. `1h 47m`, or `12m` under an hour. */
+function formatLength(seconds: number): string {
+  const hours = Math.floor(seconds / 3600);
+  const minutes = Math.floor((seconds % 3600) / 60);
+  return hours > 0 ? `${hours}h ${minutes}m` : `${minutes}m`;
+}
+
+// Real time rather than audio time, so a sped-up queue arrives sooner
+function toRealSeconds(view: PlayerView, seconds: number): number {
+  return Math.round(seconds / (view.filters?.speed ?? 1));
+}
+
+/**
+ * How long until each upcoming song starts: what's left of the current song
+ * plus everything before it. Unknown for anything after a live stream.
+ */
+function startTimes(view: PlayerView): (number | undefined)[] {
+  const current = view.currentSong;
+  let offset = current?.isLive ? undefined : current ? Math.max(current.duration - view.elapsed, 0) : 0;
+  return view.upcoming.map((song) => {
+    const start = offset;
+    if (offset !== undefined) offset = song.isLive ? undefined : offset + song.duration;
+    return start === undefined ? undefined : toRealSeconds(view, start);
+  });
+}
+
+function formatUpcoming(view: PlayerView, showStartTimes = false): string {
+  const starts = showStartTimes ? startTimes(view) : [];
+  return view.upcoming
+    .map((song, index) => {
+      const start = starts[index];
+      const details = [
+        song.isLive && view.t('player.live'),
+        requesterMention(song),
+        start !== undefined && view.t('player.playsIn', { time: YouTubeUtil.formatDuration(start) }),
+      ];
+      return [`${index + 1}. \`${song.queueId}\` [${formatTrack(song)}](${song.url})`, ...details].filter(Boolean).join(' · ');
+    })
+    .join('\n');
+}
+
+/** The queue's footer: how many more songs aren't listed, and how long the whole queue runs. */
+function queueFooterText(view: PlayerView): string | undefined {
+  const total = view.queueDuration > 0 && view.t('player.totalLength', { time: formatLength(toRealSeconds(view, view.queueDuration)) });
+  return [moreSongsText(view), total].filter(Boolean).join(' • ') || undefined;
+}
+
+function moreSongsText(view: PlayerView): string | undefined {
+  const remaining = view.queueSize - view.upcoming.length;
+  return remaining > 0 ? view.t('player.moreSongs', { count: remaining }) : undefined;
+}
+
+export class ClassicRenderer implements Renderer {
+  async renderNowPlaying(view: PlayerView): Promise<RenderedMessage> {
+    const { currentSong, elapsed, queueSize, t, theme } = view;
+    const emoji = getThemeEmoji(theme);
+    const footerNote =
+      view.upcoming.length > 0 ? (moreSongsText(view) ?? t('player.endOfQueue')) : t('player.lastSong');
+    const embed = createThemedEmbed(theme, footerNote)
+      .setTitle(withEmoji(emoji.nowPlaying, t('player.nowPlaying')))
+      .setThumbnail(PLACEHOLDER_THUMBNAIL);
+
+    if (currentSong) {
+      const onAir = onAirText(view);
+      embed.setDescription(
+        `**${formatTrack(currentSong)}**` +
+          (currentSong.album ? `\n${withEmoji(emoji.album, currentSong.album)}` : '') +
+          (onAir ? `\n${onAir}` : '')
+      );
+      const chapter = currentChapterText(view);
+      if (chapter) {
+        embed.addFields({ name: withEmoji(emoji.chapter, t('player.chapter')), value: chapter, inline: false });
+      }
+      if (currentSong.isLive) {
+        // No end to measure progress against, so just how long it's been playing
+        embed.addFields({
+          name: withEmoji(emoji.progress, t('player.progress')),
+          value: `${t('player.live')} · \`${YouTubeUtil.formatDuration(elapsed)}\``,
+          inline: false,
+        });
+      } else {
+        embed.addFields(
+          {
+            name: withEmoji(emoji.progress, t('player.progress')),
+            value: buildProgressBar(elapsed, currentSong.duration),
+            inline: false,
+          },
+          {
+            name: withEmoji(emoji.duration, t('player.duration')),
+            value: YouTubeUtil.formatDuration(currentSong.duration),
+            inline: true,
+          }
+        );
+      }
+      embed.addFields({
+        name: withEmoji(emoji.queueSize, t('player.queueSizeLabel')),
+        value: queueSize === 1 ? t('player.songCountOne') : t('player.songCount', { count: queueSize }),
+        inline: true,
+      });
+      const requester = requesterMention(currentSong);
+      if (requester) {
+        embed.addFields({ name: withEmoji(emoji.requester, t('player.requestedBy')), value: requester, inline: true });
+      }
+      const filters = filtersText(view);
+      if (filters) {
+        embed.addFields({ name: withEmoji(emoji.filters, t('player.filters')), value: filters, inline: true });
+      }
+    } else {
+      embed.setDescription(t('player.noSong'));
+    }
+
+    if (view.upcoming.length > 0) {
+      embed.addFields({ name: withEmoji(emoji.upNext, t('player.upNext')), value: formatUpcoming(view), inline: false });
+    }
+
+    return { content: '', embeds: [embed], ...(await this.attachThumbnail(embed, currentSong)) };
+  }
+
+  async renderQueue(view: PlayerView): Promise<RenderedMessage> {
+    const { currentSong, queueSize, t, theme } = view;
+    const emoji = getThemeEmoji(theme);
+    const embed = createThemedEmbed(theme, queueFooterText(view))
+      .setTitle(withEmoji(emoji.queue, t('player.queueTitle')))
+      .setThumbnail(PLACEHOLDER_THUMBNAIL);
+
+    if (currentSong) {
+      const requester = requesterMention(currentSong);
+      embed.setDescription(
+        `**${formatTrack(currentSong)}**\n\n${lengthText(currentSong, t)}` +
+          (requester ? ` · ${withEmoji(emoji.requester, requester)}` : '')
+      );
+    }
+
+    if (view.upcoming.length > 0) {
+      embed.addFields({
+        name: withEmoji(emoji.upNext, t('player.upNextCount', { count: queueSize })),
+        value: formatUpcoming(view, true),
+        inline: false,
+      });
+    }
+
+    return { content: '', embeds: [embed], ...(await this.attachThumbnail(embed, currentSong)) };
+  }
+
+  private async attachThumbnail(embed: EmbedBuilder, song?: Song): Promise<Pick<RenderedMessage, 'files' | 'thumbnailSource'>> {
+    if (!song?.thumbnail) return { files: [] };
+
+    const thumbnail = await getDisplayThumbnail(song.thumbnail);
+    embed.setThumbnail(thumbnail.url);
+    return thumbnail.file ? { files: [thumbnail.file], thumbnailSource: song.thumbnail } : { files: [] };
+  }
+}
+
+export class CompactRenderer implements Renderer {
+  async renderNowPlaying(view: PlayerView): Promise<RenderedMessage> {
+    const lines = [this.formatCurrent(view)];
+    if (view.upcoming.length > 0) {
+      lines.push(...view.upcoming.map((song, index) => `\`${index + 1}.\` \`${song.queueId}\` ${formatTrack(song)}`));
+    }
+    const more = moreSongsText(view);
+    if (more) lines.push(more);
+
+    return { content: lines.join('\n'), embeds: [], files: [] };
+  }
+
+  async renderQueue(view: PlayerView): Promise<RenderedMessage> {
+    const rendered = await this.renderNowPlaying(view);
+    const title = withEmoji(getThemeEmoji(view.theme).queue, view.t('player.compactQueueTitle', { count: view.queueSize }));
+    return { ...rendered, content: `${title}\n${rendered.content}` };
+  }
+
+  private formatCurrent(view: PlayerView): string {
+    const { currentSong, elapsed } = view;
+    const emoji = getThemeEmoji(view.theme);
+    if (!currentSong) return withEmoji(emoji.nowPlaying, view.t('player.nothingPlaying'));
+
+    const chapter = currentChapterText(view);
+    const filters = filtersText(view);
+    const onAir = onAirText(view);
+    return (
+      withEmoji(
+        emoji.nowPlaying,
+        `**${formatTrack(currentSong)}** ` +
+          (currentSong.isLive
+            ? `${view.t('player.live')} \`${YouTubeUtil.formatDuration(elapsed)}\``
+            : `\`${YouTubeUtil.formatDuration(elapsed)} / ${YouTubeUtil.formatDuration(currentSong.duration)}\``)
+      ) +
+      (onAir ? ` · ${onAir}` : '') +
+      (chapter ? ` · ${withEmoji(emoji.chapter, chapter)}` : '') +
+      (filters ? ` · ${withEmoji(emoji.filters, filters)}` : '')
+    );
+  }
+}
+
+/** Draws the current song as an image; falls back to the classic embeds if drawing fails. */
+export class ImageCardRenderer implements Renderer {
+  private readonly fallback = new ClassicRenderer();
+
+  renderNowPlaying(view: PlayerView): Promise<RenderedMessage> {
+    return this.render(
+      view,
+      withEmoji(getThemeEmoji(view.theme).nowPlaying, view.t('player.nowPlaying')),
+      false,
+      () => this.fallback.renderNowPlaying(view)
+    );
+  }
+
+  renderQueue(view: PlayerView): Promise<RenderedMessage> {
+    return this.render(
+      view,
+      withEmoji(getThemeEmoji(view.theme).queue, view.t('player.queueTitleCount', { count: view.queueSize })),
+      true,
+      () => this.fallback.renderQueue(view));
+  }
+
+  private async render(
+    view: PlayerView,
+    title: string,
+    isQueue: boolean,
+    fallback: () => Promise<RenderedMessage>
+  ): Promise<RenderedMessage> {
+    if (!view.currentSong) {
+      return fallback();
+    }
+
+    let card: Buffer;
+    try {
+      card = await renderNowPlayingCard(view.currentSong, view.elapsed);
+    } catch (error) {
+      console.error('Error drawing now-playing card:', error);
+      return fallback();
+    }
+
+    const emoji = getThemeEmoji(view.theme);
+    const embed = createThemedEmbed(view.theme, isQueue ? queueFooterText(view) : moreSongsText(view))
+      .setTitle(title)
+      .setImage(`attachment://${CARD_FILE_NAME}`);
+
+    const chapter = currentChapterText(view);
+    const filters = filtersText(view);
+    const requester = requesterMention(view.currentSong);
+    const description = [
+      view.currentSong.isLive && view.t('player.live'),
+      onAirText(view),
+      requester && withEmoji(emoji.requester, view.t('player.requestedByUser', { user: requester })),
+      chapter && withEmoji(emoji.chapter, chapter),
+      filters && withEmoji(emoji.filters, filters),
+      view.upcoming.length > 0 && formatUpcoming(view, isQueue),
+    ].filter(Boolean);
+    if (description.length > 0) {
+      embed.setDescription(description.join('\n\n'));
+    }
+
+    return { content: '', embeds: [embed], files: [new AttachmentBuilder(card, { name: CARD_FILE_NAME })] };
+  }
+}
+
+const renderers: Record<RendererStyle, Renderer> = {
+  classic: new ClassicRenderer(),
+  compact: new CompactRenderer(),
+  card: new ImageCardRenderer(),
+};
+
+export function getRenderer(style: RendererStyle): Renderer {
+  return renderers[style] ?? renderers.classic;
+}
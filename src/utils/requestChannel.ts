@@ -0,0 +1,103 @@
+import { Message } from 'discord.js';
+import { QueueManager } from './queueManager';
+import { MusicPlayer } from './musicPlayer';
+import { YouTubeUtil } from './youtubeUtil';
+import { startPlaybackIn } from './playback';
+import { findExtractor } from './sourceExtractor';
+import { getQueueLimits } from './permissions';
+import { describeBlockedSong, findBlacklistMatch } from './blacklist';
+import { describeError, logError } from './errors';
+import { Translator } from './i18n';
+import { BotServices, Song } from '../types/index';
+
+// Reading messages needs the privileged Message Content intent, so it's opt-in
+export const REQUEST_CHANNELS_ENABLED = process.env.REQUEST_CHANNELS === 'true';
+
+// Long enough to see the reaction, or read why a request failed
+const PROCESSED_MESSAGE_TTL_MS = 10_000;
+
+/**
+ * Treats a plain message in the server's song-request channel as /play for
+ * one song. The message gets ✅ or ❌, with a short reply saying why it
+ * failed, and both are deleted a few seconds later so the channel stays tidy.
+ */
+export async function handleRequestMessage(
+  message: Message,
+  queueManager: QueueManager,
+  getMusicPlayer: (guildId: string) => MusicPlayer,
+  startPlayerUpdate: (guildId: string) => void,
+  services: BotServices
+): Promise<void> {
+  if (!message.inGuild() || message.author.bot || message.system) return;
+  const guildId = message.guildId;
+  const settings = services.guildSettings.get(guildId);
+  if (message.channelId !== settings.requestChannelId || settings.disabledCommands.includes('play')) return;
+
+  const input = message.content.trim();
+  if (!input) return;
+
+  const t = services.guildSettings.translator(guildId);
+  let failure: string | undefined;
+  try {
+    queueManager.setLastCommandChannel(guildId, message.channelId);
+    failure = await queueFromMessage(message, input, queueManager, getMusicPlayer(guildId), services, t);
+    if (!failure) startPlayerUpdate(guildId);
+  } catch (error) {
+    logError('Request channel error', error);
+    failure = describeError(error, t, 'play.failed');
+  }
+
+  await message.react(failure ? '❌' : '✅').catch(() => undefined);
+  const reply = failure
+    ? await message.reply({ content: failure, allowedMentions: { repliedUser: false } }).catch(() => undefined)
+    : undefined;
+  setTimeout(() => {
+    void message.delete().catch(() => undefined);
+    void reply?.delete().catch(() => undefined);
+  }, PROCESSED_MESSAGE_TTL_MS);
+}
+
+/** Queues the song `input` names and starts playback if needed; returns why it couldn't, if it couldn't. */
+async function queueFromMessage(
+  message: Message<true>,
+  input: string,
+  queueManager: QueueManager,
+  musicPlayer: MusicPlayer,
+  services: BotServices,
+  t: Translator
+): Promise<string | undefined> {
+  const guildId = message.guildId;
+  const userId = message.author.id;
+  const voiceChannel = message.member?.voice.channel;
+  if (!voiceChannel) return t('common.joinVoiceFirst');
+
+  const limit = services.rateLimiter.tryConsume(guildId, userId);
+  if (!limit.allowed) {
+    return t(limit.scope === 'user' ? 'guard.userRateLimited' : 'guard.guildRateLimited', {
+      seconds: Math.ceil(limit.retryAfterMs / 1000),
+    });
+  }
+
+  const urlOrQuery = YouTubeUtil.normalizeUrl(services.guildSettings.resolveAlias(guildId, input) ?? input);
+  let song: Song | null;
+  if (YouTubeUtil.isUrl(urlOrQuery)) {
+    if (YouTubeUtil.isPlaylistUrl(urlOrQuery)) return t('requestChannel.noPlaylists');
+    const extractor = findExtractor(urlOrQuery);
+    if (!extractor) return t('play.invalidUrl');
+    song = await extractor.extract(urlOrQuery, userId, guildId);
+  } else {
+    song = await YouTubeUtil.searchSong(urlOrQuery, userId, guildId);
+    if (!song) return t('play.noResults', { query: urlOrQuery });
+  }
+
+  const settings = services.guildSettings.get(guildId);
+  const blockedBy = findBlacklistMatch(song, settings.blacklist);
+  if (blockedBy) return describeBlockedSong(song, blockedBy, t);
+
+  const member = message.member;
+  queueManager.addSong(guildId, song, getQueueLimits({ member, memberPermissions: member?.permissions ?? null }, settings));
+  if (!queueManager.isPlaying(guildId)) {
+    await startPlaybackIn(voiceChannel, queueManager, musicPlayer);
+  }
+  return undefined;
+}
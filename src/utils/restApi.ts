@@ -0,0 +1,166 @@
+import * as crypto from 'crypto';
+import { Song } from '../types/index';
+import { findBlacklistMatch } from './blacklist';
+import { GuildSettingsManager } from './guildSettings';
+import { ExtractionRateLimiter } from './rateLimiter';
+import { MusicPlayer } from './musicPlayer';
+import { skipSong, startPlaybackIfConnected } from './playback';
+import { QueueLimitError, QueueManager } from './queueManager';
+import { HttpError, WebRequest, WebServer, getBearerToken } from './webServer';
+import { YouTubeUtil } from './youtubeUtil';
+import { findExtractor } from './sourceExtractor';
+
+export function hashApiKey(key: string): string {
+  return crypto.createHash('sha256').update(key).digest('hex');
+}
+
+/** A new random API key; show it once and store only the hash. */
+export function createApiKey(): { key: string; hash: string } {
+  const key = `goon_${crypto.randomBytes(24).toString('base64url')}`;
+  return { key, hash: hashApiKey(key) };
+}
+
+/** The bot state the REST API reads and changes. */
+export interface ApiContext {
+  queueManager: QueueManager;
+  guildSettings: GuildSettingsManager;
+  rateLimiter: ExtractionRateLimiter;
+  getMusicPlayer(guildId: string): MusicPlayer;
+}
+
+export function toApiSong(song: Song) {
+  return {
+    queueId: song.queueId,
+    title: song.title,
+    url: song.url,
+    duration: song.duration,
+    thumbnail: song.thumbnail,
+    addedBy: song.addedBy,
+  };
+}
+
+/** The guild an API key belongs to; throws a 401 for a missing or unknown key. */
+export function findGuildByApiKey(guildSettings: GuildSettingsManager, key: string | null | undefined): string {
+  const hash = key ? hashApiKey(key) : undefined;
+  const guildId = hash ? guildSettings.findGuild((settings) => settings.apiKey?.hash === hash) : undefined;
+  if (!guildId) throw new HttpError(401, 'Missing or unknown API key');
+  return guildId;
+}
+
+/** The current song, how many seconds in it is and whether it's paused; null when nothing is playing. */
+export function describeNowPlaying(context: ApiContext, guildId: string) {
+  const current = context.queueManager.getCurrentSong(guildId);
+  if (!current) return null;
+  return {
+    song: toApiSong(current),
+    elapsed: Math.floor(context.queueManager.getSongElapsedTime(guildId)),
+    paused: !context.getMusicPlayer(guildId).isPlaying(),
+  };
+}
+
+/**
+ * Adds `/api/…` endpoints for scripts and overlays. Each request carries a
+ * server's API key (from `/settings apikey`) as a bearer token and acts on
+ * that server.
+ */
+export function registerApiRoutes(server: WebServer, context: ApiContext): void {
+  const { queueManager, guildSettings } = context;
+
+  const authorize = (request: WebRequest) => findGuildByApiKey(guildSettings, getBearerToken(request));
+  const nowPlaying = (guildId: string) => describeNowPlaying(context, guildId);
+
+  server.route('GET', '/api/nowplaying', (request) => {
+    const guildId = authorize(request);
+    return { body: { nowPlaying: nowPlaying(guildId) } };
+  });
+
+  server.route('GET', '/api/queue', (request) => {
+    const guildId = authorize(request);
+    return {
+      body: {
+        nowPlaying: nowPlaying(guildId),
+        songs: queueManager.getFullQueue(guildId).map(toApiSong),
+      },
+    };
+  });
+
+  server.route('POST', '/api/queue', async (request) => {
+    const guildId = authorize(request);
+    const settings = guildSettings.get(guildId);
+    const query = request.body?.query;
+    if (typeof query !== 'string' || query.trim().length === 0) {
+      throw new HttpError(400, 'query must be a URL or search terms');
+    }
+    // Songs are added as the key's creator unless the caller names a Discord user
+    const userId = request.body?.userId ?? settings.apiKey!.createdBy;
+    if (typeof userId !== 'string' || !/^\d+$/.test(userId)) {
+      throw new HttpError(400, 'userId must be a Discord user ID');
+    }
+
+    // Shares the user's and guild's budget with /play, so scripts can't crowd out people in Discord
+    const limit = context.rateLimiter.tryConsume(guildId, userId);
+    if (!limit.allowed) {
+      throw new HttpError(429, `Too many songs requested; try again in ${Math.ceil(limit.retryAfterMs / 1000)}s`);
+    }
+
+    const urlOrQuery = YouTubeUtil.normalizeUrl(guildSettings.resolveAlias(guildId, query) ?? query);
+    let song: Song | null;
+    if (YouTubeUtil.isUrl(urlOrQuery)) {
+      if (YouTubeUtil.isPlaylistUrl(urlOrQuery)) {
+        throw new HttpError(400, "Playlists can't be queued through the API; queue their songs one at a time");
+      }
+      const extractor = findExtractor(urlOrQuery);
+      if (!extractor) throw new HttpError(400, 'Only YouTube, Twitch and internet radio URLs are supported');
+      song = await extractor.extract(urlOrQuery, userId, guildId);
+    } else {
+      song = await YouTubeUtil.searchSong(urlOrQuery, userId, guildId);
+      if (!song) throw new HttpError(404, `No results found for ${urlOrQuery}`);
+    }
+
+    const blockedBy = findBlacklistMatch(song, settings.blacklist);
+    if (blockedBy) {
+      throw new HttpError(403, `${song.title} is blocked on this server${blockedBy.reason ? `: ${blockedBy.reason}` : ''}`);
+    }
+
+    try {
+      // API keys are made by admins, so like admins they aren't held to the queue limits
+      queueManager.addSong(guildId, song);
+    } catch (error) {
+      if (error instanceof QueueLimitError) throw new HttpError(409, error.message);
+      throw error;
+    }
+    await startPlaybackIfConnected(guildId, queueManager, context.getMusicPlayer(guildId));
+
+    return {
+      status: 201,
+      body: {
+        song: toApiSong(song),
+        position: queueManager.getFullQueue(guildId).indexOf(song) + 1,
+        nowPlaying: nowPlaying(guildId),
+      },
+    };
+  });
+
+  server.route('POST', '/api/skip', async (request) => {
+    const guildId = authorize(request);
+    const current = queueManager.getCurrentSong(guildId);
+    if (!current) throw new HttpError(409, 'Nothing is currently playing');
+
+    await skipSong(guildId, queueManager, context.getMusicPlayer(guildId), current);
+    return { body: { skipped: toApiSong(current), nowPlaying: nowPlaying(guildId) } };
+  });
+
+  server.route('POST', '/api/pause', (request) => {
+    const guildId = authorize(request);
+    if (!queueManager.getCurrentSong(guildId)) throw new HttpError(409, 'Nothing is currently playing');
+    context.getMusicPlayer(guildId).pause();
+    return { body: { nowPlaying: nowPlaying(guildId) } };
+  });
+
+  server.route('POST', '/api/resume', (request) => {
+    const guildId = authorize(request);
+    if (!queueManager.getCurrentSong(guildId)) throw new HttpError(409, 'Nothing is currently playing');
+    context.getMusicPlayer(guildId).unpause();
+    return { body: { nowPlaying: nowPlaying(guildId) } };
+  });
+}
@@ -0,0 +1,45 @@
+import { isTransientError } from './errors';
+
+export interface RetryPolicy {
+  /** Tries in total, including the first. */
+  attempts: number;
+  /** Wait before the first retry; doubles after each one. */
+  baseDelayMs: number;
+  maxDelayMs: number;
+}
+
+// yt-dlp failures from throttling usually clear within a few seconds
+export const EXTRACTION_RETRY: RetryPolicy = {
+  attempts: Math.max(Number(process.env.YTDLP_MAX_ATTEMPTS) || 3, 1),
+  baseDelayMs: 1000,
+  maxDelayMs: 8000,
+};
+
+/** Exponential backoff with full jitter, so guilds retrying at once don't hit YouTube in lockstep. */
+export function backoffDelay(policy: RetryPolicy, retry: number): number {
+  const ceiling = Math.min(policy.maxDelayMs, policy.baseDelayMs * 2 ** (retry - 1));
+  return Math.round(ceiling / 2 + Math.random() * (ceiling / 2));
+}
+
+/**
+ * Runs `task` until it succeeds, retrying transient failures (throttling,
+ * timeouts, one-off extraction errors) with backoff. `task` gets the attempt
+ * number, starting at 1, so later attempts can try something different.
+ * Errors retrying can't fix, like an age-restricted video, are thrown straight away.
+ */
+export async function withRetry<T>(label: string, task: (attempt: number) => Promise<T>, policy = EXTRACTION_RETRY): Promise<T> {
+  for (let attempt = 1; ; attempt++) {
+    try {
+      return await task(attempt);
+    } catch (error) {
+      if (attempt >= policy.attempts || !isTransientError(error)) throw error;
+
+      const delay = backoffDelay(policy, attempt);
+      console.warn(
+        `[retry] ${label} failed (attempt ${attempt}/${policy.attempts}), retrying in ${delay}ms:`,
+        error instanceof Error ? error.message : error
+      );
+      await new Promise((resolve) => setTimeout(resolve, delay));
+    }
+  }
+}
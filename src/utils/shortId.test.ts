@@ -0,0 +1,31 @@
+import { describe, it } from 'node:test';
+import * as assert from 'node:assert/strict';
+import { fromShortId, isShortId, toShortId } from './shortId';
+
+describe('toShortId', () => {
+  it('makes four-letter IDs, then grows by a syllable', () => {
+    assert.equal(toShortId(0), 'baba');
+    assert.equal(toShortId(6399).length, 4);
+    assert.equal(toShortId(6400).length, 6);
+  });
+
+  it("doesn't give neighbours near-identical IDs", () => {
+    assert.notEqual(toShortId(1).slice(0, 2), toShortId(2).slice(0, 2));
+  });
+});
+
+describe('fromShortId', () => {
+  it('reads back the sequence number of any ID', () => {
+    for (const sequence of [0, 1, 2, 6399, 6400, 6401, 518399, 518400, 40_000_000]) {
+      const id = toShortId(sequence);
+      assert.ok(isShortId(id));
+      assert.equal(fromShortId(id), sequence);
+    }
+  });
+
+  it('rejects anything that isn\'t a short ID', () => {
+    assert.equal(fromShortId('hello'), undefined);
+    assert.equal(fromShortId('bab'), undefined);
+    assert.equal(fromShortId('3'), undefined);
+  });
+});
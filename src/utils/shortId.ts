@@ -0,0 +1,69 @@
+const CONSONANTS = 'bdfghjklmnprstvz';
+const VOWELS = 'aeiou';
+const SYLLABLES = CONSONANTS.length * VOWELS.length;
+// Multiplying by a number coprime to the ID space spreads consecutive IDs apart,
+// so two songs queued back to back don't get near-identical IDs
+const SCRAMBLE_FACTOR = 2357;
+
+/**
+ * Turns a sequence number into a short pronounceable ID like `kobe`. IDs are
+ * four letters for the first 6400 numbers and grow by a syllable after that,
+ * so they never repeat for the same counter.
+ */
+export function toShortId(sequence: number): string {
+  let space = SYLLABLES * SYLLABLES;
+  let offset = 0;
+  let syllableCount = 2;
+  while (sequence - offset >= space) {
+    offset += space;
+    space *= SYLLABLES;
+    syllableCount++;
+  }
+
+  let value = ((sequence - offset) * SCRAMBLE_FACTOR) % space;
+  let id = '';
+  for (let i = 0; i < syllableCount; i++) {
+    const syllable = value % SYLLABLES;
+    value = Math.floor(value / SYLLABLES);
+    id += CONSONANTS[Math.floor(syllable / VOWELS.length)] + VOWELS[syllable % VOWELS.length];
+  }
+  return id;
+}
+
+/** The sequence number `toShortId` turned into `id`, or `undefined` if it isn't a short ID. */
+export function fromShortId(id: string): number | undefined {
+  if (!isShortId(id)) return undefined;
+
+  const syllableCount = id.length / 2;
+  let space = SYLLABLES * SYLLABLES;
+  let offset = 0;
+  for (let count = 2; count < syllableCount; count++) {
+    offset += space;
+    space *= SYLLABLES;
+  }
+
+  let value = 0;
+  for (let i = syllableCount - 1; i >= 0; i--) {
+    value = value * SYLLABLES + CONSONANTS.indexOf(id[2 * i]) * VOWELS.length + VOWELS.indexOf(id[2 * i + 1]);
+  }
+  // BigInt keeps the product exact for long IDs
+  const sequence = (BigInt(value) * modularInverse(SCRAMBLE_FACTOR, space)) % BigInt(space);
+  return offset + Number(sequence);
+}
+
+/** The number that undoes multiplying by `factor`, modulo `modulus`. */
+function modularInverse(factor: number, modulus: number): bigint {
+  const m = BigInt(modulus);
+  let [remainder, nextRemainder] = [BigInt(factor), m];
+  let [coefficient, nextCoefficient] = [BigInt(1), BigInt(0)];
+  while (nextRemainder !== BigInt(0)) {
+    const quotient = remainder / nextRemainder;
+    [remainder, nextRemainder] = [nextRemainder, remainder - quotient * nextRemainder];
+    [coefficient, nextCoefficient] = [nextCoefficient, coefficient - quotient * nextCoefficient];
+  }
+  return ((coefficient % m) + m) % m;
+}
+
+export function isShortId(value: string): boolean {
+  return /^(?:[bdfghjklmnprstvz][aeiou]){2,}$/.test(value);
+}
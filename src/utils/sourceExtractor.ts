@@ -0,0 +1,56 @@
+import { Song, TrackSource } from '../types/index';
+import { JobLane } from './jobScheduler';
+import { AudioInput, AudioInputOptions } from './audioInput';
+import { YouTubeUtil } from './youtubeUtil';
+import { ytDlpExtractor } from './ytDlpExtractor';
+import { radioExtractor } from './radioExtractor';
+
+/**
+ * A place songs come from. Each source says which links it handles, turns
+ * them into songs, searches by name, and opens a song's audio for ffmpeg, so
+ * a new backend (Spotify, SoundCloud, local files) is one implementation
+ * added to SOURCE_EXTRACTORS, plus a kind in TrackSource for its songs.
+ */
+export interface SourceExtractor {
+  name: string;
+  /** The kinds of song it looks up and streams. */
+  sources: TrackSource['kind'][];
+  /** Whether this source handles the link; checked in SOURCE_EXTRACTORS order, first match wins. */
+  matchesUrl(url: string): boolean;
+  /** The song behind a link this source matched. */
+  extract(url: string, userId: string, guildId: string, lane?: JobLane): Promise<Song>;
+  /** The best match for a search query, or null when there is none. */
+  search(query: string, userId: string, guildId: string, lane?: JobLane): Promise<Song | null>;
+  /** Opens the song's audio for playback. */
+  stream(song: Song, guildId: string, options?: AudioInputOptions): Promise<AudioInput>;
+}
+
+// In order of preference when more than one matches a link
+export const SOURCE_EXTRACTORS: SourceExtractor[] = [ytDlpExtractor, radioExtractor];
+
+// Where plain-text searches go
+const SEARCH_EXTRACTOR: SourceExtractor = ytDlpExtractor;
+
+/** The source that handles `url`, or undefined when no source can play it. */
+export function findExtractor(url: string): SourceExtractor | undefined {
+  return SOURCE_EXTRACTORS.find((extractor) => extractor.matchesUrl(url));
+}
+
+/** The source a queued song streams from. */
+export function extractorForSong(song: Song): SourceExtractor {
+  return SOURCE_EXTRACTORS.find((extractor) => extractor.sources.includes(song.source.kind)) ?? ytDlpExtractor;
+}
+
+/** Resolves a supported URL or a search query, returning null for unsupported URLs or empty searches. */
+export async function resolveSong(
+  input: string,
+  userId: string,
+  guildId: string,
+  lane: JobLane = 'interactive'
+): Promise<Song | null> {
+  if (YouTubeUtil.isUrl(input)) {
+    const extractor = findExtractor(input);
+    return extractor ? extractor.extract(input, userId, guildId, lane) : null;
+  }
+  return SEARCH_EXTRACTOR.search(input, userId, guildId, lane);
+}
@@ -0,0 +1,39 @@
+const REQUEST_TIMEOUT_MS = 10_000;
+
+/** Whether the URL is a Spotify track page, e.g. `https://open.spotify.com/track/<id>`. */
+export function isSpotifyTrackUrl(url: string): boolean {
+  try {
+    const parsed = new URL(url);
+    return parsed.hostname === 'open.spotify.com' && /^\/(intl-[\w-]+\/)?track\/\w+/.test(parsed.pathname);
+  } catch {
+    return false;
+  }
+}
+
+function readMeta(html: string, name: string): string | undefined {
+  const match = new RegExp(`<meta[^>]+(?:property|name)="${name}"[^>]+content="([^"]*)"`, 'i').exec(html);
+  return match?.[1]
+    ?.replace(/&amp;/g, '&')
+    .replace(/&quot;/g, '"')
+    .replace(/&#x27;|&#39;/g, "'")
+    .trim();
+}
+
+/**
+ * "Artist Title" for a Spotify track, to search YouTube with, since Spotify's
+ * audio can't be streamed. Read from the public track page, so no Spotify
+ * credentials are needed. Undefined when the page has no title.
+ */
+export async function spotifyTrackToQuery(url: string): Promise<string | undefined> {
+  const response = await fetch(url, {
+    headers: { 'User-Agent': 'Mozilla/5.0 (compatible; GoonBot)' },
+    signal: AbortSignal.timeout(REQUEST_TIMEOUT_MS),
+  });
+  if (!response.ok) throw new Error(`Spotify answered HTTP ${response.status}`);
+
+  const html = await response.text();
+  const title = readMeta(html, 'og:title');
+  if (!title) return undefined;
+  const artist = readMeta(html, 'music:musician_description');
+  return artist ? `${artist} ${title}` : title;
+}
@@ -0,0 +1,173 @@
+import * as fs from 'fs';
+import * as path from 'path';
+import type Database from 'better-sqlite3';
+import type { StorageBackend, StoredDocument } from './storage';
+
+// A write waits this long for another process holding the lock before failing
+const BUSY_TIMEOUT_MS = 5000;
+// The entry key for documents that aren't keyed by guild or user, such as lists
+const WHOLE_DOCUMENT_KEY = '';
+
+interface DocumentRow {
+  version: number;
+  saved_at: string;
+  revision: number;
+  keyed: number;
+}
+
+interface EntryRow {
+  key: string;
+  value: string;
+}
+
+function isKeyed(data: unknown): data is Record<string, unknown> {
+  return data !== null && typeof data === 'object' && !Array.isArray(data);
+}
+
+/**
+ * Documents in a SQLite database that several bot processes can share.
+ * Documents keyed by guild or user are stored as one row per key, and a write
+ * only touches the rows this process changed since it last read them, so
+ * processes updating different guilds never undo each other's changes.
+ * Each write bumps the document's revision, which is how other processes
+ * notice it.
+ */
+export class SqliteBackend implements StorageBackend {
+  private readonly db: Database.Database;
+  // Each document's rows as this process last read or wrote them, by document name then key
+  private readonly knownEntries: Map<string, Map<string, string>> = new Map();
+  // Each document's revision as of this process's last read or write
+  private readonly knownRevisions: Map<string, number> = new Map();
+  private dataVersion: number;
+
+  constructor(filePath: string) {
+    // Loaded here rather than imported, so the native module is only needed by setups that use SQLite
+    const BetterSqlite3 = require('better-sqlite3') as typeof Database;
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    this.db = new BetterSqlite3(filePath);
+    // WAL lets the other processes keep reading while one writes
+    this.db.pragma('journal_mode = WAL');
+    this.db.pragma(`busy_timeout = ${BUSY_TIMEOUT_MS}`);
+    this.db.exec(`
+      CREATE TABLE IF NOT EXISTS documents (
+        name TEXT PRIMARY KEY,
+        version INTEGER NOT NULL,
+        saved_at TEXT NOT NULL,
+        revision INTEGER NOT NULL,
+        keyed INTEGER NOT NULL
+      );
+      CREATE TABLE IF NOT EXISTS entries (
+        name TEXT NOT NULL,
+        key TEXT NOT NULL,
+        value TEXT NOT NULL,
+        PRIMARY KEY (name, key)
+      );
+      CREATE TABLE IF NOT EXISTS backups (
+        name TEXT NOT NULL,
+        version INTEGER NOT NULL,
+        saved_at TEXT NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (name, version)
+      );
+    `);
+    this.dataVersion = this.readDataVersion();
+  }
+
+  read(name: string): StoredDocument | undefined {
+    return this.db.transaction(() => {
+      const row = this.db
+        .prepare('SELECT version, saved_at, revision, keyed FROM documents WHERE name = ?')
+        .get(name) as DocumentRow | undefined;
+      if (!row) return undefined;
+
+      const rows = this.db.prepare('SELECT key, value FROM entries WHERE name = ?').all(name) as EntryRow[];
+      const entries = new Map(rows.map(({ key, value }): [string, string] => [key, value]));
+      this.knownEntries.set(name, entries);
+      this.knownRevisions.set(name, row.revision);
+
+      const data = row.keyed
+        ? Object.fromEntries([...entries].map(([key, value]) => [key, JSON.parse(value)]))
+        : JSON.parse(entries.get(WHOLE_DOCUMENT_KEY) ?? 'null');
+      return { version: row.version, savedAt: row.saved_at, data };
+    })();
+  }
+
+  write(name: string, document: StoredDocument): void {
+    const keyed = isKeyed(document.data);
+    const entries = new Map(
+      keyed
+        ? Object.entries(document.data as Record<string, unknown>).map(([key, value]): [string, string] => [
+            key,
+            JSON.stringify(value),
+          ])
+        : [[WHOLE_DOCUMENT_KEY, JSON.stringify(document.data)]]
+    );
+    const known = keyed ? (this.knownEntries.get(name) ?? new Map<string, string>()) : new Map<string, string>();
+
+    this.db.transaction(() => {
+      const current = this.db.prepare('SELECT revision FROM documents WHERE name = ?').get(name) as
+        | { revision: number }
+        | undefined;
+      if (!keyed) this.db.prepare('DELETE FROM entries WHERE name = ?').run(name);
+
+      const upsert = this.db.prepare(
+        'INSERT INTO entries (name, key, value) VALUES (?, ?, ?) ON CONFLICT (name, key) DO UPDATE SET value = excluded.value'
+      );
+      for (const [key, value] of entries) {
+        if (known.get(key) !== value) upsert.run(name, key, value);
+      }
+      const remove = this.db.prepare('DELETE FROM entries WHERE name = ? AND key = ?');
+      for (const key of known.keys()) {
+        if (!entries.has(key)) remove.run(name, key);
+      }
+
+      const revision = (current?.revision ?? 0) + 1;
+      this.db
+        .prepare(
+          `INSERT INTO documents (name, version, saved_at, revision, keyed) VALUES (?, ?, ?, ?, ?)
+           ON CONFLICT (name) DO UPDATE SET version = excluded.version, saved_at = excluded.saved_at,
+             revision = excluded.revision, keyed = excluded.keyed`
+        )
+        .run(name, document.version, document.savedAt, revision, keyed ? 1 : 0);
+
+      // If someone else wrote since this process last read, their rows still need loading
+      if (current === undefined || current.revision === this.knownRevisions.get(name)) {
+        this.knownRevisions.set(name, revision);
+      } else {
+        this.knownRevisions.delete(name);
+      }
+    })();
+    this.knownEntries.set(name, entries);
+  }
+
+  remove(name: string): void {
+    this.db.transaction(() => {
+      this.db.prepare('DELETE FROM entries WHERE name = ?').run(name);
+      this.db.prepare('DELETE FROM documents WHERE name = ?').run(name);
+    })();
+    this.knownEntries.delete(name);
+    this.knownRevisions.delete(name);
+  }
+
+  backup(name: string, version: number): void {
+    const stored = this.read(name);
+    if (!stored) return;
+    this.db
+      .prepare('INSERT OR REPLACE INTO backups (name, version, saved_at, data) VALUES (?, ?, ?, ?)')
+      .run(name, version, new Date().toISOString(), JSON.stringify(stored.data));
+  }
+
+  takeExternalChanges(): string[] {
+    // data_version only moves when another connection commits, so this is cheap while nothing changes
+    const dataVersion = this.readDataVersion();
+    if (dataVersion === this.dataVersion) return [];
+    this.dataVersion = dataVersion;
+
+    const rows = this.db.prepare('SELECT name, revision FROM documents').all() as { name: string; revision: number }[];
+    return rows.filter(({ name, revision }) => this.knownRevisions.get(name) !== revision).map(({ name }) => name);
+  }
+
+  private readDataVersion(): number {
+    return this.db.pragma('data_version', { simple: true }) as number;
+  }
+}
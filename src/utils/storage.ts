@@ -0,0 +1,320 @@
+import * as crypto from 'crypto';
+import * as fs from 'fs';
+import * as path from 'path';
+import { Song } from '../types/index';
+import { parseChapters } from './chapters';
+import { SqliteBackend } from './sqliteBackend';
+import { parseTrackSource, trackSourceFromUrl } from './trackSource';
+
+const DEFAULT_DATA_DIR = path.join(process.cwd(), 'data');
+// Changes to a document within this window are written together
+const SAVE_BATCH_MS = 1000;
+// How often documents shared with other processes are checked for their changes
+const EXTERNAL_CHANGE_POLL_MS = 1000;
+
+/**
+ * Upgrades a document from version `n` to `n + 1`. Migrations receive the raw
+ * parsed JSON, so they must not assume the current types.
+ */
+export type Migration = (data: any) => any;
+
+export interface DocumentSchema<T> {
+  /** File name inside the data directory, without extension; the document's key in other backends. */
+  name: string;
+  /** Current on-disk version. Bump it whenever the shape of `T` changes. */
+  version: number;
+  /** `migrations[n]` upgrades a version `n` document to version `n + 1`. */
+  migrations: Record<number, Migration>;
+  defaultValue: () => T;
+}
+
+export interface StoredDocument {
+  version: number;
+  savedAt: string;
+  data: unknown;
+}
+
+/**
+ * Where documents are kept. Storage handles versions, migrations and batching
+ * on top; a backend only reads and writes whole documents by name.
+ */
+export interface StorageBackend {
+  read(name: string): StoredDocument | undefined;
+  write(name: string, document: StoredDocument): void;
+  remove(name: string): void;
+  /** Keeps a copy of the document as it is now, before a migration rewrites it. */
+  backup(name: string, version: number): void;
+  /**
+   * Names of documents other processes changed since the last call. Only
+   * backends shared between processes implement it.
+   */
+  takeExternalChanges?(): string[];
+}
+
+/** One JSON file per document in the data directory. */
+export class FileBackend implements StorageBackend {
+  constructor(private readonly dataDir: string) {}
+
+  read(name: string): StoredDocument | undefined {
+    const filePath = this.getFilePath(name);
+    if (!fs.existsSync(filePath)) return undefined;
+
+    const parsed = JSON.parse(fs.readFileSync(filePath, 'utf8'));
+    // Files written before versioning existed are plain data and count as version 0
+    const isEnvelope =
+      parsed !== null && typeof parsed === 'object' && typeof parsed.version === 'number' && 'data' in parsed;
+    return isEnvelope ? parsed : { version: 0, savedAt: '', data: parsed };
+  }
+
+  write(name: string, document: StoredDocument): void {
+    const filePath = this.getFilePath(name);
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+
+    // Write to a temp file first so a crash mid-write never leaves a truncated document
+    const tempPath = `${filePath}.tmp`;
+    fs.writeFileSync(tempPath, JSON.stringify(document, null, 2));
+    fs.renameSync(tempPath, filePath);
+  }
+
+  remove(name: string): void {
+    fs.rmSync(this.getFilePath(name), { force: true });
+  }
+
+  backup(name: string, version: number): void {
+    const filePath = this.getFilePath(name);
+    fs.copyFileSync(filePath, `${filePath}.v${version}.bak`);
+  }
+
+  private getFilePath(name: string): string {
+    return path.join(this.dataDir, `${name}.json`);
+  }
+}
+
+/** The backend picked by `STORAGE_BACKEND`: JSON files by default, or a SQLite database processes can share. */
+function createBackend(dataDir: string): StorageBackend {
+  if (process.env.STORAGE_BACKEND !== 'sqlite') return new FileBackend(dataDir);
+  return new SqliteBackend(process.env.SQLITE_PATH || path.join(dataDir, 'goonbot.db'));
+}
+
+function hashData(data: unknown): string {
+  return crypto.createHash('sha1').update(JSON.stringify(data)).digest('hex');
+}
+
+export class Storage {
+  private readonly dataDir: string;
+  private readonly backend: StorageBackend;
+  // Writes waiting for their batch window to close, by document name
+  private readonly pendingSaves: Map<string, { timer: NodeJS.Timeout; write: () => void }> = new Map();
+  // Hash of what each document holds, so saving identical data is a no-op
+  private readonly writtenHashes: Map<string, string> = new Map();
+  // Listeners for documents other processes change, by document name
+  private readonly watchers: Map<string, { schema: DocumentSchema<any>; listener: (data: any) => void }> = new Map();
+  private watchTimer?: NodeJS.Timeout;
+
+  constructor(dataDir: string = process.env.DATA_DIR || DEFAULT_DATA_DIR, backend?: StorageBackend) {
+    this.dataDir = dataDir;
+    this.backend = backend ?? createBackend(dataDir);
+  }
+
+  getDataDir(): string {
+    return this.dataDir;
+  }
+
+  /**
+   * Storage over plain files in the same data directory, for data only this
+   * process uses, like spilled queues, whatever backend shared documents use.
+   */
+  scratch(): Storage {
+    return this.backend instanceof FileBackend ? this : new Storage(this.dataDir, new FileBackend(this.dataDir));
+  }
+
+  load<T>(schema: DocumentSchema<T>): T {
+    const stored = this.backend.read(schema.name) ?? this.importFromFile(schema.name);
+    if (!stored) {
+      return schema.defaultValue();
+    }
+
+    const storedVersion = stored.version;
+    let data = stored.data;
+
+    if (storedVersion > schema.version) {
+      throw new Error(
+        `${schema.name} is version ${storedVersion}, but this build only understands up to version ${schema.version}`
+      );
+    }
+
+    if (storedVersion === schema.version) {
+      this.writtenHashes.set(schema.name, hashData(data));
+      return data as T;
+    }
+
+    for (let version = storedVersion; version < schema.version; version++) {
+      const migrate = schema.migrations[version];
+      if (!migrate) {
+        throw new Error(`No migration registered for ${schema.name} from version ${version} to ${version + 1}`);
+      }
+      data = migrate(data);
+    }
+
+    // Keep the original around in case a migration turns out to be wrong
+    this.backend.backup(schema.name, storedVersion);
+    this.save(schema, data as T);
+    console.log(`[STORAGE] Migrated ${schema.name} from version ${storedVersion} to ${schema.version}`);
+
+    return data as T;
+  }
+
+  /** Writes `data` now, unless the document already holds exactly this data. */
+  save<T>(schema: DocumentSchema<T>, data: T): void {
+    const hash = hashData(data);
+    if (this.writtenHashes.get(schema.name) === hash) return;
+
+    this.backend.write(schema.name, {
+      version: schema.version,
+      savedAt: new Date().toISOString(),
+      data,
+    });
+    this.writtenHashes.set(schema.name, hash);
+  }
+
+  /**
+   * Saves the document after a short delay, so bursts of changes (a setting
+   * touched for every guild at startup, history on every skip) become one
+   * write. `getData` is called when the write happens, so it sees the latest
+   * state. Call `flush` before exiting.
+   */
+  scheduleSave<T>(schema: DocumentSchema<T>, getData: () => T): void {
+    const write = () => {
+      try {
+        this.save(schema, getData());
+      } catch (error) {
+        console.error(`[STORAGE] Failed to save ${schema.name}:`, error);
+      }
+    };
+
+    const pending = this.pendingSaves.get(schema.name);
+    if (pending) {
+      pending.write = write;
+      return;
+    }
+
+    if (this.pendingSaves.size === 0) {
+      // Last chance for batched writes when the process exits normally
+      process.once('exit', this.flushOnExit);
+    }
+    const timer = setTimeout(() => this.flushDocument(schema.name), SAVE_BATCH_MS);
+    this.pendingSaves.set(schema.name, { timer, write });
+  }
+
+  /** Writes every batched save immediately. */
+  flush(): void {
+    for (const name of [...this.pendingSaves.keys()]) {
+      this.flushDocument(name);
+    }
+  }
+
+  remove<T>(schema: DocumentSchema<T>): void {
+    const pending = this.pendingSaves.get(schema.name);
+    if (pending) {
+      clearTimeout(pending.timer);
+      this.pendingSaves.delete(schema.name);
+    }
+    this.writtenHashes.delete(schema.name);
+    this.backend.remove(schema.name);
+  }
+
+  /**
+   * Calls `listener` with the document whenever another process sharing the
+   * backend changes it, after writing this process's own pending changes so
+   * the two are merged. Does nothing with backends only one process uses.
+   */
+  watch<T>(schema: DocumentSchema<T>, listener: (data: T) => void): void {
+    if (!this.backend.takeExternalChanges) return;
+    this.watchers.set(schema.name, { schema, listener });
+    this.watchTimer ??= setInterval(() => this.checkExternalChanges(), EXTERNAL_CHANGE_POLL_MS);
+    this.watchTimer.unref();
+  }
+
+  /** Moves a document over from its JSON file the first time another backend is used. */
+  private importFromFile(name: string): StoredDocument | undefined {
+    if (this.backend instanceof FileBackend) return undefined;
+    const stored = new FileBackend(this.dataDir).read(name);
+    if (!stored) return undefined;
+
+    this.backend.write(name, stored);
+    console.log(`[STORAGE] Imported ${name}.json; the file is no longer used`);
+    return stored;
+  }
+
+  private checkExternalChanges(): void {
+    for (const name of this.backend.takeExternalChanges?.() ?? []) {
+      const watcher = this.watchers.get(name);
+      if (!watcher) continue;
+
+      try {
+        this.flushDocument(name);
+        const data = this.load(watcher.schema);
+        watcher.listener(data);
+      } catch (error) {
+        console.error(`[STORAGE] Failed to reload ${name} after another process changed it:`, error);
+      }
+    }
+  }
+
+  private readonly flushOnExit = (): void => this.flush();
+
+  private flushDocument(name: string): void {
+    const pending = this.pendingSaves.get(name);
+    if (!pending) return;
+
+    clearTimeout(pending.timer);
+    this.pendingSaves.delete(name);
+    if (this.pendingSaves.size === 0) {
+      process.removeListener('exit', this.flushOnExit);
+    }
+    pending.write();
+  }
+}
+
+/**
+ * Rebuilds a `Song` from persisted JSON, filling defaults for optional fields and
+ * dropping entries that are missing the fields playback depends on.
+ */
+export function reviveSong(raw: any): Song | null {
+  if (!raw || typeof raw !== 'object' || typeof raw.url !== 'string' || raw.url.length === 0) {
+    return null;
+  }
+
+  const id = String(raw.id ?? 'unknown');
+  return {
+    id,
+    title: String(raw.title ?? 'Unknown Title'),
+    url: raw.url,
+    // Songs saved before they carried a source; radio stations were flagged `isRadio`
+    source:
+      parseTrackSource(raw.source) ?? (raw.isRadio === true ? { kind: 'radio' } : trackSourceFromUrl(raw.url, id)),
+    duration: Number(raw.duration) || 0,
+    thumbnail: typeof raw.thumbnail === 'string' ? raw.thumbnail : undefined,
+    addedBy: String(raw.addedBy ?? 'unknown'),
+    needsMetadata: raw.needsMetadata === true ? true : undefined,
+    queueId: typeof raw.queueId === 'string' ? raw.queueId : undefined,
+    channelId: typeof raw.channelId === 'string' ? raw.channelId : undefined,
+    channel: typeof raw.channel === 'string' ? raw.channel : undefined,
+    artist: typeof raw.artist === 'string' ? raw.artist : undefined,
+    track: typeof raw.track === 'string' ? raw.track : undefined,
+    album: typeof raw.album === 'string' ? raw.album : undefined,
+    chapters: parseChapters(raw.chapters),
+    clip:
+      raw.clip && Number.isFinite(raw.clip.start) && Number.isFinite(raw.clip.end)
+        ? { start: Number(raw.clip.start), end: Number(raw.clip.end) }
+        : undefined,
+    isLive: raw.isLive === true ? true : undefined,
+  };
+}
+
+export function reviveSongs(raw: unknown): Song[] {
+  if (!Array.isArray(raw)) {
+    return [];
+  }
+  return raw.map(reviveSong).filter((song): song is Song => song !== null);
+}
@@ -0,0 +1,131 @@
+import { TrackSource } from '../types/index';
+import { backendHealth } from './backendHealth';
+import { extractionScheduler } from './jobScheduler';
+import { execYtDlp } from './ytDlp';
+import { requiresAccount } from './errors';
+import { withRetry } from './retry';
+import { VideoMissError, YOUTUBE_FRONTENDS, YouTubeFrontend, extractVideoId, pickAudioStream } from './frontendApis';
+
+export interface ResolvedStream {
+  url: string;
+  /** Source audio bitrate in kbit/s, when the backend reports it. */
+  bitrateKbps?: number;
+}
+
+export interface StreamOptions {
+  /** Prefer low-bitrate formats to save bandwidth on metered hosts. */
+  dataSaver?: boolean;
+  /** The song is a live stream, which YouTube only serves as HLS with video. */
+  live?: boolean;
+  /** Where the song comes from; backends that can't serve it are skipped. */
+  source?: TrackSource;
+}
+
+/** A way of turning a video page URL into a direct audio stream URL. */
+export interface StreamBackend {
+  name: string;
+  /** Whether it can resolve live streams. */
+  supportsLive: boolean;
+  /** The kinds of song it can resolve. */
+  sources: TrackSource['kind'][];
+  resolve(url: string, guildId: string, options?: StreamOptions): Promise<ResolvedStream>;
+}
+
+// Falls back to the smallest audio-only format, then to anything with audio
+const DATA_SAVER_FORMAT = 'bestaudio[abr<=64]/worstaudio/bestaudio';
+// YouTube lives have no audio-only formats (Twitch's have); the smallest video carries the same audio
+const LIVE_FORMAT = 'bestaudio/worst';
+
+/**
+ * The yt-dlp `-f` selector for the given options. Retries also accept formats
+ * with video, since an audio-only format is sometimes what YouTube won't serve.
+ */
+export function audioFormatSelector(options: StreamOptions = {}, attempt = 1): string {
+  if (options.live) return LIVE_FORMAT;
+  const selector = options.dataSaver ? DATA_SAVER_FORMAT : 'bestaudio';
+  return attempt > 1 ? `${selector}/best` : selector;
+}
+
+function parseYtDlpOutput(stdout: string): ResolvedStream {
+  const [bitrate, url] = stdout
+    .split('\n')
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0);
+
+  if (!url) {
+    throw new Error('yt-dlp did not return a direct stream URL');
+  }
+  const bitrateKbps = Number(bitrate);
+  return { url, bitrateKbps: Number.isFinite(bitrateKbps) && bitrateKbps > 0 ? bitrateKbps : undefined };
+}
+
+function ytDlpBackend(name: string, extraArgs: string[]): StreamBackend {
+  return {
+    name,
+    supportsLive: true,
+    sources: ['youtube', 'twitch'],
+    async resolve(url: string, guildId: string, options: StreamOptions = {}): Promise<ResolvedStream> {
+      const { stdout } = await withRetry(`${name} stream for ${url}`, (attempt) =>
+        extractionScheduler.schedule(guildId, () =>
+          execYtDlp(
+            ['-f', audioFormatSelector(options, attempt), '--print', 'abr', '--print', 'url', '--no-playlist', ...extraArgs, url],
+            { guildId }
+          )
+        )
+      );
+      return parseYtDlpOutput(stdout);
+    },
+  };
+}
+
+function frontendBackend(frontend: YouTubeFrontend): StreamBackend {
+  return {
+    name: frontend.name,
+    // Piped and Invidious list audio-only streams, which lives don't have
+    supportsLive: false,
+    sources: ['youtube'],
+    async resolve(url: string, _guildId: string, options: StreamOptions = {}): Promise<ResolvedStream> {
+      const videoId = options.source?.kind === 'youtube' ? options.source.videoId : extractVideoId(url);
+      if (!videoId) throw new Error(`${frontend.name} only handles YouTube video URLs`);
+
+      const stream = pickAudioStream((await frontend.fetchVideo(videoId)).audioStreams, options.dataSaver ?? false);
+      if (!stream) throw new Error(`${frontend.name} returned no audio streams`);
+      return { url: stream.url, bitrateKbps: stream.bitrateKbps };
+    },
+  };
+}
+
+// In order of preference when they're equally healthy
+export const STREAM_BACKENDS: StreamBackend[] = [
+  ytDlpBackend('yt-dlp', []),
+  // The Android client often keeps working when web signature extraction breaks
+  ytDlpBackend('yt-dlp (android)', ['--extractor-args', 'youtube:player_client=android']),
+  // Piped/Invidious instances, when configured, for when YouTube blocks or rate-limits yt-dlp
+  ...YOUTUBE_FRONTENDS.map(frontendBackend),
+];
+
+/** Tries each backend, healthiest first, recording every outcome. */
+export async function resolveStream(url: string, guildId: string, options: StreamOptions = {}): Promise<ResolvedStream> {
+  let lastError: unknown;
+
+  const { live, source } = options;
+  const backends = STREAM_BACKENDS.filter(
+    (backend) => (!live || backend.supportsLive) && (!source || backend.sources.includes(source.kind))
+  );
+  for (const backend of backendHealth.rank(backends)) {
+    try {
+      const stream = await backend.resolve(url, guildId, options);
+      backendHealth.recordSuccess(backend.name);
+      return stream;
+    } catch (error) {
+      // Every backend would hit the same wall; it's not a sign of poor health
+      if (requiresAccount(error)) throw error;
+      // A frontend that answered but lacks this one video is still healthy
+      if (!(error instanceof VideoMissError)) backendHealth.recordFailure(backend.name, error);
+      console.error(`[stream] ${backend.name} failed for ${url}:`, error instanceof Error ? error.message : error);
+      lastError = error;
+    }
+  }
+
+  throw lastError ?? new Error('No stream backends are configured');
+}
@@ -0,0 +1,105 @@
+import { QueueManager } from './queueManager';
+import { formatTrack } from './titleParser';
+
+// Discord rejects autocomplete choices whose name or value is longer than this
+const MAX_CHOICE_LENGTH = 100;
+const YOUTUBE_SUGGEST_URL = 'https://suggestqueries-clients6.youtube.com/complete/search';
+const YOUTUBE_SUGGEST_TIMEOUT_MS = 1500;
+
+export interface Suggestion {
+  /** Text shown to the user. */
+  name: string;
+  /** Text submitted to `/play` when the suggestion is picked. */
+  value: string;
+}
+
+export interface SuggestionProvider {
+  readonly name: string;
+  suggest(guildId: string, partial: string, limit: number): Promise<Suggestion[]>;
+}
+
+function truncate(text: string): string {
+  return text.length > MAX_CHOICE_LENGTH ? `${text.slice(0, MAX_CHOICE_LENGTH - 1)}…` : text;
+}
+
+/** Completes partial queries using YouTube's public search suggestion endpoint. */
+export class YouTubeSuggestionProvider implements SuggestionProvider {
+  readonly name = 'youtube';
+
+  async suggest(_guildId: string, partial: string, limit: number): Promise<Suggestion[]> {
+    const query = partial.trim();
+    if (!query) return [];
+
+    const url = new URL(YOUTUBE_SUGGEST_URL);
+    url.searchParams.set('client', 'firefox');
+    url.searchParams.set('ds', 'yt');
+    url.searchParams.set('q', query);
+
+    try {
+      const response = await fetch(url, { signal: AbortSignal.timeout(YOUTUBE_SUGGEST_TIMEOUT_MS) });
+      if (!response.ok) return [];
+
+      // Response shape: [query, [suggestion, ...], ...]
+      const body = (await response.json()) as unknown[];
+      const suggestions = Array.isArray(body[1]) ? (body[1] as unknown[]) : [];
+
+      return suggestions
+        .filter((suggestion): suggestion is string => typeof suggestion === 'string' && suggestion.length > 0)
+        .slice(0, limit)
+        .map((suggestion) => ({ name: truncate(suggestion), value: truncate(suggestion) }));
+    } catch (error) {
+      console.error('Error fetching YouTube suggestions:', error);
+      return [];
+    }
+  }
+}
+
+/** Suggests songs this guild has played before, most recent first. Never leaves the process. */
+export class HistorySuggestionProvider implements SuggestionProvider {
+  readonly name = 'history';
+
+  constructor(private readonly queueManager: QueueManager) {}
+
+  async suggest(guildId: string, partial: string, limit: number): Promise<Suggestion[]> {
+    const needle = partial.trim().toLowerCase();
+    const seenUrls = new Set<string>();
+    const suggestions: Suggestion[] = [];
+    const history = this.queueManager.getHistory(guildId);
+
+    for (let i = history.length - 1; i >= 0 && suggestions.length < limit; i--) {
+      const song = history[i];
+      if (seenUrls.has(song.url) || song.url.length > MAX_CHOICE_LENGTH) continue;
+      const name = formatTrack(song);
+      // The artist may only be known from the channel name, so match the cleaned name too
+      if (needle && !song.title.toLowerCase().includes(needle) && !name.toLowerCase().includes(needle)) continue;
+
+      seenUrls.add(song.url);
+      suggestions.push({ name: truncate(name), value: song.url });
+    }
+
+    return suggestions;
+  }
+}
+
+export class NoSuggestionProvider implements SuggestionProvider {
+  readonly name = 'none';
+
+  async suggest(): Promise<Suggestion[]> {
+    return [];
+  }
+}
+
+/** Picks the provider named by `SUGGESTION_PROVIDER` (`youtube`, `history` or `none`). */
+export function createSuggestionProvider(name: string | undefined, queueManager: QueueManager): SuggestionProvider {
+  switch ((name || 'youtube').toLowerCase()) {
+    case 'youtube':
+      return new YouTubeSuggestionProvider();
+    case 'history':
+      return new HistorySuggestionProvider(queueManager);
+    case 'none':
+      return new NoSuggestionProvider();
+    default:
+      console.warn(`Unknown SUGGESTION_PROVIDER "${name}", falling back to "none"`);
+      return new NoSuggestionProvider();
+  }
+}
@@ -0,0 +1,124 @@
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+
+export const DOWNLOAD_DIR = path.join(os.tmpdir(), 'goonbot-audio');
+
+const DEFAULT_QUOTA_MB = 1024;
+
+export interface TempFile {
+  path: string;
+  /** Deletes the file and stops counting it against the quota. Safe to call more than once. */
+  release(): void;
+}
+
+export interface TempFileUsage {
+  files: number;
+  bytes: number;
+  quotaBytes: number;
+}
+
+function isProcessRunning(pid: number): boolean {
+  try {
+    process.kill(pid, 0);
+    return true;
+  } catch (error) {
+    // EPERM means it exists but belongs to someone else
+    return (error as NodeJS.ErrnoException).code === 'EPERM';
+  }
+}
+
+/**
+ * Owns the downloaded audio in `DOWNLOAD_DIR`: every file is tracked from
+ * creation until the song using it ends, and together they must fit in the
+ * disk quota. Files are named `<pid>-...` so a restart can tell its own files
+ * from ones left behind by a crashed process.
+ */
+export class TempFileManager {
+  // Size of each live file; 0 until its download finishes
+  private readonly files: Map<string, number> = new Map();
+
+  constructor(
+    private readonly dir: string,
+    private readonly quotaBytes: number
+  ) {}
+
+  /** Reserves a path for a new file. */
+  create(extension: string): TempFile {
+    fs.mkdirSync(this.dir, { recursive: true });
+    const filePath = path.join(this.dir, `${process.pid}-${Date.now()}-${Math.random().toString(36).slice(2)}.${extension}`);
+    this.files.set(filePath, 0);
+    if (this.files.size === 1) {
+      process.once('exit', this.deleteAllOnExit);
+    }
+    return { path: filePath, release: () => this.release(filePath) };
+  }
+
+  /** Bytes still free under the quota, for capping the next download. */
+  getFreeBytes(): number {
+    return Math.max(0, this.quotaBytes - this.getUsage().bytes);
+  }
+
+  /**
+   * Records a finished file's size. Throws, deleting the file, when it doesn't
+   * exist or pushes the total over the quota.
+   */
+  commit(file: TempFile): number {
+    let size: number;
+    try {
+      size = fs.statSync(file.path).size;
+    } catch {
+      file.release();
+      throw new Error('Download did not fit in the remaining disk quota');
+    }
+
+    this.files.set(file.path, size);
+    if (this.getUsage().bytes > this.quotaBytes) {
+      file.release();
+      throw new Error('Download did not fit in the remaining disk quota');
+    }
+    return size;
+  }
+
+  getUsage(): TempFileUsage {
+    let bytes = 0;
+    for (const size of this.files.values()) bytes += size;
+    return { files: this.files.size, bytes, quotaBytes: this.quotaBytes };
+  }
+
+  /** Deletes files left behind by processes that are no longer running. Returns how many were removed. */
+  sweepOrphans(): number {
+    if (!fs.existsSync(this.dir)) return 0;
+
+    let removed = 0;
+    for (const name of fs.readdirSync(this.dir)) {
+      const pid = Number(name.split('-')[0]);
+      if (pid === process.pid || (Number.isInteger(pid) && pid > 0 && isProcessRunning(pid))) continue;
+      fs.rmSync(path.join(this.dir, name), { force: true });
+      removed++;
+    }
+    return removed;
+  }
+
+  private release(filePath: string): void {
+    if (!this.files.delete(filePath)) return;
+    if (this.files.size === 0) {
+      process.removeListener('exit', this.deleteAllOnExit);
+    }
+    fs.rm(filePath, { force: true }, () => undefined);
+  }
+
+  // Exit handlers can't wait for async deletes
+  private readonly deleteAllOnExit = (): void => {
+    for (const filePath of this.files.keys()) {
+      fs.rmSync(filePath, { force: true });
+    }
+    this.files.clear();
+  };
+}
+
+/** Downloads for `PLAYBACK_MODE=download`, capped by `DOWNLOAD_QUOTA_MB`. */
+export const tempFiles = new TempFileManager(
+  DOWNLOAD_DIR,
+  (Number(process.env.DOWNLOAD_QUOTA_MB) > 0 ? Number(process.env.DOWNLOAD_QUOTA_MB) : DEFAULT_QUOTA_MB) * 1024 * 1024
+);
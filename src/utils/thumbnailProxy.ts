@@ -0,0 +1,139 @@
+import { AttachmentBuilder } from 'discord.js';
+import { spawn } from 'child_process';
+import { TtlCache } from './ttlCache';
+
+const THUMBNAIL_FILE_NAME = 'thumbnail.jpg';
+const THUMBNAIL_WIDTH = 320;
+const MAX_SOURCE_BYTES = 5 * 1024 * 1024;
+const MAX_CACHED_THUMBNAILS = 100;
+const THUMBNAIL_CACHE_TTL_MS = 60 * 60 * 1000;
+const FETCH_TIMEOUT_MS = 5000;
+
+// Formats Discord reliably renders inline in embeds
+const EMBEDDABLE_EXTENSIONS = ['.jpg', '.jpeg', '.png', '.gif'];
+
+export interface DisplayThumbnail {
+  /** URL to put on the embed; `attachment://...` when `file` must be sent with the message. */
+  url: string;
+  file?: AttachmentBuilder;
+}
+
+// The resized JPEG, or `null` when the source can be linked as it is
+const cache = new TtlCache<Buffer | null>(THUMBNAIL_CACHE_TTL_MS, MAX_CACHED_THUMBNAILS);
+
+function toDisplayThumbnail(sourceUrl: string, jpeg: Buffer | null): DisplayThumbnail {
+  if (!jpeg) return { url: sourceUrl };
+  return { url: `attachment://${THUMBNAIL_FILE_NAME}`, file: new AttachmentBuilder(jpeg, { name: THUMBNAIL_FILE_NAME }) };
+}
+
+/** YouTube serves the same artwork as a small JPEG, which needs no re-hosting. */
+function toYouTubeJpeg(url: URL): string | undefined {
+  if (url.hostname !== 'i.ytimg.com') return undefined;
+  const match = url.pathname.match(/^\/vi(?:_webp)?\/([^/]+)\//);
+  return match ? `https://i.ytimg.com/vi/${match[1]}/hqdefault.jpg` : undefined;
+}
+
+/** Reads the whole body, or gives up with `undefined` as soon as it passes `MAX_SOURCE_BYTES`. */
+async function readLimited(response: Response): Promise<Buffer | undefined> {
+  if (!response.body) return Buffer.alloc(0);
+
+  const reader = response.body.getReader();
+  const chunks: Uint8Array[] = [];
+  let size = 0;
+  for (;;) {
+    const { done, value } = await reader.read();
+    if (done) break;
+    size += value.byteLength;
+    if (size > MAX_SOURCE_BYTES) {
+      await reader.cancel();
+      return undefined;
+    }
+    chunks.push(value);
+  }
+  return Buffer.concat(chunks);
+}
+
+async function transcodeToJpeg(image: Buffer): Promise<Buffer> {
+  return new Promise((resolve, reject) => {
+    const ffmpeg = spawn(
+      'ffmpeg',
+      ['-hide_banner', '-loglevel', 'error', '-i', 'pipe:0', '-vf', `scale=${THUMBNAIL_WIDTH}:-2`, '-frames:v', '1', '-f', 'mjpeg', 'pipe:1'],
+      { stdio: ['pipe', 'pipe', 'pipe'] }
+    );
+
+    const chunks: Buffer[] = [];
+    ffmpeg.stdout.on('data', (chunk: Buffer) => chunks.push(chunk));
+    ffmpeg.on('error', reject);
+    ffmpeg.on('close', (code) => {
+      if (code === 0 && chunks.length > 0) {
+        resolve(Buffer.concat(chunks));
+      } else {
+        reject(new Error(`ffmpeg exited with code ${code} while resizing thumbnail`));
+      }
+    });
+
+    ffmpeg.stdin.on('error', () => {
+      // ffmpeg may close stdin early on bad input; the close handler reports it
+    });
+    ffmpeg.stdin.end(image);
+  });
+}
+
+/**
+ * Returns a thumbnail Discord will actually render. WebP and oversized images
+ * are downloaded, resized to a JPEG and attached to the message instead of
+ * linked. Each source's outcome, including "link it directly", is cached in
+ * memory.
+ */
+export async function getDisplayThumbnail(sourceUrl: string): Promise<DisplayThumbnail> {
+  let parsed: URL;
+  try {
+    parsed = new URL(sourceUrl);
+  } catch {
+    return { url: sourceUrl };
+  }
+
+  const youtubeJpeg = toYouTubeJpeg(parsed);
+  if (youtubeJpeg) {
+    return { url: youtubeJpeg };
+  }
+
+  const cached = cache.get(sourceUrl);
+  if (cached !== undefined) {
+    return toDisplayThumbnail(sourceUrl, cached);
+  }
+
+  try {
+    const response = await fetch(parsed, { signal: AbortSignal.timeout(FETCH_TIMEOUT_MS) });
+    if (!response.ok) {
+      return { url: sourceUrl };
+    }
+
+    const contentType = response.headers.get('content-type') ?? '';
+    const contentLength = Number(response.headers.get('content-length') ?? 0);
+    const isEmbeddable =
+      EMBEDDABLE_EXTENSIONS.some((extension) => parsed.pathname.toLowerCase().endsWith(extension)) &&
+      !contentType.includes('webp');
+
+    // Small images in formats Discord understands can be linked directly, and
+    // anything too big to resize is linked rather than downloaded
+    if ((isEmbeddable && contentLength > 0) || contentLength > MAX_SOURCE_BYTES) {
+      await response.body?.cancel();
+      cache.set(sourceUrl, null);
+      return { url: sourceUrl };
+    }
+
+    const image = await readLimited(response);
+    if (!image) {
+      cache.set(sourceUrl, null);
+      return { url: sourceUrl };
+    }
+
+    const jpeg = await transcodeToJpeg(image);
+    cache.set(sourceUrl, jpeg);
+    return toDisplayThumbnail(sourceUrl, jpeg);
+  } catch (error) {
+    console.error(`Error proxying thumbnail ${sourceUrl}:`, error);
+    return { url: sourceUrl };
+  }
+}
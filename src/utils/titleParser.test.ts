@@ -0,0 +1,85 @@
+import { describe, it } from 'node:test';
+import * as assert from 'node:assert/strict';
+import { parseTrackTitle } from './titleParser';
+
+describe('parseTrackTitle', () => {
+  it('splits "Artist - Title"', () => {
+    assert.deepEqual(parseTrackTitle('Daft Punk - Get Lucky'), { artist: 'Daft Punk', title: 'Get Lucky' });
+  });
+
+  it('accepts en and em dashes and pipes as separators', () => {
+    assert.deepEqual(parseTrackTitle('Daft Punk – Get Lucky'), { artist: 'Daft Punk', title: 'Get Lucky' });
+    assert.deepEqual(parseTrackTitle('Daft Punk — Get Lucky'), { artist: 'Daft Punk', title: 'Get Lucky' });
+    assert.deepEqual(parseTrackTitle('Daft Punk | Get Lucky'), { artist: 'Daft Punk', title: 'Get Lucky' });
+  });
+
+  it('keeps anything after a second separator with the title', () => {
+    assert.deepEqual(parseTrackTitle('Muse - Uprising | Live at Rome'), { artist: 'Muse', title: 'Uprising | Live at Rome' });
+  });
+
+  it("doesn't split on hyphens inside words", () => {
+    assert.deepEqual(parseTrackTitle('Jay-Z Empire State of Mind'), { artist: undefined, title: 'Jay-Z Empire State of Mind' });
+  });
+
+  it('drops tags about the upload', () => {
+    assert.deepEqual(parseTrackTitle('Rick Astley - Never Gonna Give You Up (Official Video) [HD]'), {
+      artist: 'Rick Astley',
+      title: 'Never Gonna Give You Up',
+    });
+    assert.deepEqual(parseTrackTitle('Adele - Hello (Lyrics)'), { artist: 'Adele', title: 'Hello' });
+    assert.deepEqual(parseTrackTitle('Queen - Bohemian Rhapsody [Official Music Video] (4K)'), {
+      artist: 'Queen',
+      title: 'Bohemian Rhapsody',
+    });
+    assert.deepEqual(parseTrackTitle('Kendrick Lamar - HUMBLE. (Official Audio) 【Explicit】'), {
+      artist: 'Kendrick Lamar',
+      title: 'HUMBLE.',
+    });
+  });
+
+  it('keeps remix names and other bracketed parts of the title', () => {
+    assert.deepEqual(parseTrackTitle('Lorde - Royals (Remix) [Official Video]'), { artist: 'Lorde', title: 'Royals (Remix)' });
+  });
+
+  it('reads `Artist "Title"` uploads', () => {
+    assert.deepEqual(parseTrackTitle('Adele "Hello"'), { artist: 'Adele', title: 'Hello' });
+    assert.deepEqual(parseTrackTitle('Adele “Hello” (Official Video)'), { artist: 'Adele', title: 'Hello' });
+    assert.deepEqual(parseTrackTitle('Adele "Hello" live'), { artist: 'Adele', title: 'Hello live' });
+  });
+
+  it('keeps feature credits', () => {
+    assert.deepEqual(parseTrackTitle('Calvin Harris - This Is What You Came For (feat. Rihanna) (Official Video)'), {
+      artist: 'Calvin Harris',
+      title: 'This Is What You Came For (feat. Rihanna)',
+    });
+    assert.deepEqual(parseTrackTitle('Mark Ronson feat. Bruno Mars - Uptown Funk'), {
+      artist: 'Mark Ronson feat. Bruno Mars',
+      title: 'Uptown Funk',
+    });
+  });
+
+  it('falls back to the channel name, without "- Topic" or "VEVO"', () => {
+    assert.deepEqual(parseTrackTitle('Bohemian Rhapsody', 'Queen - Topic'), { artist: 'Queen', title: 'Bohemian Rhapsody' });
+    assert.deepEqual(parseTrackTitle('Hello (Official Video)', 'AdeleVEVO'), { artist: 'Adele', title: 'Hello' });
+    assert.deepEqual(parseTrackTitle('Hello', 'Adele VEVO'), { artist: 'Adele', title: 'Hello' });
+  });
+
+  it('prefers the artist in the title over the channel', () => {
+    assert.deepEqual(parseTrackTitle('Daft Punk - Get Lucky', 'Some Reupload Channel'), {
+      artist: 'Daft Punk',
+      title: 'Get Lucky',
+    });
+  });
+
+  it('leaves titles with no separator and no channel as they are', () => {
+    assert.deepEqual(parseTrackTitle('Lofi beats to study to'), { artist: undefined, title: 'Lofi beats to study to' });
+  });
+
+  it('keeps the raw title when only tags are left', () => {
+    assert.deepEqual(parseTrackTitle('(Official Video)'), { artist: undefined, title: '(Official Video)' });
+  });
+
+  it('splits on a separator only when both sides are left', () => {
+    assert.deepEqual(parseTrackTitle(' - Intro', 'Band'), { artist: 'Band', title: 'Intro' });
+  });
+});
@@ -0,0 +1,60 @@
+import { Song } from '../types/index';
+
+export interface TrackInfo {
+  artist?: string;
+  title: string;
+}
+
+// Bracketed tags that describe the upload rather than the song, e.g. "(Official Video)" or "[4K]"
+const NOISE_TAG = new RegExp(
+  String.raw`\s*[(\[【]\s*(?:` +
+    [
+      String.raw`official(?:\s+(?:music|lyrics?|hd))?(?:\s+(?:video|audio|visuali[sz]er|mv))?`,
+      String.raw`(?:music|lyrics?|hd|hq|4k|8k|1080p|720p|audio|video|mv|m\/v|visuali[sz]er|explicit|clean)(?:\s+(?:video|version))?`,
+      String.raw`full\s+(?:album|ep)`,
+      String.raw`with\s+lyrics`,
+    ].join('|') +
+    String.raw`)\s*[)\]】]`,
+  'gi'
+);
+const SEPARATOR = /\s+[-–—|]\s+/;
+// `Artist "Title"` uploads without a dash
+const QUOTED_TITLE = /^(.+?)\s+["“](.+)["”]\s*(.*)$/;
+// Auto-generated "Artist - Topic" channels and VEVO accounts
+const CHANNEL_SUFFIX = /\s*(?:-\s*topic|vevo)$/i;
+
+function tidy(text: string): string {
+  return text.replace(/\s{2,}/g, ' ').replace(/^[\s\-–—|:]+|[\s\-–—|:]+$/g, '');
+}
+
+/**
+ * Splits an upload title such as `Artist - Title (Official Video) [4K]` into
+ * artist and title, dropping tags about the upload itself. Feature credits and
+ * remix names are kept. When the title names no artist, the channel name is
+ * used, minus "- Topic" and "VEVO".
+ */
+export function parseTrackTitle(rawTitle: string, channel?: string): TrackInfo {
+  const cleaned = tidy(rawTitle.replace(NOISE_TAG, ''));
+
+  const separator = cleaned.match(SEPARATOR);
+  if (separator?.index !== undefined) {
+    const artist = tidy(cleaned.slice(0, separator.index));
+    // Anything after a second separator (e.g. "| Live at ...") is kept with the title
+    const title = tidy(cleaned.slice(separator.index + separator[0].length));
+    if (artist && title) return { artist, title };
+  }
+
+  const quoted = cleaned.match(QUOTED_TITLE);
+  if (quoted) {
+    return { artist: tidy(quoted[1]), title: tidy(`${quoted[2]} ${quoted[3]}`) };
+  }
+
+  const artist = channel ? tidy(channel.replace(CHANNEL_SUFFIX, '')) : '';
+  return { artist: artist || undefined, title: cleaned || rawTitle };
+}
+
+/** `Artist – Title` when the artist is known, otherwise the title as uploaded. */
+export function formatTrack(song: Song): string {
+  if (song.artist && song.track) return `${song.artist} – ${song.track}`;
+  return song.track ?? song.title;
+}
@@ -0,0 +1,91 @@
+import { Chapter, Song } from '../types/index';
+import { DocumentSchema, Storage, reviveSong } from './storage';
+
+/** Details of a YouTube video that don't change between requests for it. */
+export interface TrackInfo {
+  title: string;
+  url: string;
+  duration: number;
+  thumbnail?: string;
+  channelId?: string;
+  channel?: string;
+  artist?: string;
+  track?: string;
+  album?: string;
+  chapters?: Chapter[];
+  /** When it was looked up, in epoch milliseconds; the oldest are dropped first when the store is full. */
+  cachedAt: number;
+}
+
+// video ID -> details
+export type TrackMetadataDocument = Record<string, TrackInfo>;
+
+export const TRACK_METADATA_SCHEMA: DocumentSchema<TrackMetadataDocument> = {
+  name: 'track-metadata',
+  version: 1,
+  migrations: {},
+  defaultValue: () => ({}),
+};
+
+const MAX_TRACKS = Math.max(Number(process.env.TRACK_METADATA_LIMIT) || 5000, 100);
+
+/**
+ * Looked-up song details by video ID, kept across restarts, so songs queued
+ * again from history, playlists or imports don't wait for yt-dlp and still
+ * resolve when YouTube can't be reached.
+ */
+export class TrackMetadataStore {
+  private tracks: TrackMetadataDocument = {};
+  private storage?: Storage;
+
+  /** Loads the saved details and saves new ones from now on; until then lookups are only kept in memory. */
+  attach(storage: Storage): void {
+    this.storage = storage;
+    try {
+      this.tracks = { ...storage.load(TRACK_METADATA_SCHEMA), ...this.tracks };
+    } catch (error) {
+      console.error('[METADATA] Failed to load track metadata:', error);
+    }
+  }
+
+  /** The saved details of `videoId` as a song added by `userId`. */
+  recall(videoId: string, userId: string): Song | undefined {
+    const info = this.tracks[videoId];
+    if (!info) return undefined;
+    return reviveSong({ ...info, id: videoId, addedBy: userId }) ?? undefined;
+  }
+
+  /** Saves a song's details, unless it's a placeholder or only part of a video. */
+  remember(song: Song, now = Date.now()): void {
+    if (song.id === 'unknown' || song.needsMetadata || song.clip) return;
+
+    this.tracks[song.id] = {
+      title: song.title,
+      url: song.url,
+      duration: song.duration,
+      thumbnail: song.thumbnail,
+      channelId: song.channelId,
+      channel: song.channel,
+      artist: song.artist,
+      track: song.track,
+      album: song.album,
+      chapters: song.chapters,
+      cachedAt: now,
+    };
+    this.evictOldest();
+    this.storage?.scheduleSave(TRACK_METADATA_SCHEMA, () => this.tracks);
+  }
+
+  // Drops a tenth at a time, so a full store isn't sorted on every lookup
+  private evictOldest(): void {
+    const videoIds = Object.keys(this.tracks);
+    if (videoIds.length <= MAX_TRACKS) return;
+
+    const oldest = videoIds
+      .sort((a, b) => this.tracks[a].cachedAt - this.tracks[b].cachedAt)
+      .slice(0, Math.ceil(MAX_TRACKS / 10));
+    for (const videoId of oldest) delete this.tracks[videoId];
+  }
+}
+
+export const trackMetadata = new TrackMetadataStore();
@@ -0,0 +1,32 @@
+import { TrackSource } from '../types/index';
+import { extractVideoId } from './frontendApis';
+
+/** Twitch channels (played live), VODs and clips. */
+export function isTwitchUrl(url: string): boolean {
+  try {
+    const host = new URL(url).hostname.toLowerCase();
+    return host === 'twitch.tv' || host.endsWith('.twitch.tv');
+  } catch {
+    return false;
+  }
+}
+
+/** The source of a song saved before songs carried one, worked out from its link. */
+export function trackSourceFromUrl(url: string, id: string): TrackSource {
+  if (isTwitchUrl(url)) return { kind: 'twitch' };
+  return { kind: 'youtube', videoId: extractVideoId(url) ?? id };
+}
+
+/** A saved source, or undefined when it's missing or malformed. */
+export function parseTrackSource(raw: any): TrackSource | undefined {
+  switch (raw?.kind) {
+    case 'youtube':
+      return typeof raw.videoId === 'string' && raw.videoId ? { kind: 'youtube', videoId: raw.videoId } : undefined;
+    case 'twitch':
+      return { kind: 'twitch' };
+    case 'radio':
+      return { kind: 'radio' };
+    default:
+      return undefined;
+  }
+}
@@ -0,0 +1,78 @@
+import * as fs from 'fs';
+import { spawn } from 'child_process';
+import { TempFile, tempFiles } from './tempFiles';
+
+// Speech past this is cut off; announcements are one short sentence
+const MAX_TEXT_LENGTH = 200;
+const SYNTHESIS_TIMEOUT_MS = 10_000;
+
+/** Turns text into an audio file. */
+export interface SpeechEngine {
+  name: string;
+  synthesize(text: string, output: TempFile): Promise<void>;
+}
+
+/** Piper, a local neural TTS; `PIPER_MODEL` is the .onnx voice to use. */
+function piperEngine(model: string, binary: string): SpeechEngine {
+  return {
+    name: 'piper',
+    synthesize(text, output) {
+      return new Promise((resolve, reject) => {
+        const piper = spawn(binary, ['--model', model, '--output_file', output.path], { stdio: ['pipe', 'ignore', 'pipe'] });
+        const timer = setTimeout(() => piper.kill('SIGKILL'), SYNTHESIS_TIMEOUT_MS);
+        let stderr = '';
+        piper.stderr?.on('data', (chunk) => (stderr += chunk.toString()));
+        piper.on('error', (error) => {
+          clearTimeout(timer);
+          reject(error);
+        });
+        piper.on('close', (code) => {
+          clearTimeout(timer);
+          if (code === 0) resolve();
+          else reject(new Error(`piper exited with ${code}: ${stderr.trim().slice(-200)}`));
+        });
+        piper.stdin?.end(text);
+      });
+    },
+  };
+}
+
+/** An HTTP TTS server (e.g. OpenTTS) that answers `GET <TTS_URL>?text=...` with audio. */
+function httpEngine(url: string): SpeechEngine {
+  return {
+    name: 'http',
+    async synthesize(text, output) {
+      const requestUrl = new URL(url);
+      requestUrl.searchParams.set('text', text);
+      const response = await fetch(requestUrl, { signal: AbortSignal.timeout(SYNTHESIS_TIMEOUT_MS) });
+      if (!response.ok) throw new Error(`${requestUrl.host} answered HTTP ${response.status}`);
+      fs.writeFileSync(output.path, Buffer.from(await response.arrayBuffer()));
+    },
+  };
+}
+
+/** The engine configured with `PIPER_MODEL` or `TTS_URL`, if any. Piper wins when both are set. */
+export const speechEngine: SpeechEngine | undefined = process.env.PIPER_MODEL
+  ? piperEngine(process.env.PIPER_MODEL, process.env.PIPER_BINARY || 'piper')
+  : process.env.TTS_URL
+    ? httpEngine(process.env.TTS_URL)
+    : undefined;
+
+/**
+ * Speaks `text` into a temp file the caller releases once played. Returns
+ * undefined when no engine is configured or it fails, so playback goes on
+ * without the announcement.
+ */
+export async function synthesizeSpeech(text: string): Promise<TempFile | undefined> {
+  if (!speechEngine) return undefined;
+
+  const output = tempFiles.create('wav');
+  try {
+    await speechEngine.synthesize(text.slice(0, MAX_TEXT_LENGTH), output);
+    return output;
+  } catch (error) {
+    output.release();
+    console.error(`[TTS] ${speechEngine.name} failed:`, error instanceof Error ? error.message : error);
+    return undefined;
+  }
+}
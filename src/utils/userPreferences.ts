@@ -0,0 +1,53 @@
+import { UserPreferences } from '../types/index';
+import { DocumentSchema, Storage } from './storage';
+
+export type UserPreferencesDocument = Record<string, UserPreferences>;
+
+export const USER_PREFERENCES_SCHEMA: DocumentSchema<UserPreferencesDocument> = {
+  name: 'user-preferences',
+  version: 1,
+  migrations: {},
+  defaultValue: () => ({}),
+};
+
+export const DEFAULT_VOLUME = 100;
+export const MAX_VOLUME = 200;
+
+/**
+ * A requester's own volume only applies while they're the sole listener;
+ * with anyone else in the channel the server's volume wins.
+ */
+export function chooseVolume(guildVolume: number, requesterVolume: number | undefined, requesterId: string, listenerIds: string[]): number {
+  const listeningAlone = listenerIds.length === 1 && listenerIds[0] === requesterId;
+  return listeningAlone && requesterVolume !== undefined ? requesterVolume : guildVolume;
+}
+
+/** Per-user preferences, shared by every server the user is in. */
+export class UserPreferencesManager {
+  private preferences: UserPreferencesDocument = {};
+
+  constructor(private readonly storage: Storage) {
+    try {
+      this.preferences = storage.load(USER_PREFERENCES_SCHEMA);
+    } catch (error) {
+      console.error('[PREFERENCES] Failed to load user preferences:', error);
+    }
+    // Preferences follow users across servers, which other processes may be serving
+    storage.watch(USER_PREFERENCES_SCHEMA, (stored) => (this.preferences = stored));
+  }
+
+  get(userId: string): UserPreferences {
+    return this.preferences[userId] ?? {};
+  }
+
+  update(userId: string, changes: Partial<UserPreferences>): UserPreferences {
+    const updated = { ...this.get(userId), ...changes };
+    this.preferences[userId] = updated;
+    this.save();
+    return updated;
+  }
+
+  private save(): void {
+    this.storage.scheduleSave(USER_PREFERENCES_SCHEMA, () => this.preferences);
+  }
+}
@@ -0,0 +1,65 @@
+import { Guild } from 'discord.js';
+import { Translator } from './i18n';
+
+/** How long a vote stays open after its first ballot. */
+const VOTE_TTL_MS = 2 * 60_000;
+
+export type VoteAction = 'stop' | 'leave' | 'clear' | 'skip';
+
+export interface VoteResult {
+  votes: number;
+  needed: number;
+  passed: boolean;
+}
+
+interface OpenVote {
+  voters: Set<string>;
+  openedAt: number;
+}
+
+/**
+ * Majority votes for actions that affect everyone listening, so one person
+ * can't wipe the queue on their own. Only ballots from people still in the
+ * voice channel count, and a vote that doesn't pass within a few minutes
+ * starts over.
+ */
+export class VoteTracker {
+  private readonly openVotes: Map<string, OpenVote> = new Map();
+
+  castVote(guildId: string, action: VoteAction, userId: string, listenerIds: string[], now = Date.now()): VoteResult {
+    const key = `${guildId}:${action}`;
+    let vote = this.openVotes.get(key);
+    if (!vote || now - vote.openedAt > VOTE_TTL_MS) {
+      vote = { voters: new Set(), openedAt: now };
+      this.openVotes.set(key, vote);
+    }
+    vote.voters.add(userId);
+
+    const listeners = new Set(listenerIds);
+    const votes = [...vote.voters].filter((voterId) => listeners.has(voterId)).length;
+    const needed = Math.floor(listeners.size / 2) + 1;
+    const passed = votes >= needed;
+    if (passed) {
+      this.openVotes.delete(key);
+    }
+    return { votes, needed, passed };
+  }
+
+  /** Drops open votes, or just the one for `action`, once it has happened some other way. */
+  reset(guildId: string, action?: VoteAction): void {
+    for (const key of this.openVotes.keys()) {
+      if (key === `${guildId}:${action}` || (!action && key.startsWith(`${guildId}:`))) this.openVotes.delete(key);
+    }
+  }
+}
+
+/** People (not bots) in the voice channel the bot is in. */
+export function getVoiceListenerIds(guild: Guild | null): string[] {
+  const channel = guild?.members.me?.voice.channel;
+  if (!channel) return [];
+  return channel.members.filter((member) => !member.user.bot).map((member) => member.id);
+}
+
+export function describeVote(action: string, result: VoteResult, t: Translator): string {
+  return t('common.vote', { action, votes: result.votes, needed: result.needed });
+}
@@ -0,0 +1,193 @@
+import * as http from 'http';
+import { Duplex } from 'stream';
+import WebSocket, { WebSocketServer } from 'ws';
+
+// Request bodies are small JSON documents; anything bigger is a mistake or abuse
+const MAX_BODY_BYTES = 64 * 1024;
+
+export type HttpMethod = 'GET' | 'POST' | 'PATCH' | 'DELETE';
+
+export interface WebRequest {
+  method: HttpMethod;
+  /** Values for the route's `:name` segments. */
+  params: Record<string, string>;
+  query: URLSearchParams;
+  headers: http.IncomingHttpHeaders;
+  /** Parsed JSON body; undefined when there is none. */
+  body: any;
+}
+
+export interface WebResponse {
+  status?: number;
+  /** Sent as JSON unless `html` is set. */
+  body?: unknown;
+  html?: string;
+}
+
+export type RouteHandler = (request: WebRequest) => WebResponse | Promise<WebResponse>;
+
+/** Checks a WebSocket upgrade (throw HttpError to refuse it) and returns what to do with the socket once open. */
+export type SocketHandler = (request: WebRequest) => (socket: WebSocket) => void;
+
+/** Thrown by handlers to answer with an error status and a `{ error }` body. */
+export class HttpError extends Error {
+  constructor(readonly status: number, message: string) {
+    super(message);
+    this.name = 'HttpError';
+  }
+}
+
+interface Route {
+  method: HttpMethod;
+  segments: string[];
+  handler: RouteHandler;
+}
+
+/** The bearer token from an `Authorization: Bearer …` header. */
+export function getBearerToken(request: WebRequest): string | undefined {
+  const header = request.headers.authorization;
+  return header?.startsWith('Bearer ') ? header.slice('Bearer '.length).trim() : undefined;
+}
+
+/**
+ * A small HTTP server for the dashboard and other web features. Routes are
+ * plain paths where `:name` segments match anything and are passed to the
+ * handler in `params`; WebSocket endpoints match their exact path.
+ */
+export class WebServer {
+  private readonly routes: Route[] = [];
+  private readonly socketRoutes: Map<string, SocketHandler> = new Map();
+  private readonly sockets = new WebSocketServer({ noServer: true });
+  private server?: http.Server;
+
+  constructor(readonly port: number, readonly publicUrl: string) {}
+
+  route(method: HttpMethod, path: string, handler: RouteHandler): void {
+    this.routes.push({ method, segments: path.split('/').filter(Boolean), handler });
+  }
+
+  /** Accepts WebSocket connections on `path`. */
+  websocket(path: string, handler: SocketHandler): void {
+    this.socketRoutes.set(path, handler);
+  }
+
+  start(): void {
+    if (this.server) return;
+    this.server = http.createServer((req, res) => void this.handle(req, res));
+    this.server.on('upgrade', (req, socket, head) => this.handleUpgrade(req, socket, head));
+    this.server.listen(this.port, () => console.log(`🌐 Web server listening on port ${this.port}`));
+  }
+
+  stop(): Promise<void> {
+    const server = this.server;
+    this.server = undefined;
+    if (!server) return Promise.resolve();
+    for (const socket of this.sockets.clients) socket.terminate();
+    return new Promise((resolve) => {
+      server.close(() => resolve());
+      // Open dashboards keep connections alive, which would otherwise hold up shutdown
+      server.closeAllConnections();
+    });
+  }
+
+  private async handle(req: http.IncomingMessage, res: http.ServerResponse): Promise<void> {
+    try {
+      const url = new URL(req.url ?? '/', 'http://localhost');
+      const match = this.match(req.method ?? 'GET', url.pathname);
+      if (!match) throw new HttpError(404, 'Not found');
+
+      const response = await match.route.handler({
+        method: match.route.method,
+        params: match.params,
+        query: url.searchParams,
+        headers: req.headers,
+        body: await readJsonBody(req),
+      });
+      send(res, response.status ?? 200, response);
+    } catch (error) {
+      if (error instanceof HttpError) {
+        send(res, error.status, { body: { error: error.message } });
+        return;
+      }
+      console.error('[WEB] Request failed:', error);
+      send(res, 500, { body: { error: 'Internal error' } });
+    }
+  }
+
+  private handleUpgrade(req: http.IncomingMessage, socket: Duplex, head: Buffer): void {
+    const url = new URL(req.url ?? '/', 'http://localhost');
+    let onOpen: (socket: WebSocket) => void;
+    try {
+      const handler = this.socketRoutes.get(url.pathname);
+      if (!handler) throw new HttpError(404, 'Not found');
+      onOpen = handler({ method: 'GET', params: {}, query: url.searchParams, headers: req.headers, body: undefined });
+    } catch (error) {
+      if (!(error instanceof HttpError)) console.error('[WEB] WebSocket upgrade failed:', error);
+      const status = error instanceof HttpError ? error.status : 500;
+      socket.end(`HTTP/1.1 ${status} ${http.STATUS_CODES[status]}\r\nConnection: close\r\n\r\n`);
+      return;
+    }
+    this.sockets.handleUpgrade(req, socket, head, (webSocket) => onOpen(webSocket));
+  }
+
+  private match(method: string, pathname: string): { route: Route; params: Record<string, string> } | undefined {
+    const segments = pathname.split('/').filter(Boolean);
+    for (const route of this.routes) {
+      if (route.method !== method || route.segments.length !== segments.length) continue;
+
+      const params: Record<string, string> = {};
+      const matches = route.segments.every((segment, i) => {
+        if (segment.startsWith(':')) {
+          params[segment.slice(1)] = decodeURIComponent(segments[i]);
+          return true;
+        }
+        return segment === segments[i];
+      });
+      if (matches) return { route, params };
+    }
+    return undefined;
+  }
+}
+
+function readJsonBody(req: http.IncomingMessage): Promise<any> {
+  return new Promise((resolve, reject) => {
+    const chunks: Buffer[] = [];
+    let size = 0;
+    req.on('data', (chunk: Buffer) => {
+      size += chunk.length;
+      if (size > MAX_BODY_BYTES) {
+        reject(new HttpError(413, 'Request body is too large'));
+        req.destroy();
+        return;
+      }
+      chunks.push(chunk);
+    });
+    req.on('end', () => {
+      if (chunks.length === 0) return resolve(undefined);
+      try {
+        resolve(JSON.parse(Buffer.concat(chunks).toString('utf8')));
+      } catch {
+        reject(new HttpError(400, 'Request body must be JSON'));
+      }
+    });
+    req.on('error', reject);
+  });
+}
+
+function send(res: http.ServerResponse, status: number, response: WebResponse): void {
+  if (response.html !== undefined) {
+    res.writeHead(status, { 'Content-Type': 'text/html; charset=utf-8' });
+    res.end(response.html);
+    return;
+  }
+  res.writeHead(status, { 'Content-Type': 'application/json' });
+  res.end(JSON.stringify(response.body ?? {}));
+}
+
+const WEB_PORT = Number(process.env.WEB_PORT) || 0;
+
+/** The bot's web server, when `WEB_PORT` is set; links it hands out start with `WEB_PUBLIC_URL`. */
+export const webServer: WebServer | undefined =
+  WEB_PORT > 0
+    ? new WebServer(WEB_PORT, (process.env.WEB_PUBLIC_URL || `http://localhost:${WEB_PORT}`).replace(/\/+$/, ''))
+    : undefined;
@@ -1,51 +1,262 @@
-import { execFile } from 'child_process';
-import { promisify } from 'util';
 import { Song } from '../types/index';
+import { JobLane, extractionScheduler } from './jobScheduler';
+import { parseChapters } from './chapters';
+import { execYtDlp } from './ytDlp';
+import { requiresAccount } from './errors';
+import { withRetry } from './retry';
+import { parseTrackTitle } from './titleParser';
+import { backendHealth } from './backendHealth';
+import { VideoMissError, YOUTUBE_FRONTENDS, extractVideoId } from './frontendApis';
+import { TtlCache } from './ttlCache';
+import { trackMetadata } from './trackMetadata';
+import { isTwitchUrl } from './trackSource';
 
-const execFileAsync = promisify(execFile);
+const MAX_PLAYLIST_ENTRIES = 1000;
+
+// Repeat requests for the same song within this window skip yt-dlp; 0 turns the cache off
+const SONG_CACHE_TTL_MS = Math.max(Number(process.env.SONG_CACHE_MINUTES ?? 10) || 0, 0) * 60_000;
+const MAX_CACHED_SONGS = 500;
+
+// Keyed by video ID (or URL) and by normalized search query
+const songCache = new TtlCache<Song>(SONG_CACHE_TTL_MS, MAX_CACHED_SONGS);
+
+function urlCacheKey(url: string): string {
+  const videoId = extractVideoId(url);
+  return videoId ? `video:${videoId}` : `url:${url.trim()}`;
+}
+
+function searchCacheKey(query: string): string {
+  return `search:${query.trim().toLowerCase().replace(/\s+/g, ' ')}`;
+}
+
+/** A copy of the cached song, added by `userId`; queueing changes the copy, never the cached one. */
+function recallSong(key: string, userId: string): Song | undefined {
+  const song = songCache.get(key);
+  return song && { ...song, addedBy: userId };
+}
+
+function rememberSong(keys: string[], song: Song): Song {
+  // Whether a channel is live changes from one minute to the next
+  if (song.isLive) return song;
+  for (const key of keys) songCache.set(key, { ...song });
+  trackMetadata.remember(song);
+  return song;
+}
 
 export class YouTubeUtil {
-  static async isValidUrl(url: string): Promise<boolean> {
+  static isUrl(text: string): boolean {
+    try {
+      const parsed = new URL(text);
+      return parsed.protocol === 'http:' || parsed.protocol === 'https:';
+    } catch {
+      return false;
+    }
+  }
+
+  /**
+   * Rewrites YouTube Music links to the YouTube ones the rest of the bot
+   * expects: a track becomes a plain watch URL, dropping the radio it was
+   * opened from, and a playlist or album a /playlist URL. Album pages
+   * (`/browse/MPREb_...`) stay as they are for yt-dlp to resolve. Other
+   * links are returned unchanged.
+   */
+  static normalizeUrl(url: string): string {
+    try {
+      const parsed = new URL(url);
+      if (parsed.hostname.toLowerCase() !== 'music.youtube.com') return url;
+      const videoId = parsed.searchParams.get('v');
+      const list = parsed.searchParams.get('list');
+      if (parsed.pathname === '/watch' && videoId) return `https://www.youtube.com/watch?v=${videoId}`;
+      if (parsed.pathname === '/playlist' && list) return `https://www.youtube.com/playlist?list=${list}`;
+      return url;
+    } catch {
+      return url;
+    }
+  }
+
+  static isPlaylistUrl(url: string): boolean {
     try {
       const parsed = new URL(url);
-      const host = parsed.hostname.toLowerCase();
-      return host === 'youtube.com' || host === 'www.youtube.com' || host === 'youtu.be' || host.endsWith('.youtube.com');
+      if (parsed.hostname.toLowerCase() === 'music.youtube.com' && parsed.pathname.startsWith('/browse/MPREb')) return true;
+      return parsed.pathname === '/playlist' && parsed.searchParams.has('list');
     } catch {
       return false;
     }
   }
 
-  static async fetchSongInfo(url: string, userId: string): Promise<Song> {
+  /**
+   * Lists a playlist without extracting each video, which takes one request
+   * instead of one per entry. The songs are marked `needsMetadata`.
+   */
+  static async fetchPlaylistEntries(url: string, userId: string, guildId: string): Promise<Song[]> {
     try {
-      const { stdout } = await execFileAsync('yt-dlp', [
-        '--dump-single-json',
-        '--no-playlist',
-        url,
-      ]);
+      const { stdout } = await withRetry(`playlist listing for ${url}`, () =>
+        extractionScheduler.schedule(
+          guildId,
+          () =>
+            execYtDlp(['--flat-playlist', '--dump-single-json', '--playlist-end', String(MAX_PLAYLIST_ENTRIES), url], {
+              maxBuffer: 64 * 1024 * 1024,
+              guildId,
+            }),
+          'bulk'
+        )
+      );
 
-      const info = JSON.parse(stdout);
+      const playlist = JSON.parse(stdout);
+      const entries: any[] = Array.isArray(playlist.entries) ? playlist.entries : [];
+      // YouTube Music albums list as "Album - <name>"
+      const album = typeof playlist.title === 'string' && playlist.title.startsWith('Album - ') ? playlist.title.slice(8) : undefined;
 
-      return {
-        id: String(info.id || 'unknown'),
-        title: String(info.title || 'Unknown Title'),
-        url: String(info.webpage_url || url),
-        duration: Number(info.duration || 0),
-        thumbnail: typeof info.thumbnail === 'string' ? info.thumbnail : undefined,
-        addedBy: userId,
-      };
+      return entries
+        .filter((entry) => entry && entry.id)
+        .map((entry): Song => {
+          const title = String(entry.title || 'YouTube Track');
+          const { artist, title: track } = parseTrackTitle(title, entry.channel ?? entry.uploader);
+          return {
+            id: String(entry.id),
+            title,
+            url: String(entry.url || `https://www.youtube.com/watch?v=${entry.id}`),
+            source: { kind: 'youtube', videoId: String(entry.id) },
+            duration: Number(entry.duration || 0),
+            thumbnail: Array.isArray(entry.thumbnails) && entry.thumbnails.length > 0
+              ? String(entry.thumbnails[entry.thumbnails.length - 1].url)
+              : undefined,
+            addedBy: userId,
+            channelId: typeof entry.channel_id === 'string' ? entry.channel_id : undefined,
+            channel: String(entry.channel ?? entry.uploader ?? '') || undefined,
+            artist,
+            track,
+            album,
+            isLive: entry.live_status === 'is_live' || undefined,
+            needsMetadata: true,
+          };
+        });
+    } catch (error) {
+      console.error('Error fetching YouTube playlist:', error);
+      return [];
+    }
+  }
+
+  static async fetchSongInfo(url: string, userId: string, guildId: string, lane: JobLane = 'interactive'): Promise<Song> {
+    url = this.normalizeUrl(url);
+    const cached = recallSong(urlCacheKey(url), userId);
+    if (cached) return cached;
+    // Details saved from an earlier run need no lookup, even with YouTube unreachable
+    const videoId = extractVideoId(url);
+    const saved = videoId ? trackMetadata.recall(videoId, userId) : undefined;
+    if (saved) return rememberSong([urlCacheKey(url)], saved);
+
+    try {
+      const { stdout } = await withRetry(`song info for ${url}`, () =>
+        extractionScheduler.schedule(guildId, () => execYtDlp(['--dump-single-json', '--no-playlist', url], { guildId }), lane)
+      );
+
+      return rememberSong([urlCacheKey(url)], this.toSong(JSON.parse(stdout), url, userId));
     } catch (error) {
+      // Playback would fail too, so let the caller explain why. Twitch has no
+      // other source to fall back on, and an offline channel can't be played
+      if (requiresAccount(error) || isTwitchUrl(url)) throw error;
       console.error('Error fetching YouTube info:', error);
+
+      const fromFrontend = await this.fetchSongInfoFromFrontends(url, userId);
+      if (fromFrontend) return rememberSong([urlCacheKey(url)], fromFrontend);
+
       // Fallback metadata keeps playback possible even when metadata extraction fails.
       return {
         id: 'unknown',
         title: 'YouTube Track',
         url,
+        source: { kind: 'youtube', videoId: videoId ?? 'unknown' },
         duration: 0,
         addedBy: userId,
       };
     }
   }
 
+  static async searchSong(query: string, userId: string, guildId: string, lane: JobLane = 'interactive'): Promise<Song | null> {
+    const cached = recallSong(searchCacheKey(query), userId);
+    if (cached) return cached;
+
+    try {
+      const { stdout } = await withRetry(`search for "${query}"`, () =>
+        extractionScheduler.schedule(
+          guildId,
+          () => execYtDlp(['--dump-single-json', '--no-playlist', `ytsearch1:${query}`], { guildId }),
+          lane
+        )
+      );
+
+      const result = JSON.parse(stdout);
+      const entry = Array.isArray(result.entries) ? result.entries[0] : undefined;
+      if (!entry) {
+        return null;
+      }
+
+      const song = this.toSong(entry, String(entry.webpage_url || `https://www.youtube.com/watch?v=${entry.id}`), userId);
+      // Pasting the result's link later hits the cache too
+      return rememberSong([searchCacheKey(query), urlCacheKey(song.url)], song);
+    } catch (error) {
+      console.error('Error searching YouTube:', error);
+      return null;
+    }
+  }
+
+  /** Asks the configured Piped/Invidious instances, healthiest first, when yt-dlp fails. */
+  private static async fetchSongInfoFromFrontends(url: string, userId: string): Promise<Song | null> {
+    const videoId = extractVideoId(url);
+    if (!videoId) return null;
+
+    for (const frontend of backendHealth.rank(YOUTUBE_FRONTENDS)) {
+      try {
+        const video = await frontend.fetchVideo(videoId);
+        backendHealth.recordSuccess(frontend.name);
+        const { artist, title: track } = parseTrackTitle(video.title, video.uploader);
+        return {
+          id: videoId,
+          title: video.title,
+          url: `https://www.youtube.com/watch?v=${videoId}`,
+          source: { kind: 'youtube', videoId },
+          duration: video.duration,
+          thumbnail: video.thumbnail,
+          addedBy: userId,
+          channel: video.uploader,
+          artist,
+          track,
+        };
+      } catch (error) {
+        if (!(error instanceof VideoMissError)) backendHealth.recordFailure(frontend.name, error);
+        console.error(`[metadata] ${frontend.name} failed for ${url}:`, error instanceof Error ? error.message : error);
+      }
+    }
+    return null;
+  }
+
+  private static toSong(info: any, url: string, userId: string): Song {
+    const title = String(info.title || 'Unknown Title');
+    // Music uploads carry proper tags; everything else gets its title parsed
+    const parsed = parseTrackTitle(title, info.channel ?? info.uploader);
+    const isLive = info.is_live === true;
+    const id = String(info.id || 'unknown');
+    return {
+      id,
+      title,
+      url: String(info.webpage_url || url),
+      // yt-dlp names the extractor it used, e.g. `Youtube`, `TwitchVod` or `TwitchStream`
+      source: String(info.extractor_key ?? '').startsWith('Twitch') ? { kind: 'twitch' } : { kind: 'youtube', videoId: id },
+      // Lives report how long they've been on air, if anything
+      duration: isLive ? 0 : Number(info.duration || 0),
+      thumbnail: typeof info.thumbnail === 'string' ? info.thumbnail : undefined,
+      addedBy: userId,
+      channelId: typeof info.channel_id === 'string' ? info.channel_id : undefined,
+      channel: String(info.channel ?? info.uploader ?? '') || undefined,
+      artist: typeof info.artist === 'string' && info.artist ? info.artist : parsed.artist,
+      track: typeof info.track === 'string' && info.track ? info.track : parsed.title,
+      album: typeof info.album === 'string' && info.album ? info.album : undefined,
+      chapters: parseChapters(info.chapters),
+      isLive: isLive || undefined,
+    };
+  }
+
   static formatDuration(seconds: number): string {
     const hours = Math.floor(seconds / 3600);
     const minutes = Math.floor((seconds % 3600) / 60);
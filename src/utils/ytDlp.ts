@@ -0,0 +1,92 @@
+import { ChildProcess, ExecFileOptions, SpawnOptions, execFile, spawn } from 'child_process';
+import { promisify } from 'util';
+import { getProxyUrl } from './proxy';
+import { getYtDlpCommand } from './ytDlpBinary';
+import { GoonError, GoonErrorKind } from './errors';
+
+const execFileAsync = promisify(execFile);
+
+const COOKIES_FILE = process.env.YTDLP_COOKIES_FILE;
+// e.g. `firefox` or `chrome:Profile 1`; passed to --cookies-from-browser
+const COOKIES_FROM_BROWSER = process.env.YTDLP_COOKIES_FROM_BROWSER;
+
+// Kills a lookup that hangs, e.g. on a stalled connection; downloads can pass their own limit
+const EXEC_TIMEOUT_MS = 120_000;
+
+// yt-dlp stderr patterns, checked in order; YouTube's bot check also suggests --cookies, so it goes first
+const STDERR_PATTERNS: [GoonErrorKind, RegExp[]][] = [
+  ['rateLimited', [/not a bot/i, /HTTP Error 429/i, /too many requests/i]],
+  ['ageRestricted', [/sign in to confirm your age/i, /inappropriate for some users/i, /age[- ]restricted/i]],
+  ['membersOnly', [/members[- ]only/i, /join this channel to get access/i, /requires? (?:a )?(?:login|sign[- ]in)/i, /use --cookies/i]],
+  ['geoBlocked', [/not available in your country/i, /blocked it in your country/i, /geo[- ]?restrict/i]],
+  ['offline', [/is not currently live/i, /stream is offline/i]],
+  ['unavailable', [/video unavailable/i, /private video/i, /has been removed/i, /video is not available/i, /does not exist/i]],
+];
+
+export function hasCookies(): boolean {
+  return Boolean(COOKIES_FILE || COOKIES_FROM_BROWSER);
+}
+
+export interface YtDlpOptions {
+  /** Guild the run is for, which picks its proxy. */
+  guildId?: string;
+}
+
+/** Options added to every yt-dlp run. */
+function globalArgs(guildId?: string): string[] {
+  const args: string[] = [];
+  if (COOKIES_FILE) {
+    args.push('--cookies', COOKIES_FILE);
+  } else if (COOKIES_FROM_BROWSER) {
+    args.push('--cookies-from-browser', COOKIES_FROM_BROWSER);
+  }
+  const proxy = getProxyUrl(guildId);
+  if (proxy) {
+    args.push('--proxy', proxy);
+  }
+  return args;
+}
+
+/** Turns a failed yt-dlp run into a GoonError saying why, keeping stderr's last line for the logs. */
+function toYtDlpError(error: unknown): GoonError {
+  const { stderr, killed, code } = (error ?? {}) as { stderr?: unknown; killed?: boolean; code?: unknown };
+  const output = String(stderr ?? '').trim();
+  const lastLine = output.split('\n').pop() || (error instanceof Error ? error.message : String(error));
+
+  // Going over maxBuffer kills the process too, but that's a listing too big rather than a hang
+  if (killed && code !== 'ERR_CHILD_PROCESS_STDIO_MAXBUFFER') {
+    return new GoonError('timeout', 'yt-dlp took too long and was stopped', error);
+  }
+  const match = STDERR_PATTERNS.find(([, patterns]) => patterns.some((pattern) => pattern.test(output)));
+  if (!match) {
+    return new GoonError('extractionFailed', lastLine, error);
+  }
+  const [kind] = match;
+  // The configured account was tried and turned away
+  if ((kind === 'ageRestricted' || kind === 'membersOnly') && hasCookies()) {
+    return new GoonError('cookiesNoAccess', lastLine, error);
+  }
+  return new GoonError(kind, lastLine, error);
+}
+
+/** Runs yt-dlp to completion with the configured global options. */
+export async function execYtDlp(
+  args: string[],
+  { guildId, ...options }: ExecFileOptions & YtDlpOptions = {}
+): Promise<{ stdout: string; stderr: string }> {
+  try {
+    const { stdout, stderr } = await execFileAsync(getYtDlpCommand(), [...globalArgs(guildId), ...args], {
+      timeout: EXEC_TIMEOUT_MS,
+      ...options,
+      encoding: 'utf8',
+    });
+    return { stdout, stderr };
+  } catch (error) {
+    throw toYtDlpError(error);
+  }
+}
+
+/** Starts a long-running yt-dlp process, e.g. one streaming audio to stdout. */
+export function spawnYtDlp(args: string[], { guildId, ...options }: SpawnOptions & YtDlpOptions = {}): ChildProcess {
+  return spawn(getYtDlpCommand(), [...globalArgs(guildId), ...args], options);
+}
@@ -0,0 +1,148 @@
+import * as crypto from 'crypto';
+import * as fs from 'fs';
+import * as path from 'path';
+import { Storage } from './storage';
+
+// Release installed the first time, unless YTDLP_VERSION names another
+const PINNED_VERSION = '2024.08.06';
+const RELEASES_URL = 'https://github.com/yt-dlp/yt-dlp/releases';
+const LATEST_RELEASE_API = 'https://api.github.com/repos/yt-dlp/yt-dlp/releases/latest';
+const UPDATE_CHECK_INTERVAL_MS = 24 * 60 * 60 * 1000;
+const DOWNLOAD_TIMEOUT_MS = 120_000;
+
+/** When set, yt-dlp is downloaded into the data directory and kept up to date instead of taken from PATH. */
+export const YTDLP_MANAGED = process.env.YTDLP_MANAGED === 'true';
+
+const BIN_DIR = path.join(new Storage().getDataDir(), 'bin');
+const BINARY_PATH = path.join(BIN_DIR, process.platform === 'win32' ? 'yt-dlp.exe' : 'yt-dlp');
+const VERSION_PATH = path.join(BIN_DIR, 'yt-dlp.version');
+
+export interface YtDlpUpdateResult {
+  previousVersion?: string;
+  version: string;
+  updated: boolean;
+}
+
+/** Standalone builds, which don't need Python installed. */
+function releaseAssetName(): string {
+  switch (process.platform) {
+    case 'win32':
+      return 'yt-dlp.exe';
+    case 'darwin':
+      return 'yt-dlp_macos';
+    default:
+      return process.arch === 'arm64' ? 'yt-dlp_linux_aarch64' : 'yt-dlp_linux';
+  }
+}
+
+/** What to run: the managed binary once installed, otherwise `yt-dlp` from PATH. */
+export function getYtDlpCommand(): string {
+  return YTDLP_MANAGED && fs.existsSync(BINARY_PATH) ? BINARY_PATH : 'yt-dlp';
+}
+
+export function getInstalledVersion(): string | undefined {
+  try {
+    return fs.readFileSync(VERSION_PATH, 'utf8').trim() || undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+async function download(url: string): Promise<Buffer> {
+  const response = await fetch(url, { signal: AbortSignal.timeout(DOWNLOAD_TIMEOUT_MS) });
+  if (!response.ok) {
+    throw new Error(`Download of ${url} failed with HTTP ${response.status}`);
+  }
+  return Buffer.from(await response.arrayBuffer());
+}
+
+/** Reads the expected hash for `asset` from the release's SHA2-256SUMS file. */
+async function fetchExpectedChecksum(version: string, asset: string): Promise<string> {
+  const sums = (await download(`${RELEASES_URL}/download/${version}/SHA2-256SUMS`)).toString('utf8');
+  for (const line of sums.split('\n')) {
+    const [hash, name] = line.trim().split(/\s+/);
+    if (name === asset && /^[0-9a-f]{64}$/i.test(hash)) return hash.toLowerCase();
+  }
+  throw new Error(`yt-dlp ${version} lists no checksum for ${asset}`);
+}
+
+async function install(version: string): Promise<void> {
+  const asset = releaseAssetName();
+  const expected = await fetchExpectedChecksum(version, asset);
+  const binary = await download(`${RELEASES_URL}/download/${version}/${asset}`);
+
+  const actual = crypto.createHash('sha256').update(binary).digest('hex');
+  if (actual !== expected) {
+    throw new Error(`Checksum mismatch for yt-dlp ${version} (expected ${expected}, got ${actual})`);
+  }
+
+  // Swapped in with a rename so running extractions keep their old binary
+  fs.mkdirSync(BIN_DIR, { recursive: true });
+  const tempPath = `${BINARY_PATH}.tmp`;
+  fs.writeFileSync(tempPath, binary, { mode: 0o755 });
+  fs.renameSync(tempPath, BINARY_PATH);
+  fs.writeFileSync(VERSION_PATH, version);
+  console.log(`[yt-dlp] Installed ${version}`);
+}
+
+async function fetchLatestVersion(): Promise<string> {
+  const response = await fetch(LATEST_RELEASE_API, {
+    headers: { Accept: 'application/vnd.github+json' },
+    signal: AbortSignal.timeout(DOWNLOAD_TIMEOUT_MS),
+  });
+  if (!response.ok) {
+    throw new Error(`GitHub release lookup failed with HTTP ${response.status}`);
+  }
+  const release = (await response.json()) as { tag_name?: unknown };
+  if (typeof release.tag_name !== 'string') {
+    throw new Error('GitHub returned a release without a tag');
+  }
+  return release.tag_name;
+}
+
+// One install at a time; each call waits for the ones before it, then does its own check
+let lastRun: Promise<unknown> = Promise.resolve();
+
+function runExclusive(task: () => Promise<YtDlpUpdateResult>): Promise<YtDlpUpdateResult> {
+  const run = lastRun.then(task);
+  lastRun = run.catch(() => undefined);
+  return run;
+}
+
+/** Installs the pinned release if no managed binary exists yet. */
+export function ensureYtDlp(): Promise<YtDlpUpdateResult> {
+  return runExclusive(async () => {
+    const installed = fs.existsSync(BINARY_PATH) ? getInstalledVersion() : undefined;
+    if (installed) return { previousVersion: installed, version: installed, updated: false };
+
+    const version = process.env.YTDLP_VERSION || PINNED_VERSION;
+    await install(version);
+    return { version, updated: true };
+  });
+}
+
+/** Installs the latest release if it differs from the installed one. */
+export function updateYtDlp(): Promise<YtDlpUpdateResult> {
+  return runExclusive(async () => {
+    const previousVersion = fs.existsSync(BINARY_PATH) ? getInstalledVersion() : undefined;
+    const latest = await fetchLatestVersion();
+    if (latest === previousVersion) return { previousVersion, version: latest, updated: false };
+
+    await install(latest);
+    return { previousVersion, version: latest, updated: true };
+  });
+}
+
+/** Sets up the managed binary and checks for a new release once a day. */
+export function startManagedYtDlp(): void {
+  if (!YTDLP_MANAGED) return;
+
+  ensureYtDlp().catch((error) => console.error('[yt-dlp] Install failed, falling back to PATH:', error));
+
+  // YouTube changes often break old releases, so updates apply without waiting for an admin
+  if (process.env.YTDLP_AUTO_UPDATE !== 'false') {
+    setInterval(() => {
+      updateYtDlp().catch((error) => console.error('[yt-dlp] Update check failed:', error));
+    }, UPDATE_CHECK_INTERVAL_MS).unref();
+  }
+}
@@ -0,0 +1,30 @@
+import type { SourceExtractor } from './sourceExtractor';
+import { YouTubeUtil } from './youtubeUtil';
+import { isTwitchUrl } from './trackSource';
+import { openAudioInput } from './audioInput';
+
+/** YouTube (including YouTube Music) and Twitch, looked up and streamed through yt-dlp. */
+export const ytDlpExtractor: SourceExtractor = {
+  name: 'yt-dlp',
+  sources: ['youtube', 'twitch'],
+
+  matchesUrl(url: string): boolean {
+    try {
+      const host = new URL(url).hostname.toLowerCase();
+      return (
+        host === 'youtube.com' ||
+        host === 'youtu.be' ||
+        host.endsWith('.youtube.com') ||
+        isTwitchUrl(url)
+      );
+    } catch {
+      return false;
+    }
+  },
+
+  extract: (url, userId, guildId, lane) => YouTubeUtil.fetchSongInfo(url, userId, guildId, lane),
+
+  search: (query, userId, guildId, lane) => YouTubeUtil.searchSong(query, userId, guildId, lane),
+
+  stream: (song, guildId, options) => openAudioInput(song, guildId, options),
+};